@@ -0,0 +1,18 @@
+//! Writes the TypeScript definitions and JSON schemas for the IPC payloads
+//! into the frontend's `src/types/generated`.
+
+use deterrence_lib::state::schema;
+use std::fs;
+use std::path::Path;
+
+fn main() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(schema::GENERATED_DIR);
+    for (name, contents) in schema::generated_files() {
+        let path = dir.join(name);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).expect("create generated dir");
+        }
+        fs::write(&path, contents).expect("write generated file");
+        println!("wrote {}", path.display());
+    }
+}
@@ -0,0 +1,160 @@
+use serde::{Deserialize, Serialize};
+
+use crate::campaign::territory::{Region, TheaterBounds};
+use crate::engine::config;
+
+/// A community map: the full region layout for a campaign.
+/// The first region is the homeland the player starts with.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomMap {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub regions: Vec<Region>,
+    /// Explicit theater bounds; the default screen-plus-margin theater if absent
+    #[serde(default)]
+    pub theater: Option<TheaterBounds>,
+}
+
+/// Parse a map from JSON and validate it.
+pub fn parse_map(json: &str) -> Result<CustomMap, String> {
+    let map: CustomMap = serde_json::from_str(json).map_err(|e| format!("Failed to parse map: {e}"))?;
+    validate_map(&map)?;
+    Ok(map)
+}
+
+/// Check a map for problems the campaign can't recover from: a missing or
+/// undefended homeland, duplicate or dangling region IDs, one-way adjacency,
+/// off-screen coordinates, empty cities, and overlapping cities or battery slots.
+pub fn validate_map(map: &CustomMap) -> Result<(), String> {
+    let Some(homeland) = map.regions.first() else {
+        return Err("Map has no regions".into());
+    };
+    if homeland.cities.is_empty() {
+        return Err(format!("Homeland '{}' has no cities", homeland.name));
+    }
+    if !homeland.battery_slots.iter().any(|s| s.occupied) {
+        return Err(format!("Homeland '{}' has no occupied battery slot", homeland.name));
+    }
+
+    for (i, region) in map.regions.iter().enumerate() {
+        if map.regions[..i].iter().any(|r| r.id == region.id) {
+            return Err(format!("Duplicate region id {}", region.id.0));
+        }
+        if i > 0 && region.battery_slots.iter().any(|s| s.occupied) {
+            return Err(format!("Only the homeland may start with occupied slots ('{}')", region.name));
+        }
+        for adj_id in &region.adjacent {
+            let Some(adj) = map.regions.iter().find(|r| r.id == *adj_id) else {
+                return Err(format!("Region '{}' is adjacent to unknown region {}", region.name, adj_id.0));
+            };
+            if !adj.adjacent.contains(&region.id) {
+                return Err(format!("Adjacency between '{}' and '{}' is one-way", region.name, adj.name));
+            }
+        }
+        if !on_screen(region.map_x, region.map_y) {
+            return Err(format!("Region '{}' map position is off-screen", region.name));
+        }
+        if region.cities.iter().any(|c| c.population == 0) {
+            return Err(format!("Region '{}' has a city with no population", region.name));
+        }
+    }
+
+    if map.theater.is_some_and(|t| !t.covers_screen()) {
+        return Err("Theater bounds must cover the whole screen".into());
+    }
+
+    let mut placed: Vec<(f32, f32)> = Vec::new();
+    for region in &map.regions {
+        let points = region
+            .cities
+            .iter()
+            .map(|c| (c.x, c.y))
+            .chain(region.battery_slots.iter().map(|s| (s.x, s.y)));
+        for (x, y) in points {
+            if !on_screen(x, y) {
+                return Err(format!("Region '{}' has a site off-screen at ({x}, {y})", region.name));
+            }
+            if placed
+                .iter()
+                .any(|&(px, py)| (x - px).hypot(y - py) < config::CUSTOM_MAP_MIN_SPACING)
+            {
+                return Err(format!("Region '{}' has a site overlapping another at ({x}, {y})", region.name));
+            }
+            placed.push((x, y));
+        }
+    }
+
+    Ok(())
+}
+
+fn on_screen(x: f32, y: f32) -> bool {
+    (0.0..=config::WORLD_WIDTH).contains(&x) && (0.0..=config::WORLD_HEIGHT).contains(&y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::campaign::territory::{define_regions, RegionId};
+
+    fn builtin_map() -> CustomMap {
+        CustomMap {
+            name: "Builtin".into(),
+            description: String::new(),
+            regions: define_regions(),
+            theater: None,
+        }
+    }
+
+    #[test]
+    fn builtin_regions_are_a_valid_map() {
+        assert!(validate_map(&builtin_map()).is_ok());
+    }
+
+    #[test]
+    fn map_roundtrips_through_json() {
+        let json = serde_json::to_string(&builtin_map()).unwrap();
+        let map = parse_map(&json).unwrap();
+        assert_eq!(map.regions.len(), 5);
+    }
+
+    #[test]
+    fn off_screen_city_is_rejected() {
+        let mut map = builtin_map();
+        map.regions[1].cities[0].x = config::WORLD_WIDTH + 10.0;
+        assert!(validate_map(&map).unwrap_err().contains("off-screen"));
+    }
+
+    #[test]
+    fn overlapping_sites_are_rejected() {
+        let mut map = builtin_map();
+        let city = map.regions[0].cities[0].clone();
+        map.regions[1].cities[0].x = city.x + 1.0;
+        map.regions[1].cities[0].y = city.y;
+        assert!(validate_map(&map).unwrap_err().contains("overlapping"));
+    }
+
+    #[test]
+    fn theater_smaller_than_screen_is_rejected() {
+        let mut map = builtin_map();
+        map.theater = Some(TheaterBounds {
+            max_x: config::WORLD_WIDTH * 0.5,
+            ..TheaterBounds::default()
+        });
+        assert!(validate_map(&map).unwrap_err().contains("Theater"));
+    }
+
+    #[test]
+    fn unpopulated_city_is_rejected() {
+        let mut map = builtin_map();
+        map.regions[2].cities[0].population = 0;
+        assert!(validate_map(&map).unwrap_err().contains("no population"));
+    }
+
+    #[test]
+    fn one_way_adjacency_is_rejected() {
+        let mut map = builtin_map();
+        map.regions[1].adjacent.retain(|id| *id != RegionId(0));
+        assert!(validate_map(&map).is_err());
+    }
+}
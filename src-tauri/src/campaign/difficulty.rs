@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+
+use crate::engine::config;
+
+/// Campaign difficulty preset, chosen at NewGame.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Hard",
+        }
+    }
+
+    /// Multiplier on each wave's missile count.
+    pub fn wave_budget_mult(&self) -> f32 {
+        match self {
+            Difficulty::Easy => config::EASY_WAVE_BUDGET_MULT,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => config::HARD_WAVE_BUDGET_MULT,
+        }
+    }
+
+    /// Multiplier on end-of-wave income.
+    pub fn income_mult(&self) -> f32 {
+        match self {
+            Difficulty::Easy => config::EASY_INCOME_MULT,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => config::HARD_INCOME_MULT,
+        }
+    }
+
+    /// Multiplier on city repair costs.
+    pub fn repair_cost_mult(&self) -> f32 {
+        match self {
+            Difficulty::Easy => config::EASY_REPAIR_COST_MULT,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => config::HARD_REPAIR_COST_MULT,
+        }
+    }
+
+    /// Multiplier on the wave an interceptor type unlocks at.
+    pub fn unlock_wave_mult(&self) -> f32 {
+        match self {
+            Difficulty::Easy => config::EASY_UNLOCK_WAVE_MULT,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => config::HARD_UNLOCK_WAVE_MULT,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_roundtrip_and_unknown_ones_are_rejected() {
+        for d in [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard] {
+            let json = serde_json::to_string(d.as_str()).unwrap();
+            assert_eq!(serde_json::from_str::<Difficulty>(&json).unwrap(), d);
+        }
+        assert!(serde_json::from_str::<Difficulty>("\"Nightmare\"").is_err());
+    }
+
+    #[test]
+    fn presets_are_ordered() {
+        assert!(Difficulty::Easy.wave_budget_mult() < Difficulty::Hard.wave_budget_mult());
+        assert!(Difficulty::Easy.income_mult() > Difficulty::Hard.income_mult());
+        assert!(Difficulty::Easy.repair_cost_mult() < Difficulty::Hard.repair_cost_mult());
+        assert!(Difficulty::Easy.unlock_wave_mult() < Difficulty::Hard.unlock_wave_mult());
+    }
+}
@@ -0,0 +1,48 @@
+use crate::engine::config;
+
+/// Price multiplier from the relations meter: 1.0 at full relations, up to
+/// 1 + RELATIONS_MAX_SURCHARGE when relations have collapsed.
+pub fn cost_multiplier(relations: f32) -> f32 {
+    let strain = 1.0 - (relations / config::RELATIONS_MAX).clamp(0.0, 1.0);
+    1.0 + strain * config::RELATIONS_MAX_SURCHARGE
+}
+
+/// Cost to form an alliance with a region, as a share of its expansion cost.
+pub fn alliance_cost(expansion_cost: u32, relations: f32) -> u32 {
+    (expansion_cost as f32 * config::ALLIANCE_COST_FRACTION * cost_multiplier(relations)).ceil() as u32
+}
+
+/// Cost to lease a battery slot in an allied region.
+pub fn lease_cost(place_battery_cost: u32, relations: f32) -> u32 {
+    (place_battery_cost as f32 * config::LEASE_COST_FRACTION * cost_multiplier(relations)).ceil() as u32
+}
+
+/// Relations after allied cities took `damage` total HP during a wave.
+pub fn relations_after_damage(relations: f32, damage: f32) -> f32 {
+    (relations - damage.max(0.0) * config::RELATIONS_PENALTY_PER_HP).clamp(0.0, config::RELATIONS_MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_relations_have_no_surcharge() {
+        assert_eq!(cost_multiplier(config::RELATIONS_MAX), 1.0);
+        assert_eq!(alliance_cost(200, config::RELATIONS_MAX), 100);
+    }
+
+    #[test]
+    fn worse_relations_raise_costs() {
+        let good = lease_cost(100, config::RELATIONS_MAX);
+        let bad = lease_cost(100, 0.0);
+        assert!(bad > good, "Collapsed relations ({bad}) should cost more than full ({good})");
+    }
+
+    #[test]
+    fn damage_lowers_relations_and_clamps() {
+        let after = relations_after_damage(config::RELATIONS_START, 10.0);
+        assert!(after < config::RELATIONS_START);
+        assert_eq!(relations_after_damage(1.0, 10_000.0), 0.0);
+    }
+}
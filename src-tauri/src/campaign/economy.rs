@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::campaign::difficulty::Difficulty;
+
 /// Cost table for strategic actions
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CostTable {
@@ -18,6 +20,18 @@ impl Default for CostTable {
     }
 }
 
+impl CostTable {
+    /// Costs for a campaign at the given difficulty.
+    pub fn for_difficulty(difficulty: Difficulty) -> Self {
+        let base = Self::default();
+        Self {
+            repair_cost_per_hp: ((base.repair_cost_per_hp as f32 * difficulty.repair_cost_mult()).round() as u32)
+                .max(1),
+            ..base
+        }
+    }
+}
+
 /// Calculate resources earned at end of a wave.
 /// Each surviving city contributes: (population * health_ratio * region_multiplier) / 10
 pub fn calculate_wave_income(
@@ -30,6 +44,11 @@ pub fn calculate_wave_income(
     (total / 10.0).ceil() as u32
 }
 
+/// Scale wave income for the campaign difficulty.
+pub fn apply_difficulty(income: u32, difficulty: Difficulty) -> u32 {
+    (income as f32 * difficulty.income_mult()).round() as u32
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -69,4 +88,16 @@ mod tests {
         // 600 * 1.0 * 1.5 / 10 = 90
         assert_eq!(income, 90);
     }
+
+    #[test]
+    fn difficulty_scales_income_and_repairs() {
+        assert_eq!(apply_difficulty(100, Difficulty::Normal), 100);
+        assert!(apply_difficulty(100, Difficulty::Easy) > 100);
+        assert!(apply_difficulty(100, Difficulty::Hard) < 100);
+
+        let normal = CostTable::for_difficulty(Difficulty::Normal);
+        assert_eq!(normal.repair_cost_per_hp, CostTable::default().repair_cost_per_hp);
+        assert!(CostTable::for_difficulty(Difficulty::Easy).repair_cost_per_hp < normal.repair_cost_per_hp);
+        assert!(CostTable::for_difficulty(Difficulty::Hard).repair_cost_per_hp > normal.repair_cost_per_hp);
+    }
 }
@@ -0,0 +1,193 @@
+use serde::{Deserialize, Serialize};
+
+use crate::engine::config;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum WaveGrade {
+    S,
+    A,
+    B,
+    C,
+    D,
+    F,
+}
+
+impl WaveGrade {
+    pub fn from_score(score: u32) -> Self {
+        match score {
+            95.. => Self::S,
+            85.. => Self::A,
+            70.. => Self::B,
+            55.. => Self::C,
+            40.. => Self::D,
+            _ => Self::F,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::S => "S",
+            Self::A => "A",
+            Self::B => "B",
+            Self::C => "C",
+            Self::D => "D",
+            Self::F => "F",
+        }
+    }
+}
+
+/// Graded result of one wave, kept in campaign history.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WaveScore {
+    pub wave_number: u32,
+    pub grade: WaveGrade,
+    /// 0..=100
+    pub score: u32,
+    pub missiles_destroyed: u32,
+    pub interceptors_launched: u32,
+    pub city_damage: f32,
+    /// Interceptors launched beyond the number of threats that materialized
+    pub overkill: u32,
+}
+
+/// Campaign-long achievements earned from the wave history.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Medal {
+    /// MEDAL_SHARPSHOOTER_WAVES waves graded A or better
+    Sharpshooter,
+    /// MEDAL_IRON_DOME_STREAK consecutive waves without city damage
+    IronDome,
+    /// MEDAL_ACE_WAVES waves graded S
+    Ace,
+    /// MEDAL_VETERAN_WAVES waves survived
+    Veteran,
+}
+
+impl Medal {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Sharpshooter => "Sharpshooter",
+            Self::IronDome => "IronDome",
+            Self::Ace => "Ace",
+            Self::Veteran => "Veteran",
+        }
+    }
+}
+
+/// Grade a wave from its interception efficiency, city damage, and overkill.
+///
+/// Efficiency (kills per interceptor) and protection (city damage against
+/// GRADE_DAMAGE_FAIL) are worth 40 points each; the last 20 are lost at
+/// GRADE_OVERKILL_PENALTY points per wasted interceptor. Interceptors beyond
+/// the threats destroyed, impacted or exited count as wasted.
+pub fn grade_wave(
+    wave_number: u32,
+    missiles_destroyed: u32,
+    missiles_impacted: u32,
+    missiles_exited: u32,
+    interceptors_launched: u32,
+    city_damage: f32,
+) -> WaveScore {
+    let efficiency = if interceptors_launched > 0 {
+        (missiles_destroyed as f32 / interceptors_launched as f32).min(1.0)
+    } else if missiles_impacted == 0 {
+        1.0
+    } else {
+        0.0
+    };
+    let protection = (1.0 - city_damage / config::GRADE_DAMAGE_FAIL).clamp(0.0, 1.0);
+    let overkill = interceptors_launched.saturating_sub(missiles_destroyed + missiles_impacted + missiles_exited);
+    let discipline = 20u32.saturating_sub(overkill * config::GRADE_OVERKILL_PENALTY);
+
+    let score = (efficiency * 40.0 + protection * 40.0).round() as u32 + discipline;
+    WaveScore {
+        wave_number,
+        grade: WaveGrade::from_score(score),
+        score,
+        missiles_destroyed,
+        interceptors_launched,
+        city_damage,
+        overkill,
+    }
+}
+
+/// All medals the wave history qualifies for, in a fixed order.
+pub fn earned_medals(history: &[WaveScore]) -> Vec<Medal> {
+    let mut medals = Vec::new();
+
+    let good_waves = history.iter().filter(|s| s.grade <= WaveGrade::A).count() as u32;
+    if good_waves >= config::MEDAL_SHARPSHOOTER_WAVES {
+        medals.push(Medal::Sharpshooter);
+    }
+
+    let mut streak = 0u32;
+    let mut best_streak = 0u32;
+    for s in history {
+        streak = if s.city_damage <= 0.0 { streak + 1 } else { 0 };
+        best_streak = best_streak.max(streak);
+    }
+    if best_streak >= config::MEDAL_IRON_DOME_STREAK {
+        medals.push(Medal::IronDome);
+    }
+
+    let s_waves = history.iter().filter(|s| s.grade == WaveGrade::S).count() as u32;
+    if s_waves >= config::MEDAL_ACE_WAVES {
+        medals.push(Medal::Ace);
+    }
+
+    if history.len() as u32 >= config::MEDAL_VETERAN_WAVES {
+        medals.push(Medal::Veteran);
+    }
+
+    medals
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn perfect_wave_grades_s() {
+        let score = grade_wave(1, 5, 0, 0, 5, 0.0);
+        assert_eq!(score.score, 100);
+        assert_eq!(score.grade, WaveGrade::S);
+    }
+
+    #[test]
+    fn city_damage_lowers_grade() {
+        let clean = grade_wave(1, 5, 0, 0, 5, 0.0);
+        let hurt = grade_wave(1, 5, 1, 0, 5, 75.0);
+        assert!(hurt.score < clean.score);
+        assert!(hurt.grade > clean.grade);
+    }
+
+    #[test]
+    fn overkill_is_penalized() {
+        let tidy = grade_wave(1, 5, 0, 0, 5, 0.0);
+        let wasteful = grade_wave(1, 5, 0, 0, 9, 0.0);
+        assert_eq!(wasteful.overkill, 4);
+        assert!(wasteful.score < tidy.score);
+    }
+
+    #[test]
+    fn exited_threats_are_not_overkill() {
+        // Two of five interceptors were fired at threats that left the theater
+        let exited = grade_wave(1, 3, 0, 2, 5, 0.0);
+        let wasted = grade_wave(1, 3, 0, 0, 5, 0.0);
+        assert_eq!(exited.overkill, 0);
+        assert_eq!(wasted.overkill, 2);
+        assert!(exited.score > wasted.score);
+    }
+
+    #[test]
+    fn iron_dome_needs_consecutive_clean_waves() {
+        let clean = |n| grade_wave(n, 3, 0, 0, 3, 0.0);
+        let dirty = |n| grade_wave(n, 3, 1, 0, 3, 30.0);
+
+        let broken: Vec<WaveScore> = vec![clean(1), clean(2), dirty(3), clean(4), clean(5)];
+        assert!(!earned_medals(&broken).contains(&Medal::IronDome));
+
+        let streak: Vec<WaveScore> = (1..=config::MEDAL_IRON_DOME_STREAK).map(clean).collect();
+        assert!(earned_medals(&streak).contains(&Medal::IronDome));
+    }
+}
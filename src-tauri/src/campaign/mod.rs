@@ -1,4 +1,10 @@
+pub mod custom_map;
+pub mod difficulty;
+pub mod diplomacy;
 pub mod economy;
+pub mod grading;
+pub mod stats;
+pub mod strategic_events;
 pub mod territory;
 pub mod upgrades;
 pub mod wave_composer;
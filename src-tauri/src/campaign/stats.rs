@@ -0,0 +1,259 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::campaign::grading::WaveScore;
+use crate::ecs::components::InterceptorType;
+
+const INTERCEPTOR_TYPES: [InterceptorType; 4] = [
+    InterceptorType::Standard,
+    InterceptorType::Sprint,
+    InterceptorType::Exoatmospheric,
+    InterceptorType::AreaDenial,
+];
+
+/// Where resources came from or went.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ResourceCategory {
+    WaveIncome,
+    PrestigeBonus,
+    Expansion,
+    Alliances,
+    Batteries,
+    Restock,
+    Repairs,
+    Research,
+    Events,
+}
+
+impl ResourceCategory {
+    const ALL: [ResourceCategory; 9] = [
+        Self::WaveIncome,
+        Self::PrestigeBonus,
+        Self::Expansion,
+        Self::Alliances,
+        Self::Batteries,
+        Self::Restock,
+        Self::Repairs,
+        Self::Research,
+        Self::Events,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::WaveIncome => "WaveIncome",
+            Self::PrestigeBonus => "PrestigeBonus",
+            Self::Expansion => "Expansion",
+            Self::Alliances => "Alliances",
+            Self::Batteries => "Batteries",
+            Self::Restock => "Restock",
+            Self::Repairs => "Repairs",
+            Self::Research => "Research",
+            Self::Events => "Events",
+        }
+    }
+}
+
+/// Running campaign totals, saved with the campaign.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LifetimeStats {
+    pub waves_recorded: u32,
+    pub missiles_intercepted: u32,
+    pub missiles_impacted: u32,
+    pub interceptors_launched: u32,
+    pub launches_by_type: HashMap<InterceptorType, u32>,
+    /// Missiles destroyed by a shockwave traced back to each interceptor type
+    pub kills_by_type: HashMap<InterceptorType, u32>,
+    pub earned: HashMap<ResourceCategory, u32>,
+    pub spent: HashMap<ResourceCategory, u32>,
+    /// Cities defended, summed over waves, and how many of those survived the wave
+    pub city_waves: u32,
+    pub city_waves_intact: u32,
+    /// Rebuilt from wave history, which lacks impacts, per-type kills, resources and city uptime
+    #[serde(default)]
+    pub partial: bool,
+}
+
+impl LifetimeStats {
+    /// Rebuild what the wave history still records, for saves made before stats were tracked.
+    /// Saves older than the wave history only know how many waves were survived.
+    pub fn from_wave_scores(scores: &[WaveScore], waves_survived: u32) -> Self {
+        Self {
+            waves_recorded: (scores.len() as u32).max(waves_survived),
+            missiles_intercepted: scores.iter().map(|s| s.missiles_destroyed).sum(),
+            interceptors_launched: scores.iter().map(|s| s.interceptors_launched).sum(),
+            partial: true,
+            ..Self::default()
+        }
+    }
+
+    pub fn record_launch(&mut self, itype: InterceptorType) {
+        self.interceptors_launched += 1;
+        *self.launches_by_type.entry(itype).or_default() += 1;
+    }
+
+    pub fn record_kill(&mut self, itype: InterceptorType) {
+        *self.kills_by_type.entry(itype).or_default() += 1;
+    }
+
+    pub fn record_earned(&mut self, category: ResourceCategory, amount: u32) {
+        if amount > 0 {
+            *self.earned.entry(category).or_default() += amount;
+        }
+    }
+
+    pub fn record_spent(&mut self, category: ResourceCategory, amount: u32) {
+        if amount > 0 {
+            *self.spent.entry(category).or_default() += amount;
+        }
+    }
+
+    pub fn record_wave(&mut self, destroyed: u32, impacted: u32, cities: u32, cities_intact: u32) {
+        self.waves_recorded += 1;
+        self.missiles_intercepted += destroyed;
+        self.missiles_impacted += impacted;
+        self.city_waves += cities;
+        self.city_waves_intact += cities_intact;
+    }
+
+    /// Aggregate into the shape the statistics screen displays.
+    pub fn aggregate(&self) -> CampaignStats {
+        let by_type = INTERCEPTOR_TYPES
+            .iter()
+            .filter_map(|itype| {
+                let launched = self.launches_by_type.get(itype).copied().unwrap_or(0);
+                let kills = self.kills_by_type.get(itype).copied().unwrap_or(0);
+                (launched > 0 || kills > 0).then(|| InterceptorTypeStats {
+                    interceptor_type: itype.as_str().to_string(),
+                    launched,
+                    kills,
+                    kills_per_interceptor: ratio(kills, launched),
+                })
+            })
+            .collect();
+        let lines = |map: &HashMap<ResourceCategory, u32>| -> Vec<ResourceLine> {
+            ResourceCategory::ALL
+                .iter()
+                .filter_map(|c| {
+                    map.get(c).map(|&amount| ResourceLine {
+                        category: c.as_str().to_string(),
+                        amount,
+                    })
+                })
+                .collect()
+        };
+
+        CampaignStats {
+            waves_recorded: self.waves_recorded,
+            missiles_intercepted: self.missiles_intercepted,
+            missiles_impacted: self.missiles_impacted,
+            interceptors_launched: self.interceptors_launched,
+            // Backfilled history never counted impacts, so a rate would read near 100%
+            interception_rate: (!self.partial).then(|| {
+                ratio(self.missiles_intercepted, self.missiles_intercepted + self.missiles_impacted)
+            }),
+            partial: self.partial,
+            by_type,
+            earned: lines(&self.earned),
+            spent: lines(&self.spent),
+            total_earned: self.earned.values().sum(),
+            total_spent: self.spent.values().sum(),
+            city_uptime: ratio(self.city_waves_intact, self.city_waves),
+        }
+    }
+}
+
+fn ratio(num: u32, den: u32) -> f32 {
+    if den == 0 { 0.0 } else { num as f32 / den as f32 }
+}
+
+/// Aggregated lifetime stats sent to the frontend statistics screen.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CampaignStats {
+    pub waves_recorded: u32,
+    pub missiles_intercepted: u32,
+    pub missiles_impacted: u32,
+    pub interceptors_launched: u32,
+    /// Share of resolved missiles that were intercepted rather than impacting;
+    /// unknown for partial stats
+    pub interception_rate: Option<f32>,
+    pub by_type: Vec<InterceptorTypeStats>,
+    pub earned: Vec<ResourceLine>,
+    pub spent: Vec<ResourceLine>,
+    pub total_earned: u32,
+    pub total_spent: u32,
+    /// Share of city-waves that ended with the city still standing
+    pub city_uptime: f32,
+    /// Some totals were rebuilt from a save made before stats were tracked
+    pub partial: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InterceptorTypeStats {
+    pub interceptor_type: String,
+    pub launched: u32,
+    pub kills: u32,
+    /// Kills per launch; chain reactions can push this above 1.0
+    pub kills_per_interceptor: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResourceLine {
+    pub category: String,
+    pub amount: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::campaign::grading::WaveGrade;
+
+    #[test]
+    fn aggregate_computes_rates_and_totals() {
+        let mut stats = LifetimeStats::default();
+        for _ in 0..4 {
+            stats.record_launch(InterceptorType::Sprint);
+        }
+        stats.record_kill(InterceptorType::Sprint);
+        stats.record_kill(InterceptorType::Sprint);
+        stats.record_earned(ResourceCategory::WaveIncome, 300);
+        stats.record_spent(ResourceCategory::Repairs, 50);
+        stats.record_spent(ResourceCategory::Research, 100);
+        stats.record_wave(6, 2, 4, 3);
+
+        let agg = stats.aggregate();
+        assert_eq!(agg.by_type.len(), 1);
+        assert_eq!(agg.by_type[0].kills_per_interceptor, 0.5);
+        assert_eq!(agg.interception_rate, Some(0.75));
+        assert!(!agg.partial);
+        assert_eq!(agg.city_uptime, 0.75);
+        assert_eq!(agg.total_earned, 300);
+        assert_eq!(agg.total_spent, 150);
+        assert_eq!(agg.spent[0].category, "Repairs");
+    }
+
+    #[test]
+    fn backfilled_stats_are_partial() {
+        let score = WaveScore {
+            wave_number: 1,
+            grade: WaveGrade::A,
+            score: 90,
+            missiles_destroyed: 8,
+            interceptors_launched: 10,
+            city_damage: 0.0,
+            overkill: 2,
+        };
+        let agg = LifetimeStats::from_wave_scores(&[score], 1).aggregate();
+        assert!(agg.partial);
+        assert_eq!(agg.missiles_intercepted, 8);
+        assert_eq!(agg.interception_rate, None, "Impacts were never recorded");
+    }
+
+    #[test]
+    fn stats_roundtrip_through_json() {
+        let mut stats = LifetimeStats::default();
+        stats.record_launch(InterceptorType::AreaDenial);
+        stats.record_earned(ResourceCategory::PrestigeBonus, 200);
+        let json = serde_json::to_string(&stats).unwrap();
+        assert_eq!(serde_json::from_str::<LifetimeStats>(&json).unwrap(), stats);
+    }
+}
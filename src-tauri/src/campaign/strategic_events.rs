@@ -0,0 +1,126 @@
+use rand::Rng;
+use rand_chacha::ChaChaRng;
+use serde::{Deserialize, Serialize};
+
+use crate::campaign::territory::RegionId;
+use crate::engine::config;
+
+/// A crisis rolled between waves. The player accepts (paying `cost`) or declines.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum StrategicEvent {
+    /// Accept: pay for a security sweep. Decline: the battery loses `ammo_lost` ammo.
+    Sabotage {
+        region_id: RegionId,
+        slot_index: usize,
+        ammo_lost: u32,
+        cost: u32,
+    },
+    /// Accept: buy a preview of the next wave's composition.
+    IntelWindfall { cost: u32 },
+    /// Accept: pay resettlement costs and the city grows by `population`.
+    RefugeeInflux {
+        region_id: RegionId,
+        city_index: usize,
+        population: u32,
+        cost: u32,
+    },
+}
+
+impl StrategicEvent {
+    pub fn cost(&self) -> u32 {
+        match self {
+            Self::Sabotage { cost, .. } | Self::IntelWindfall { cost } | Self::RefugeeInflux { cost, .. } => *cost,
+        }
+    }
+}
+
+/// Expected composition of the next wave, revealed by an intel windfall.
+/// Computed for clear weather, since weather is rolled when the wave starts.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WavePreview {
+    pub wave_number: u32,
+    pub missile_count: u32,
+    pub mirv_count: u32,
+    pub sea_skimmer_count: u32,
+}
+
+/// Roll for a strategic event between waves.
+///
+/// `armed_batteries` are (region, slot) pairs with ammo to sabotage;
+/// `living_cities` are (region, city) pairs that can take in refugees.
+/// Event kinds without a valid target are skipped.
+pub fn roll_event(
+    rng: &mut ChaChaRng,
+    armed_batteries: &[(RegionId, usize)],
+    living_cities: &[(RegionId, usize)],
+) -> Option<StrategicEvent> {
+    if !rng.gen_bool(config::STRATEGIC_EVENT_CHANCE) {
+        return None;
+    }
+
+    let mut kinds = vec![1u8];
+    if !armed_batteries.is_empty() {
+        kinds.push(0);
+    }
+    if !living_cities.is_empty() {
+        kinds.push(2);
+    }
+
+    let event = match kinds[rng.gen_range(0..kinds.len())] {
+        0 => {
+            let (region_id, slot_index) = armed_batteries[rng.gen_range(0..armed_batteries.len())];
+            StrategicEvent::Sabotage {
+                region_id,
+                slot_index,
+                ammo_lost: config::SABOTAGE_AMMO_LOST,
+                cost: config::SABOTAGE_SWEEP_COST,
+            }
+        }
+        2 => {
+            let (region_id, city_index) = living_cities[rng.gen_range(0..living_cities.len())];
+            StrategicEvent::RefugeeInflux {
+                region_id,
+                city_index,
+                population: config::REFUGEE_POPULATION,
+                cost: config::REFUGEE_RESETTLE_COST,
+            }
+        }
+        _ => StrategicEvent::IntelWindfall {
+            cost: config::INTEL_COST,
+        },
+    };
+    Some(event)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    fn roll_many(armed: &[(RegionId, usize)], living: &[(RegionId, usize)]) -> Vec<StrategicEvent> {
+        let mut rng = ChaChaRng::seed_from_u64(3);
+        (0..200).filter_map(|_| roll_event(&mut rng, armed, living)).collect()
+    }
+
+    #[test]
+    fn events_fire_some_of_the_time() {
+        let events = roll_many(&[(RegionId(0), 0)], &[(RegionId(0), 0)]);
+        assert!(!events.is_empty());
+        assert!(events.len() < 200);
+    }
+
+    #[test]
+    fn same_seed_rolls_same_events() {
+        let a = roll_many(&[(RegionId(0), 0)], &[(RegionId(0), 1)]);
+        let b = roll_many(&[(RegionId(0), 0)], &[(RegionId(0), 1)]);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn no_targets_only_yields_intel() {
+        let events = roll_many(&[], &[]);
+        assert!(events
+            .iter()
+            .all(|e| matches!(e, StrategicEvent::IntelWindfall { .. })));
+    }
+}
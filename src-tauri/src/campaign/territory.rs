@@ -10,6 +10,34 @@ pub enum TerrainType {
     Urban,
 }
 
+impl TerrainType {
+    /// Radar range multiplier for batteries placed in this terrain.
+    pub fn radar_multiplier(self) -> f32 {
+        match self {
+            TerrainType::Mountains => config::MOUNTAIN_RADAR_MULT,
+            _ => 1.0,
+        }
+    }
+
+    /// Income multiplier for cities in this terrain (on top of the region multiplier).
+    pub fn income_multiplier(self) -> f32 {
+        match self {
+            TerrainType::Plains => config::PLAINS_INCOME_MULT,
+            _ => 1.0,
+        }
+    }
+
+    /// Whether owning this terrain widens the enemy's flight-time window.
+    pub fn widens_flight_times(self) -> bool {
+        self == TerrainType::Mountains
+    }
+
+    /// Whether owning this terrain exposes the player to sea-skimming threats.
+    pub fn has_sea_approach(self) -> bool {
+        self == TerrainType::Coastal
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct RegionId(pub u32);
 
@@ -42,6 +70,41 @@ pub struct Region {
     pub map_y: f32,
 }
 
+/// Playable theater: entities leaving it are removed by the cleanup system.
+/// Defaults to the screen plus OOB_MARGIN on every side.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TheaterBounds {
+    pub min_x: f32,
+    pub max_x: f32,
+    pub min_y: f32,
+    pub max_y: f32,
+}
+
+impl Default for TheaterBounds {
+    fn default() -> Self {
+        Self {
+            min_x: -config::OOB_MARGIN,
+            max_x: config::WORLD_WIDTH + config::OOB_MARGIN,
+            min_y: -config::OOB_MARGIN,
+            max_y: config::WORLD_HEIGHT + config::OOB_MARGIN,
+        }
+    }
+}
+
+impl TheaterBounds {
+    pub fn contains(&self, x: f32, y: f32) -> bool {
+        (self.min_x..=self.max_x).contains(&x) && (self.min_y..=self.max_y).contains(&y)
+    }
+
+    /// Whether the theater covers the whole screen, where threats spawn and cities sit.
+    pub fn covers_screen(&self) -> bool {
+        self.min_x <= 0.0
+            && self.max_x >= config::WORLD_WIDTH
+            && self.min_y <= 0.0
+            && self.max_y >= config::WORLD_HEIGHT
+    }
+}
+
 /// Define all regions. Positions are within 1280x720 and non-overlapping.
 /// Region 0 (Homeland) matches the original hardcoded layout.
 pub fn define_regions() -> Vec<Region> {
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::campaign::difficulty::Difficulty;
 use crate::ecs::components::InterceptorType;
 use crate::engine::config::{self, InterceptorProfile};
 
@@ -24,6 +25,13 @@ pub fn unlock_gate(itype: InterceptorType) -> (u32, u32) {
     }
 }
 
+/// Unlock requirements with the wave gate scaled for the campaign difficulty.
+pub fn unlock_gate_for(itype: InterceptorType, difficulty: Difficulty) -> (u32, u32) {
+    let (min_wave, cost) = unlock_gate(itype);
+    let min_wave = ((min_wave as f32 * difficulty.unlock_wave_mult()).round() as u32).max(1);
+    (min_wave, cost)
+}
+
 /// Cost for a given upgrade axis at a given current level.
 /// Returns None if already at max level.
 pub fn upgrade_cost(axis: UpgradeAxis, current_level: u32) -> Option<u32> {
@@ -109,20 +117,26 @@ impl Default for TechTree {
 
 impl TechTree {
     /// Check if a type can be unlocked at the given wave number with available resources.
-    pub fn can_unlock(&self, itype: InterceptorType, wave_number: u32, resources: u32) -> bool {
+    pub fn can_unlock(&self, itype: InterceptorType, wave_number: u32, resources: u32, difficulty: Difficulty) -> bool {
         if self.unlocked_types.contains(&itype) {
             return false;
         }
-        let (min_wave, cost) = unlock_gate(itype);
+        let (min_wave, cost) = unlock_gate_for(itype, difficulty);
         wave_number >= min_wave && resources >= cost
     }
 
     /// Unlock an interceptor type. Returns the cost, or an error.
-    pub fn unlock(&mut self, itype: InterceptorType, wave_number: u32, resources: u32) -> Result<u32, String> {
+    pub fn unlock(
+        &mut self,
+        itype: InterceptorType,
+        wave_number: u32,
+        resources: u32,
+        difficulty: Difficulty,
+    ) -> Result<u32, String> {
         if self.unlocked_types.contains(&itype) {
             return Err("Type already unlocked".into());
         }
-        let (min_wave, cost) = unlock_gate(itype);
+        let (min_wave, cost) = unlock_gate_for(itype, difficulty);
         if wave_number < min_wave {
             return Err(format!("Requires wave {}, currently at wave {}", min_wave, wave_number));
         }
@@ -199,15 +213,23 @@ mod tests {
     #[test]
     fn sprint_unlockable_at_wave_8() {
         let tree = TechTree::default();
-        assert!(!tree.can_unlock(InterceptorType::Sprint, 7, 1000));
-        assert!(tree.can_unlock(InterceptorType::Sprint, 8, 200));
-        assert!(!tree.can_unlock(InterceptorType::Sprint, 8, 199));
+        assert!(!tree.can_unlock(InterceptorType::Sprint, 7, 1000, Difficulty::Normal));
+        assert!(tree.can_unlock(InterceptorType::Sprint, 8, 200, Difficulty::Normal));
+        assert!(!tree.can_unlock(InterceptorType::Sprint, 8, 199, Difficulty::Normal));
+    }
+
+    #[test]
+    fn hard_difficulty_delays_unlocks() {
+        let tree = TechTree::default();
+        assert!(!tree.can_unlock(InterceptorType::Sprint, 8, 200, Difficulty::Hard));
+        assert!(tree.can_unlock(InterceptorType::Sprint, 10, 200, Difficulty::Hard));
+        assert!(tree.can_unlock(InterceptorType::Sprint, 6, 200, Difficulty::Easy));
     }
 
     #[test]
     fn unlock_costs_resources() {
         let mut tree = TechTree::default();
-        let cost = tree.unlock(InterceptorType::Sprint, 8, 200).unwrap();
+        let cost = tree.unlock(InterceptorType::Sprint, 8, 200, Difficulty::Normal).unwrap();
         assert_eq!(cost, 200);
         assert!(tree.is_unlocked(InterceptorType::Sprint));
     }
@@ -215,8 +237,8 @@ mod tests {
     #[test]
     fn unlock_fails_if_already_unlocked() {
         let mut tree = TechTree::default();
-        tree.unlock(InterceptorType::Sprint, 8, 200).unwrap();
-        assert!(tree.unlock(InterceptorType::Sprint, 8, 200).is_err());
+        tree.unlock(InterceptorType::Sprint, 8, 200, Difficulty::Normal).unwrap();
+        assert!(tree.unlock(InterceptorType::Sprint, 8, 200, Difficulty::Normal).is_err());
     }
 
     #[test]
@@ -1,11 +1,15 @@
+use crate::campaign::difficulty::Difficulty;
+use crate::campaign::territory::TerrainType;
 use crate::engine::config;
 use crate::state::wave_state::WaveDefinition;
 use crate::state::weather::{WeatherCondition, WeatherState};
 
-/// Compose a wave definition based on wave number, territory size, and weather.
+/// Compose a wave definition based on wave number, owned territory, and weather.
 /// More owned regions = more missiles (stretched defenses).
 /// Storm/Severe weather increases missile count.
-pub fn compose_wave(wave_number: u32, owned_region_count: u32, weather: &WeatherState) -> WaveDefinition {
+/// Mountains widen the flight-time window; coastal regions add sea-skimmers.
+pub fn compose_wave(wave_number: u32, owned_terrains: &[TerrainType], weather: &WeatherState) -> WaveDefinition {
+    let owned_region_count = owned_terrains.len() as u32;
     let territory_factor = 1.0 + (owned_region_count as f32 - 1.0) * 0.15;
     let base_missiles = config::WAVE_BASE_MISSILES as f32
         + (wave_number.saturating_sub(1) * config::WAVE_MISSILES_PER_LEVEL) as f32;
@@ -20,8 +24,14 @@ pub fn compose_wave(wave_number: u32, owned_region_count: u32, weather: &Weather
         .saturating_sub(wave_number * 5)
         .max(30);
 
-    let flight_time_min = (config::MISSILE_FLIGHT_TIME_MIN - wave_number as f32 * 0.3).max(3.0);
-    let flight_time_max = (config::MISSILE_FLIGHT_TIME_MAX - wave_number as f32 * 0.5).max(5.0);
+    let mut flight_time_min = (config::MISSILE_FLIGHT_TIME_MIN - wave_number as f32 * 0.3).max(3.0);
+    let mut flight_time_max = (config::MISSILE_FLIGHT_TIME_MAX - wave_number as f32 * 0.5).max(5.0);
+    if owned_terrains.iter().any(|t| t.widens_flight_times()) {
+        let mid = (flight_time_min + flight_time_max) * 0.5;
+        let half = (flight_time_max - flight_time_min) * 0.5 * config::MOUNTAIN_FLIGHT_TIME_SPREAD;
+        flight_time_min = (mid - half).max(2.0);
+        flight_time_max = mid + half;
+    }
 
     // MIRVs appear starting at wave MIRV_FIRST_WAVE
     let (mirv_count, mirv_child_count) = if wave_number >= config::MIRV_FIRST_WAVE {
@@ -33,6 +43,11 @@ pub fn compose_wave(wave_number: u32, owned_region_count: u32, weather: &Weather
         (0, 0)
     };
 
+    // Sea-skimmers come from the non-MIRV share of the wave
+    let coastal_count = owned_terrains.iter().filter(|t| t.has_sea_approach()).count() as u32;
+    let sea_skimmer_count = (coastal_count * config::COASTAL_SKIMMERS_PER_REGION * (1 + wave_number / 10))
+        .min(missile_count.saturating_sub(mirv_count) / 3);
+
     WaveDefinition {
         missile_count,
         spawn_interval_ticks: spawn_interval,
@@ -40,7 +55,41 @@ pub fn compose_wave(wave_number: u32, owned_region_count: u32, weather: &Weather
         flight_time_max,
         mirv_count,
         mirv_child_count,
+        sea_skimmer_count,
+    }
+}
+
+/// Extra scaling for endless mode past ENDLESS_RAMP_WAVE: the missile count
+/// keeps growing, the spawn interval drops below the campaign floor, and MIRVs
+/// carry more children.
+pub fn apply_endless_scaling(mut def: WaveDefinition, wave_number: u32) -> WaveDefinition {
+    let waves_past = wave_number.saturating_sub(config::ENDLESS_RAMP_WAVE);
+    if waves_past == 0 {
+        return def;
+    }
+
+    let growth = 1.0 + waves_past as f32 * config::ENDLESS_MISSILE_GROWTH;
+    def.missile_count = (def.missile_count as f32 * growth).ceil() as u32;
+    def.spawn_interval_ticks = def
+        .spawn_interval_ticks
+        .saturating_sub(waves_past)
+        .max(config::ENDLESS_MIN_SPAWN_INTERVAL);
+    if def.mirv_count > 0 {
+        def.mirv_child_count = (def.mirv_child_count + waves_past / config::ENDLESS_MIRV_CHILD_STEP)
+            .min(config::ENDLESS_MAX_MIRV_CHILDREN);
     }
+    def
+}
+
+/// Scale the wave budget for the campaign difficulty. MIRVs and sea-skimmers
+/// keep their share of the (possibly smaller) wave.
+pub fn apply_difficulty(mut def: WaveDefinition, difficulty: Difficulty) -> WaveDefinition {
+    def.missile_count = ((def.missile_count as f32 * difficulty.wave_budget_mult()).ceil() as u32).max(1);
+    def.mirv_count = def.mirv_count.min(def.missile_count / 3);
+    def.sea_skimmer_count = def
+        .sea_skimmer_count
+        .min(def.missile_count.saturating_sub(def.mirv_count) / 3);
+    def
 }
 
 #[cfg(test)]
@@ -51,9 +100,17 @@ mod tests {
         WeatherState::default()
     }
 
+    fn plains(count: usize) -> Vec<TerrainType> {
+        vec![TerrainType::Plains; count]
+    }
+
+    fn homeland() -> Vec<TerrainType> {
+        plains(1)
+    }
+
     #[test]
     fn wave1_single_region_matches_original() {
-        let def = compose_wave(1, 1, &clear_weather());
+        let def = compose_wave(1, &homeland(), &clear_weather());
         let original = WaveDefinition::for_wave(1);
         assert_eq!(def.missile_count, original.missile_count);
         assert_eq!(def.spawn_interval_ticks, original.spawn_interval_ticks);
@@ -61,8 +118,8 @@ mod tests {
 
     #[test]
     fn more_territory_means_more_missiles() {
-        let def_1 = compose_wave(3, 1, &clear_weather());
-        let def_3 = compose_wave(3, 3, &clear_weather());
+        let def_1 = compose_wave(3, &homeland(), &clear_weather());
+        let def_3 = compose_wave(3, &plains(3), &clear_weather());
         assert!(
             def_3.missile_count > def_1.missile_count,
             "3 regions ({}) should have more missiles than 1 region ({})",
@@ -73,28 +130,28 @@ mod tests {
 
     #[test]
     fn wave_difficulty_increases_with_wave_number() {
-        let def_1 = compose_wave(1, 1, &clear_weather());
-        let def_5 = compose_wave(5, 1, &clear_weather());
+        let def_1 = compose_wave(1, &homeland(), &clear_weather());
+        let def_5 = compose_wave(5, &homeland(), &clear_weather());
         assert!(def_5.missile_count > def_1.missile_count);
         assert!(def_5.flight_time_max < def_1.flight_time_max);
     }
 
     #[test]
     fn no_mirv_before_wave_26() {
-        let def = compose_wave(25, 1, &clear_weather());
+        let def = compose_wave(25, &homeland(), &clear_weather());
         assert_eq!(def.mirv_count, 0, "No MIRVs before wave 26");
     }
 
     #[test]
     fn mirv_at_wave_26() {
-        let def = compose_wave(26, 1, &clear_weather());
+        let def = compose_wave(26, &homeland(), &clear_weather());
         assert!(def.mirv_count > 0, "MIRVs should appear at wave 26");
         assert_eq!(def.mirv_child_count, 3);
     }
 
     #[test]
     fn mirv_children_increase_at_wave_35() {
-        let def = compose_wave(35, 1, &clear_weather());
+        let def = compose_wave(35, &homeland(), &clear_weather());
         assert_eq!(def.mirv_child_count, 5, "Wave 35+ should have 5 MIRV children");
     }
 
@@ -105,8 +162,8 @@ mod tests {
             wind_x: 15.0,
             wind_y: 0.0,
         };
-        let clear_def = compose_wave(5, 1, &clear_weather());
-        let storm_def = compose_wave(5, 1, &storm);
+        let clear_def = compose_wave(5, &homeland(), &clear_weather());
+        let storm_def = compose_wave(5, &homeland(), &storm);
         assert!(
             storm_def.missile_count > clear_def.missile_count,
             "Storm ({}) should have more missiles than Clear ({})",
@@ -122,8 +179,8 @@ mod tests {
             wind_x: 30.0,
             wind_y: 0.0,
         };
-        let clear_def = compose_wave(5, 1, &clear_weather());
-        let severe_def = compose_wave(5, 1, &severe);
+        let clear_def = compose_wave(5, &homeland(), &clear_weather());
+        let severe_def = compose_wave(5, &homeland(), &severe);
         assert!(
             severe_def.missile_count > clear_def.missile_count,
             "Severe ({}) should have more missiles than Clear ({})",
@@ -131,4 +188,53 @@ mod tests {
             clear_def.missile_count
         );
     }
+
+    #[test]
+    fn mountains_widen_flight_time_window() {
+        let flat = compose_wave(5, &plains(2), &clear_weather());
+        let rugged = compose_wave(5, &[TerrainType::Plains, TerrainType::Mountains], &clear_weather());
+        assert_eq!(flat.missile_count, rugged.missile_count);
+        assert!(rugged.flight_time_min < flat.flight_time_min);
+        assert!(rugged.flight_time_max > flat.flight_time_max);
+    }
+
+    #[test]
+    fn coastal_regions_add_sea_skimmers() {
+        let inland = compose_wave(5, &plains(2), &clear_weather());
+        let coastal = compose_wave(5, &[TerrainType::Plains, TerrainType::Coastal], &clear_weather());
+        assert_eq!(inland.sea_skimmer_count, 0);
+        assert_eq!(coastal.sea_skimmer_count, 1);
+        assert!(coastal.sea_skimmer_count + coastal.mirv_count <= coastal.missile_count);
+    }
+
+    #[test]
+    fn endless_scaling_leaves_early_waves_alone() {
+        let def = compose_wave(config::ENDLESS_RAMP_WAVE, &homeland(), &clear_weather());
+        let endless = apply_endless_scaling(def.clone(), config::ENDLESS_RAMP_WAVE);
+        assert_eq!(endless.missile_count, def.missile_count);
+        assert_eq!(endless.spawn_interval_ticks, def.spawn_interval_ticks);
+    }
+
+    #[test]
+    fn endless_scaling_keeps_growing() {
+        let at = |wave| apply_endless_scaling(compose_wave(wave, &homeland(), &clear_weather()), wave);
+        let w40 = at(40);
+        let w80 = at(80);
+        assert!(w80.missile_count > w40.missile_count);
+        assert!(w80.spawn_interval_ticks < 30);
+        assert!(w80.mirv_child_count > w40.mirv_child_count);
+    }
+
+    #[test]
+    fn difficulty_scales_wave_budget() {
+        let def = compose_wave(30, &[TerrainType::Plains, TerrainType::Coastal], &clear_weather());
+        let easy = apply_difficulty(def.clone(), Difficulty::Easy);
+        let normal = apply_difficulty(def.clone(), Difficulty::Normal);
+        let hard = apply_difficulty(def.clone(), Difficulty::Hard);
+
+        assert_eq!(normal.missile_count, def.missile_count);
+        assert!(easy.missile_count < normal.missile_count);
+        assert!(hard.missile_count > normal.missile_count);
+        assert!(easy.mirv_count + easy.sea_skimmer_count <= easy.missile_count);
+    }
 }
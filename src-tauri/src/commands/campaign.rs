@@ -1,4 +1,7 @@
+use crate::campaign::difficulty::Difficulty;
+use crate::commands::persistence::maps_dir;
 use crate::engine::game_loop::{EngineCommand, GameEngine};
+use crate::persistence::map_files;
 
 #[tauri::command]
 pub fn start_wave(engine: tauri::State<'_, GameEngine>) {
@@ -23,6 +26,19 @@ pub fn place_battery(engine: tauri::State<'_, GameEngine>, region_id: u32, slot_
     });
 }
 
+#[tauri::command]
+pub fn form_alliance(engine: tauri::State<'_, GameEngine>, region_id: u32) {
+    engine.send_command(EngineCommand::FormAlliance { region_id });
+}
+
+#[tauri::command]
+pub fn lease_battery(engine: tauri::State<'_, GameEngine>, region_id: u32, slot_index: u32) {
+    engine.send_command(EngineCommand::LeaseBattery {
+        region_id,
+        slot_index,
+    });
+}
+
 #[tauri::command]
 pub fn restock_all_batteries(engine: tauri::State<'_, GameEngine>) {
     engine.send_command(EngineCommand::RestockAllBatteries);
@@ -43,14 +59,52 @@ pub fn upgrade_interceptor(engine: tauri::State<'_, GameEngine>, interceptor_typ
     engine.send_command(EngineCommand::UpgradeInterceptor { interceptor_type, axis });
 }
 
+#[tauri::command]
+pub fn resolve_strategic_event(engine: tauri::State<'_, GameEngine>, accept: bool) {
+    engine.send_command(EngineCommand::ResolveStrategicEvent { accept });
+}
+
 #[tauri::command]
 pub fn get_campaign_state(engine: tauri::State<'_, GameEngine>) {
     engine.send_command(EngineCommand::GetCampaignState);
 }
 
 #[tauri::command]
-pub fn new_game(engine: tauri::State<'_, GameEngine>) {
-    engine.send_command(EngineCommand::NewGame);
+pub fn get_campaign_stats(engine: tauri::State<'_, GameEngine>) {
+    engine.send_command(EngineCommand::GetCampaignStats);
+}
+
+#[tauri::command]
+pub fn new_game(
+    engine: tauri::State<'_, GameEngine>,
+    app: tauri::AppHandle,
+    endless: bool,
+    difficulty: Option<Difficulty>,
+    map_name: Option<String>,
+) -> Result<(), String> {
+    // Unknown difficulty names fail argument deserialization instead of becoming Normal
+    let difficulty = difficulty.unwrap_or_default();
+    let map = match map_name {
+        Some(name) => Some(Box::new(map_files::load_map(&maps_dir(&app), &name)?)),
+        None => None,
+    };
+    engine.send_command(EngineCommand::NewGame {
+        endless,
+        difficulty,
+        map,
+    });
+    Ok(())
+}
+
+#[tauri::command]
+pub fn prestige_reset(engine: tauri::State<'_, GameEngine>) {
+    engine.send_command(EngineCommand::Prestige);
+}
+
+/// Replay the current wave from the state it started with.
+#[tauri::command]
+pub fn restart_wave(engine: tauri::State<'_, GameEngine>) {
+    engine.send_command(EngineCommand::RestartWave);
 }
 
 #[tauri::command]
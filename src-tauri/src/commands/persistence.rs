@@ -1,35 +1,55 @@
 use crate::engine::game_loop::{EngineCommand, GameEngine};
+use crate::persistence::integrity::{self, IntegrityStatus};
+use crate::persistence::map_files::{self, MapMetadata};
 use crate::persistence::save_load::{self, SaveMetadata};
 use std::path::PathBuf;
 use tauri::Manager;
 
-fn saves_dir(app: &tauri::AppHandle) -> PathBuf {
+fn data_dir(app: &tauri::AppHandle) -> PathBuf {
     app.path()
         .app_data_dir()
         .unwrap_or_else(|_| PathBuf::from("."))
-        .join("saves")
 }
 
+fn saves_dir(app: &tauri::AppHandle) -> PathBuf {
+    data_dir(app).join("saves")
+}
+
+pub(crate) fn maps_dir(app: &tauri::AppHandle) -> PathBuf {
+    data_dir(app).join("maps")
+}
+
+/// Save the campaign. Fails up front if the signing key is missing or unreadable,
+/// so the player learns the save didn't happen.
 #[tauri::command]
-pub fn save_game(engine: tauri::State<'_, GameEngine>, app: tauri::AppHandle, slot_name: String) {
+pub fn save_game(engine: tauri::State<'_, GameEngine>, app: tauri::AppHandle, slot_name: String) -> Result<(), String> {
+    integrity::load_or_create_key(&data_dir(&app))?;
     let app_data_dir = saves_dir(&app);
     engine.send_command(EngineCommand::SaveGame {
         slot_name,
         app_data_dir,
     });
+    Ok(())
 }
 
+/// Load a save after checking its signature. Tampered saves are refused and
+/// reported back rather than loaded; unsigned saves from older versions load.
 #[tauri::command]
-pub fn load_game(engine: tauri::State<'_, GameEngine>, app: tauri::AppHandle, slot_name: String) {
-    let dir = saves_dir(&app);
-    match save_load::load_from_file(&dir, &slot_name) {
-        Ok(save_data) => {
-            engine.send_command(EngineCommand::LoadGame { save_data });
-        }
-        Err(e) => {
-            eprintln!("Failed to load game: {e}");
-        }
+pub fn load_game(
+    engine: tauri::State<'_, GameEngine>,
+    app: tauri::AppHandle,
+    slot_name: String,
+) -> Result<IntegrityStatus, String> {
+    let key = integrity::load_or_create_key(&data_dir(&app))?;
+    let (save_data, status) = save_load::load_from_file(&saves_dir(&app), &slot_name, &key)?;
+    if status == IntegrityStatus::Tampered {
+        eprintln!("Refusing to load '{slot_name}': save failed integrity check");
+        return Ok(status);
     }
+    engine.send_command(EngineCommand::LoadGame {
+        save_data: Box::new(save_data),
+    });
+    Ok(status)
 }
 
 #[tauri::command]
@@ -45,3 +65,8 @@ pub fn delete_save(app: tauri::AppHandle, slot_name: String) {
         eprintln!("Failed to delete save: {e}");
     }
 }
+
+#[tauri::command]
+pub fn list_maps(app: tauri::AppHandle) -> Vec<MapMetadata> {
+    map_files::list_maps(&maps_dir(&app))
+}
@@ -1,7 +1,9 @@
+use crate::campaign::territory::TheaterBounds;
 use crate::ecs::components::InterceptorType;
 use crate::engine::config;
 use crate::engine::game_loop::{EngineCommand, GameEngine};
-use crate::systems::arc_prediction::{self, ArcPrediction};
+use crate::systems::arc_prediction::ArcPrediction;
+use crate::systems::launch_solution;
 use crate::systems::input_system::PlayerCommand;
 
 #[tauri::command]
@@ -23,6 +25,35 @@ pub fn launch_interceptor(
     }));
 }
 
+#[tauri::command]
+pub fn salvo_intercept(
+    engine: tauri::State<'_, GameEngine>,
+    target_x: f32,
+    target_y: f32,
+    count: u32,
+    interceptor_type: Option<String>,
+) {
+    let itype = interceptor_type
+        .map(|s| InterceptorType::parse(&s))
+        .unwrap_or_default();
+    engine.send_command(EngineCommand::Player(PlayerCommand::SalvoIntercept {
+        target_x,
+        target_y,
+        count,
+        interceptor_type: itype,
+    }));
+}
+
+#[tauri::command]
+pub fn pause_wave(engine: tauri::State<'_, GameEngine>) {
+    engine.send_command(EngineCommand::Player(PlayerCommand::Pause));
+}
+
+#[tauri::command]
+pub fn resume_wave(engine: tauri::State<'_, GameEngine>) {
+    engine.send_command(EngineCommand::Player(PlayerCommand::Resume));
+}
+
 #[tauri::command]
 pub fn predict_arc(
     battery_x: f32,
@@ -31,10 +62,24 @@ pub fn predict_arc(
     target_y: f32,
     interceptor_type: Option<String>,
     wind_x: Option<f32>,
+    theater: Option<TheaterBounds>,
 ) -> ArcPrediction {
     let itype = interceptor_type
         .map(|s| InterceptorType::parse(&s))
         .unwrap_or_default();
     let profile = config::interceptor_profile(itype);
-    arc_prediction::predict_arc(battery_x, battery_y, target_x, target_y, &profile, wind_x.unwrap_or(0.0))
+    launch_solution::predict_corrected_arc(
+        battery_x,
+        battery_y,
+        target_x,
+        target_y,
+        &profile,
+        wind_x.unwrap_or(0.0),
+        &theater.unwrap_or_default(),
+    )
+}
+
+#[tauri::command]
+pub fn predict_impacts(engine: tauri::State<'_, GameEngine>) {
+    engine.send_command(EngineCommand::PredictImpacts);
 }
@@ -1,4 +1,6 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct Transform {
@@ -26,6 +28,15 @@ pub enum WarheadType {
     Mirv,
 }
 
+impl WarheadType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WarheadType::Standard => "Standard",
+            WarheadType::Mirv => "Mirv",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct Warhead {
     pub yield_force: f32,
@@ -33,7 +44,7 @@ pub struct Warhead {
     pub warhead_type: WarheadType,
 }
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub enum InterceptorType {
     #[default]
     Standard,
@@ -72,6 +83,10 @@ pub struct Interceptor {
     pub battery_id: u32,
     pub target_x: f32,
     pub target_y: f32,
+    /// Steering point for thrust. Offset from the target by the launch solution
+    /// so gravity drop and wind carry the interceptor onto the target point.
+    pub aim_x: f32,
+    pub aim_y: f32,
     /// Proximity fuse: auto-detonate when within this radius of any missile. 0.0 = disabled.
     pub proximity_fuse_radius: f32,
 }
@@ -81,6 +96,8 @@ pub struct MirvCarrier {
     pub child_count: u32,
     pub split_altitude: f32,
     pub spread_angle: f32,
+    /// Player-side estimate of split_altitude, set by detection while the bus is detected
+    pub estimated_split_altitude: Option<f32>,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
@@ -107,12 +124,24 @@ pub struct Shockwave {
     pub force: f32,
     pub expansion_rate: f32,
     pub damage_applied: bool,
+    /// Interceptor type credited with this blast's kills; chain reactions inherit it
+    #[serde(default)]
+    pub source: Option<InterceptorType>,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct BatteryState {
     pub ammo: u32,
     pub max_ammo: u32,
+    /// Ticks until the launcher can fire again
+    pub reload_remaining: u32,
+    /// Firing arc in radians, counter-clockwise from +x. Targets outside are rejected.
+    pub arc_min: f32,
+    pub arc_max: f32,
+    /// Interceptors from this battery currently in flight (refreshed by input_system)
+    pub in_flight: u32,
+    /// Radar range multiplier from the battery's terrain
+    pub radar_multiplier: f32,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -134,3 +163,9 @@ pub struct Detected {
     pub by_radar: bool,
     pub by_glow: bool,
 }
+
+/// Recent positions of a moving entity, oldest first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Trail {
+    pub points: VecDeque<(f32, f32)>,
+}
@@ -20,6 +20,9 @@ impl std::fmt::Display for EntityId {
 
 pub struct EntityAllocator {
     generations: Vec<u32>,
+    /// Whether each index is currently on the free list. A freed index already
+    /// carries its next generation, so liveness can't be inferred from generations alone.
+    free: Vec<bool>,
     free_indices: Vec<u32>,
     next_index: u32,
 }
@@ -28,6 +31,7 @@ impl EntityAllocator {
     pub fn new() -> Self {
         Self {
             generations: Vec::new(),
+            free: Vec::new(),
             free_indices: Vec::new(),
             next_index: 0,
         }
@@ -35,28 +39,32 @@ impl EntityAllocator {
 
     pub fn allocate(&mut self) -> EntityId {
         if let Some(index) = self.free_indices.pop() {
+            self.free[index as usize] = false;
             let generation = self.generations[index as usize];
             EntityId::new(index, generation)
         } else {
             let index = self.next_index;
             self.next_index += 1;
             self.generations.push(0);
+            self.free.push(false);
             EntityId::new(index, 0)
         }
     }
 
+    /// Free a live entity. Stale or already-freed IDs are ignored, so an index
+    /// can never be queued for reuse twice.
     pub fn deallocate(&mut self, id: EntityId) {
-        if (id.index as usize) < self.generations.len()
-            && self.generations[id.index as usize] == id.generation
-        {
-            self.generations[id.index as usize] += 1;
+        if self.is_alive(id) {
+            let idx = id.index as usize;
+            self.generations[idx] = self.generations[idx].wrapping_add(1);
+            self.free[idx] = true;
             self.free_indices.push(id.index);
         }
     }
 
     pub fn is_alive(&self, id: EntityId) -> bool {
-        (id.index as usize) < self.generations.len()
-            && self.generations[id.index as usize] == id.generation
+        let idx = id.index as usize;
+        idx < self.generations.len() && self.generations[idx] == id.generation && !self.free[idx]
     }
 
     /// Get the current generation for an index (used by cleanup to reconstruct EntityIds)
@@ -93,6 +101,22 @@ mod tests {
         assert!(!alloc.is_alive(e0));
     }
 
+    #[test]
+    fn freed_index_is_not_alive_under_its_next_generation() {
+        let mut alloc = EntityAllocator::new();
+        let e0 = alloc.allocate();
+        alloc.deallocate(e0);
+
+        // Reconstructing an ID from the bumped generation must not resurrect the slot
+        let ghost = EntityId::new(e0.index, alloc.generation_of(e0.index).unwrap());
+        assert!(!alloc.is_alive(ghost));
+        alloc.deallocate(ghost);
+
+        let a = alloc.allocate();
+        let b = alloc.allocate();
+        assert_ne!(a.index, b.index, "Index was queued for reuse twice");
+    }
+
     #[test]
     fn sequential_allocation() {
         let mut alloc = EntityAllocator::new();
@@ -1,26 +1,126 @@
 use super::components::*;
 use super::entity::{EntityAllocator, EntityId};
 
+/// Storage for one component type, indexed by entity index. Each slot is
+/// stamped with the generation it was inserted for, so an ID that outlived
+/// its entity never reads or writes whatever later reused the index.
+pub struct ComponentStorage<T> {
+    slots: Vec<Option<(u32, T)>>,
+}
+
+impl<T> ComponentStorage<T> {
+    fn new() -> Self {
+        Self { slots: Vec::new() }
+    }
+
+    pub fn get(&self, id: EntityId) -> Option<&T> {
+        match self.slots.get(id.index as usize)? {
+            Some((generation, value)) if *generation == id.generation => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, id: EntityId) -> Option<&mut T> {
+        match self.slots.get_mut(id.index as usize)? {
+            Some((generation, value)) if *generation == id.generation => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn contains(&self, id: EntityId) -> bool {
+        self.get(id).is_some()
+    }
+
+    /// Take the component off an entity. Stale IDs remove nothing.
+    pub fn remove(&mut self, id: EntityId) -> Option<T> {
+        let slot = self.slots.get_mut(id.index as usize)?;
+        match slot {
+            Some((generation, _)) if *generation == id.generation => slot.take().map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    /// Every entity holding this component, in index order.
+    pub fn iter(&self) -> impl Iterator<Item = (EntityId, &T)> + '_ {
+        self.slots.iter().enumerate().filter_map(|(i, slot)| {
+            slot.as_ref()
+                .map(|(generation, value)| (EntityId::new(i as u32, *generation), value))
+        })
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (EntityId, &mut T)> + '_ {
+        self.slots.iter_mut().enumerate().filter_map(|(i, slot)| {
+            slot.as_mut()
+                .map(|(generation, value)| (EntityId::new(i as u32, *generation), value))
+        })
+    }
+
+    fn set(&mut self, id: EntityId, value: T) {
+        let idx = id.index as usize;
+        if self.slots.len() <= idx {
+            self.slots.resize_with(idx + 1, || None);
+        }
+        self.slots[idx] = Some((id.generation, value));
+    }
+
+    fn clear(&mut self, index: usize) {
+        if let Some(slot) = self.slots.get_mut(index) {
+            *slot = None;
+        }
+    }
+}
+
+/// A type stored in one of the World's component storages.
+pub trait Component: Sized {
+    fn storage_mut(world: &mut World) -> &mut ComponentStorage<Self>;
+}
+
+macro_rules! component {
+    ($ty:ty, $field:ident) => {
+        impl Component for $ty {
+            fn storage_mut(world: &mut World) -> &mut ComponentStorage<Self> {
+                &mut world.$field
+            }
+        }
+    };
+}
+
+component!(Transform, transforms);
+component!(Velocity, velocities);
+component!(Ballistic, ballistics);
+component!(Warhead, warheads);
+component!(Interceptor, interceptors);
+component!(Lifetime, lifetimes);
+component!(Health, healths);
+component!(ReentryGlow, reentry_glows);
+component!(Shockwave, shockwaves);
+component!(EntityMarker, markers);
+component!(BatteryState, battery_states);
+component!(MirvCarrier, mirv_carriers);
+component!(Detected, detected);
+component!(Trail, trails);
+
 /// SoA (Struct of Arrays) ECS World.
-/// Each component type has its own Vec<Option<T>> storage, indexed by entity index.
+/// Each component type has its own storage, reached only through generation-checked IDs.
 pub struct World {
     pub allocator: EntityAllocator,
     alive: Vec<bool>,
 
-    // Component storage — one Vec per component type
-    pub transforms: Vec<Option<Transform>>,
-    pub velocities: Vec<Option<Velocity>>,
-    pub ballistics: Vec<Option<Ballistic>>,
-    pub warheads: Vec<Option<Warhead>>,
-    pub interceptors: Vec<Option<Interceptor>>,
-    pub lifetimes: Vec<Option<Lifetime>>,
-    pub healths: Vec<Option<Health>>,
-    pub reentry_glows: Vec<Option<ReentryGlow>>,
-    pub shockwaves: Vec<Option<Shockwave>>,
-    pub markers: Vec<Option<EntityMarker>>,
-    pub battery_states: Vec<Option<BatteryState>>,
-    pub mirv_carriers: Vec<Option<MirvCarrier>>,
-    pub detected: Vec<Option<Detected>>,
+    // Component storage — one per component type
+    pub transforms: ComponentStorage<Transform>,
+    pub velocities: ComponentStorage<Velocity>,
+    pub ballistics: ComponentStorage<Ballistic>,
+    pub warheads: ComponentStorage<Warhead>,
+    pub interceptors: ComponentStorage<Interceptor>,
+    pub lifetimes: ComponentStorage<Lifetime>,
+    pub healths: ComponentStorage<Health>,
+    pub reentry_glows: ComponentStorage<ReentryGlow>,
+    pub shockwaves: ComponentStorage<Shockwave>,
+    pub markers: ComponentStorage<EntityMarker>,
+    pub battery_states: ComponentStorage<BatteryState>,
+    pub mirv_carriers: ComponentStorage<MirvCarrier>,
+    pub detected: ComponentStorage<Detected>,
+    pub trails: ComponentStorage<Trail>,
 }
 
 impl World {
@@ -28,19 +128,20 @@ impl World {
         Self {
             allocator: EntityAllocator::new(),
             alive: Vec::new(),
-            transforms: Vec::new(),
-            velocities: Vec::new(),
-            ballistics: Vec::new(),
-            warheads: Vec::new(),
-            interceptors: Vec::new(),
-            lifetimes: Vec::new(),
-            healths: Vec::new(),
-            reentry_glows: Vec::new(),
-            shockwaves: Vec::new(),
-            markers: Vec::new(),
-            battery_states: Vec::new(),
-            mirv_carriers: Vec::new(),
-            detected: Vec::new(),
+            transforms: ComponentStorage::new(),
+            velocities: ComponentStorage::new(),
+            ballistics: ComponentStorage::new(),
+            warheads: ComponentStorage::new(),
+            interceptors: ComponentStorage::new(),
+            lifetimes: ComponentStorage::new(),
+            healths: ComponentStorage::new(),
+            reentry_glows: ComponentStorage::new(),
+            shockwaves: ComponentStorage::new(),
+            markers: ComponentStorage::new(),
+            battery_states: ComponentStorage::new(),
+            mirv_carriers: ComponentStorage::new(),
+            detected: ComponentStorage::new(),
+            trails: ComponentStorage::new(),
         }
     }
 
@@ -48,47 +149,42 @@ impl World {
         let id = self.allocator.allocate();
         let idx = id.index as usize;
 
-        // Grow all storage to accommodate
-        while self.alive.len() <= idx {
-            self.alive.push(false);
-            self.transforms.push(None);
-            self.velocities.push(None);
-            self.ballistics.push(None);
-            self.warheads.push(None);
-            self.interceptors.push(None);
-            self.lifetimes.push(None);
-            self.healths.push(None);
-            self.reentry_glows.push(None);
-            self.shockwaves.push(None);
-            self.markers.push(None);
-            self.battery_states.push(None);
-            self.mirv_carriers.push(None);
-            self.detected.push(None);
+        // Recycled indices are already in range
+        if self.alive.len() <= idx {
+            self.alive.resize(idx + 1, false);
         }
 
         self.alive[idx] = true;
         id
     }
 
+    /// Attach (or replace) a component on a live entity. Stale IDs are ignored.
+    pub fn insert<C: Component>(&mut self, id: EntityId, component: C) {
+        if self.is_alive(id) {
+            C::storage_mut(self).set(id, component);
+        }
+    }
+
     pub fn despawn(&mut self, id: EntityId) {
-        if !self.allocator.is_alive(id) {
+        if !self.is_alive(id) {
             return;
         }
         let idx = id.index as usize;
         self.alive[idx] = false;
-        self.transforms[idx] = None;
-        self.velocities[idx] = None;
-        self.ballistics[idx] = None;
-        self.warheads[idx] = None;
-        self.interceptors[idx] = None;
-        self.lifetimes[idx] = None;
-        self.healths[idx] = None;
-        self.reentry_glows[idx] = None;
-        self.shockwaves[idx] = None;
-        self.markers[idx] = None;
-        self.battery_states[idx] = None;
-        self.mirv_carriers[idx] = None;
-        self.detected[idx] = None;
+        self.transforms.clear(idx);
+        self.velocities.clear(idx);
+        self.ballistics.clear(idx);
+        self.warheads.clear(idx);
+        self.interceptors.clear(idx);
+        self.lifetimes.clear(idx);
+        self.healths.clear(idx);
+        self.reentry_glows.clear(idx);
+        self.shockwaves.clear(idx);
+        self.markers.clear(idx);
+        self.battery_states.clear(idx);
+        self.mirv_carriers.clear(idx);
+        self.detected.clear(idx);
+        self.trails.clear(idx);
         self.allocator.deallocate(id);
     }
 
@@ -102,13 +198,23 @@ impl World {
         self.alive.iter().filter(|&&a| a).count()
     }
 
-    /// Iterate over all alive entity indices
-    pub fn alive_entities(&self) -> Vec<usize> {
-        self.alive
-            .iter()
-            .enumerate()
-            .filter_map(|(i, &alive)| if alive { Some(i) } else { None })
-            .collect()
+    /// All alive entities, in index order
+    pub fn alive_entities(&self) -> Vec<EntityId> {
+        self.entities().collect()
+    }
+
+    /// Alive entities without collecting them
+    pub fn entities(&self) -> impl Iterator<Item = EntityId> + '_ {
+        self.alive.iter().enumerate().filter_map(|(i, &alive)| {
+            let generation = self.allocator.generation_of(i as u32)?;
+            alive.then_some(EntityId::new(i as u32, generation))
+        })
+    }
+
+    /// Refill `out` with the alive entities, reusing its capacity
+    pub fn alive_entities_into(&self, out: &mut Vec<EntityId>) {
+        out.clear();
+        out.extend(self.entities());
     }
 }
 
@@ -138,20 +244,86 @@ mod tests {
     fn component_storage() {
         let mut world = World::new();
         let e = world.spawn();
-        let idx = e.index as usize;
 
-        world.transforms[idx] = Some(Transform {
-            x: 10.0,
-            y: 20.0,
-            rotation: 0.0,
-        });
-        world.velocities[idx] = Some(Velocity { vx: 1.0, vy: -2.0 });
+        world.insert(
+            e,
+            Transform {
+                x: 10.0,
+                y: 20.0,
+                rotation: 0.0,
+            },
+        );
+        world.insert(e, Velocity { vx: 1.0, vy: -2.0 });
 
-        assert!(world.transforms[idx].is_some());
-        assert!(world.velocities[idx].is_some());
-        assert!(world.ballistics[idx].is_none());
+        assert!(world.transforms.contains(e));
+        assert!(world.velocities.contains(e));
+        assert!(!world.ballistics.contains(e));
 
         world.despawn(e);
-        assert!(world.transforms[idx].is_none());
+        assert!(!world.transforms.contains(e));
+    }
+
+    #[test]
+    fn stale_ids_cannot_touch_the_reused_slot() {
+        let mut world = World::new();
+        let old = world.spawn();
+        world.insert(old, Health { current: 10.0, max: 10.0 });
+        world.despawn(old);
+
+        let new = world.spawn();
+        assert_eq!(new.index, old.index);
+        world.insert(new, Health { current: 50.0, max: 50.0 });
+
+        assert!(world.healths.get(old).is_none());
+        assert!(world.healths.get_mut(old).is_none());
+        assert!(world.healths.remove(old).is_none());
+        world.insert(old, Health { current: 0.0, max: 0.0 });
+        assert_eq!(world.healths.get(new).unwrap().current, 50.0);
+        assert_eq!(world.healths.iter().map(|(id, _)| id).collect::<Vec<_>>(), vec![new]);
+    }
+
+    #[test]
+    fn stale_ids_never_alias_recycled_slots() {
+        let mut world = World::new();
+        let mut live: Vec<EntityId> = Vec::new();
+        let mut stale: Vec<EntityId> = Vec::new();
+        let mut peak = 0;
+
+        // Churn through many short-lived entities in bursts, like successive waves
+        for round in 0..200u32 {
+            for _ in 0..250 {
+                let e = world.spawn();
+                assert!(!world.transforms.contains(e), "Recycled slot kept old components");
+                world.insert(
+                    e,
+                    Transform {
+                        x: round as f32,
+                        y: 0.0,
+                        rotation: 0.0,
+                    },
+                );
+                live.push(e);
+            }
+            peak = peak.max(live.len());
+            // Despawn all but a few survivors each round
+            let survivors = live.split_off(live.len() - 5);
+            for e in live.drain(..) {
+                world.despawn(e);
+                world.despawn(e);
+                stale.push(e);
+            }
+            live = survivors;
+        }
+
+        assert_eq!(world.entity_count(), live.len());
+        assert!(world.transforms.slots.len() <= peak, "Storage grew past the peak live count");
+        for e in &live {
+            assert!(world.is_alive(*e));
+            assert!(world.transforms.contains(*e));
+        }
+        for e in stale.iter().step_by(97) {
+            assert!(!world.is_alive(*e));
+            assert!(world.transforms.get(*e).is_none());
+        }
     }
 }
@@ -55,6 +55,13 @@ pub const BATTERY_POSITIONS: [(f32, f32); 2] = [
     (1120.0, GROUND_Y),
 ];
 pub const BATTERY_MAX_AMMO: u32 = 10;
+/// Ticks between launches from one battery (0.5s at 60Hz)
+pub const BATTERY_RELOAD_TICKS: u32 = 30;
+/// Max interceptors one battery can guide in flight at once
+pub const BATTERY_MAX_IN_FLIGHT: u32 = 3;
+/// Default firing arc (radians from +x): no launches within ~10° of the horizon
+pub const BATTERY_ARC_MIN: f32 = 0.17;
+pub const BATTERY_ARC_MAX: f32 = std::f32::consts::PI - 0.17;
 
 // --- Interceptor ballistic properties ---
 pub const INTERCEPTOR_MASS: f32 = 30.0;
@@ -63,6 +70,23 @@ pub const INTERCEPTOR_CROSS_SECTION: f32 = 0.3;
 /// Proximity threshold for interceptor detonation at target
 pub const INTERCEPTOR_DETONATION_PROXIMITY: f32 = 15.0;
 
+// --- Launch Solution ---
+/// Max aim-correction passes when solving for gravity drop and wind
+pub const LAUNCH_SOLUTION_MAX_ITERATIONS: u32 = 8;
+/// Predicted miss distance at which a launch solution is accepted (inside the detonation radius)
+pub const LAUNCH_SOLUTION_TOLERANCE: f32 = INTERCEPTOR_DETONATION_PROXIMITY;
+/// Max seconds of flight simulated when predicting enemy missile impacts
+pub const IMPACT_PREDICTION_HORIZON: f32 = 30.0;
+/// Passes of the lead solver: each re-predicts where the missile will be
+/// when an interceptor on the latest solution arrives
+pub const LAUNCH_LEAD_ITERATIONS: u32 = 4;
+
+// --- Salvo ---
+/// A salvo click within this distance of a missile leads that missile instead of the point
+pub const SALVO_THREAT_PICK_RADIUS: f32 = 40.0;
+/// Minimum ticks between successive salvo launches, so shots arrive as a spread
+pub const SALVO_STAGGER_TICKS: u32 = 6;
+
 // --- Enemy missile properties ---
 pub const MISSILE_MASS: f32 = 50.0;
 pub const MISSILE_DRAG_COEFF: f32 = 0.3;
@@ -77,6 +101,10 @@ pub const WAVE_BASE_SPAWN_INTERVAL: u32 = 90;
 pub const MISSILE_FLIGHT_TIME_MIN: f32 = 6.0;
 /// Max flight time in seconds (controls arc height)
 pub const MISSILE_FLIGHT_TIME_MAX: f32 = 12.0;
+/// RNG seed offset per wave restart so a retried wave plays out differently
+pub const RESTART_SEED_OFFSET: u64 = 1_000_003;
+/// RNG seed offset for each wave's weather stream, keeping it apart from the wave RNG
+pub const WEATHER_SEED_OFFSET: u64 = 7_000_001;
 
 // --- Damage ---
 pub const GROUND_IMPACT_BASE_DAMAGE: f32 = 50.0;
@@ -153,6 +181,9 @@ pub const MIRV_CHILD_YIELD: f32 = 80.0;
 pub const MIRV_CHILD_BLAST_RADIUS: f32 = 30.0;
 pub const MIRV_DEFAULT_CHILD_COUNT: u32 = 3;
 pub const MIRV_FIRST_WAVE: u32 = 26;
+/// Split-altitude estimate error as a fraction of the bus's remaining height above the split.
+/// Estimates err high (split sooner), converging on the true altitude as the bus descends.
+pub const MIRV_ESTIMATE_ERROR_FACTOR: f32 = 0.15;
 
 // --- Weather + Wind ---
 /// First wave where weather effects can appear
@@ -163,10 +194,126 @@ pub const WIND_SPEED_STORM: f32 = 15.0;
 pub const WIND_SPEED_SEVERE: f32 = 30.0;
 /// Wind scales linearly with altitude: wind_effect = wind_speed * y * WIND_ALTITUDE_FACTOR
 pub const WIND_ALTITUDE_FACTOR: f32 = 0.003;
+/// Chance the strategic-phase forecast names the right condition
+pub const WEATHER_FORECAST_ACCURACY: f32 = 0.8;
+/// Max error in the forecast wind estimate (m/s); the reported range is twice this wide
+pub const WEATHER_FORECAST_WIND_ERROR: f32 = 6.0;
 /// Storm/Severe missile count multipliers
 pub const STORM_MISSILE_MULT: f32 = 1.15;
 pub const SEVERE_MISSILE_MULT: f32 = 1.3;
 
+// --- Terrain Modifiers ---
+/// Radar range multiplier for batteries in mountainous regions (terrain masking)
+pub const MOUNTAIN_RADAR_MULT: f32 = 0.75;
+/// Mountains widen the missile flight-time window around its midpoint by this factor
+pub const MOUNTAIN_FLIGHT_TIME_SPREAD: f32 = 1.5;
+/// Income multiplier for cities in plains regions
+pub const PLAINS_INCOME_MULT: f32 = 1.2;
+/// Sea-skimmers per owned coastal region (grows by one every 10 waves)
+pub const COASTAL_SKIMMERS_PER_REGION: u32 = 1;
+/// Sea-skimmers enter from the side edge at this altitude
+pub const SEA_SKIMMER_ALTITUDE: f32 = 120.0;
+
+// --- Diplomacy ---
+/// Relations meter range and starting value
+pub const RELATIONS_MAX: f32 = 100.0;
+pub const RELATIONS_START: f32 = 70.0;
+/// Extra cost fraction applied to alliance/lease prices when relations hit zero
+pub const RELATIONS_MAX_SURCHARGE: f32 = 1.0;
+/// Relations lost per HP of damage taken by allied cities
+pub const RELATIONS_PENALTY_PER_HP: f32 = 0.2;
+/// Alliance cost as a fraction of the region's expansion cost
+pub const ALLIANCE_COST_FRACTION: f32 = 0.5;
+/// Lease cost as a fraction of the place-battery cost
+pub const LEASE_COST_FRACTION: f32 = 0.6;
+/// Share of normal income contributed by allied cities
+pub const ALLIED_INCOME_SHARE: f32 = 0.5;
+
+// --- Strategic Events ---
+/// Chance that a strategic event fires between waves
+pub const STRATEGIC_EVENT_CHANCE: f64 = 0.35;
+/// Sabotage: ammo lost if declined, cost of the security sweep that prevents it
+pub const SABOTAGE_AMMO_LOST: u32 = 4;
+pub const SABOTAGE_SWEEP_COST: u32 = 40;
+/// Intel windfall: cost of the next-wave preview
+pub const INTEL_COST: u32 = 30;
+/// Refugee influx: population added and resettlement cost if accepted
+pub const REFUGEE_POPULATION: u32 = 100;
+pub const REFUGEE_RESETTLE_COST: u32 = 50;
+
+// --- Wave Grading ---
+/// City damage (total HP across cities) that zeroes the protection part of a wave score
+pub const GRADE_DAMAGE_FAIL: f32 = 150.0;
+/// Score points lost per interceptor launched beyond the threats that materialized
+pub const GRADE_OVERKILL_PENALTY: u32 = 4;
+/// Medal thresholds
+pub const MEDAL_SHARPSHOOTER_WAVES: u32 = 5;
+pub const MEDAL_IRON_DOME_STREAK: u32 = 5;
+pub const MEDAL_ACE_WAVES: u32 = 3;
+pub const MEDAL_VETERAN_WAVES: u32 = 25;
+
+// --- Endless Mode & Prestige ---
+/// Endless mode: wave past which extra scaling kicks in
+pub const ENDLESS_RAMP_WAVE: u32 = 20;
+/// Extra missiles per wave past the ramp, as a fraction of the composed count
+pub const ENDLESS_MISSILE_GROWTH: f32 = 0.08;
+/// Spawn interval floor in endless mode (the campaign floor is 30 ticks)
+pub const ENDLESS_MIN_SPAWN_INTERVAL: u32 = 10;
+/// Waves past the ramp per extra MIRV child, up to ENDLESS_MAX_MIRV_CHILDREN
+pub const ENDLESS_MIRV_CHILD_STEP: u32 = 10;
+pub const ENDLESS_MAX_MIRV_CHILDREN: u32 = 8;
+/// Waves a campaign must survive before the player can prestige
+pub const PRESTIGE_MIN_WAVES: u32 = 15;
+pub const PRESTIGE_MAX_LEVEL: u32 = 10;
+/// Permanent buffs per prestige level
+pub const PRESTIGE_RESOURCES_PER_LEVEL: u32 = 25;
+pub const PRESTIGE_AMMO_PER_LEVEL: u32 = 1;
+
+// --- Difficulty Presets ---
+/// Normal is 1.0 for every multiplier
+pub const EASY_WAVE_BUDGET_MULT: f32 = 0.75;
+pub const HARD_WAVE_BUDGET_MULT: f32 = 1.3;
+pub const EASY_INCOME_MULT: f32 = 1.25;
+pub const HARD_INCOME_MULT: f32 = 0.8;
+pub const EASY_REPAIR_COST_MULT: f32 = 0.5;
+pub const HARD_REPAIR_COST_MULT: f32 = 1.5;
+/// Interceptor types unlock this much earlier (Easy) or later (Hard)
+pub const EASY_UNLOCK_WAVE_MULT: f32 = 0.75;
+pub const HARD_UNLOCK_WAVE_MULT: f32 = 1.25;
+
+// --- Trails ---
+/// Missile and interceptor positions are sampled into their trail every N ticks
+pub const TRAIL_SAMPLE_TICKS: u64 = 2;
+/// Trail points kept per entity (TRAIL_MAX_POINTS * TRAIL_SAMPLE_TICKS ticks of history)
+pub const TRAIL_MAX_POINTS: usize = 30;
+/// Shorter trails while the game loop is degraded
+pub const DEGRADED_TRAIL_POINTS: usize = 15;
+
+// --- Snapshot LOD ---
+/// Level of detail kicks in once more than this many missiles are alive
+pub const SNAPSHOT_LOD_MISSILE_THRESHOLD: usize = 40;
+/// Under LOD, unengaged missiles above this altitude (other than MIRV buses) are low-threat
+pub const SNAPSHOT_LOD_THREAT_ALTITUDE: f32 = 300.0;
+/// Trail points sent for low-threat missiles under LOD
+pub const SNAPSHOT_LOD_TRAIL_POINTS: usize = 4;
+/// Under LOD, a missile stays full-detail while an interceptor's target point lies within this of its path
+pub const SNAPSHOT_LOD_ENGAGE_RADIUS: f32 = 60.0;
+/// Serialized snapshot size the LOD policy is tuned to stay under (bytes)
+pub const SNAPSHOT_SIZE_BUDGET: usize = 100_000;
+
+// --- Tick Watchdog ---
+/// Consecutive over-budget ticks before the game loop degrades
+pub const WATCHDOG_OVERRUN_TICKS: u32 = 30;
+/// Consecutive ticks within budget before it recovers
+pub const WATCHDOG_RECOVERY_TICKS: u32 = 120;
+/// In degraded mode, detection runs and snapshots are emitted only every N ticks
+pub const DEGRADED_DETECTION_INTERVAL: u64 = 4;
+pub const DEGRADED_SNAPSHOT_INTERVAL: u64 = 2;
+
+// --- Custom Maps ---
+/// Minimum distance between any two cities or battery slots on a custom map
+pub const CUSTOM_MAP_MIN_SPACING: f32 = 30.0;
+
 // --- Radar / Detection ---
 /// Base radar detection range from any battery (in world units)
 pub const RADAR_BASE_RANGE: f32 = 500.0;
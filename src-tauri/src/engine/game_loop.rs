@@ -1,9 +1,15 @@
+use crate::campaign::custom_map::CustomMap;
+use crate::campaign::difficulty::Difficulty;
 use crate::campaign::upgrades::UpgradeAxis;
 use crate::ecs::components::InterceptorType;
 use crate::engine::config;
 use crate::engine::simulation::Simulation;
+use crate::engine::watchdog::TickWatchdog;
 use crate::events::game_events::GameEvent;
+use crate::persistence::integrity;
+use crate::persistence::profile;
 use crate::persistence::save_load::{self, SaveData};
+use crate::state::campaign_state::CampaignState;
 use crate::state::game_state::GamePhase;
 use crate::systems::input_system::PlayerCommand;
 use std::path::PathBuf;
@@ -25,14 +31,25 @@ pub enum EngineCommand {
     ContinueToStrategic,
     ExpandRegion { region_id: u32 },
     PlaceBattery { region_id: u32, slot_index: u32 },
+    FormAlliance { region_id: u32 },
+    LeaseBattery { region_id: u32, slot_index: u32 },
     RestockAllBatteries,
     RepairCity { city_index: u32 },
     UnlockInterceptor { interceptor_type: String },
     UpgradeInterceptor { interceptor_type: String, axis: String },
+    ResolveStrategicEvent { accept: bool },
     GetCampaignState,
+    GetCampaignStats,
+    PredictImpacts,
     SaveGame { slot_name: String, app_data_dir: PathBuf },
-    LoadGame { save_data: SaveData },
-    NewGame,
+    LoadGame { save_data: Box<SaveData> },
+    NewGame {
+        endless: bool,
+        difficulty: Difficulty,
+        map: Option<Box<CustomMap>>,
+    },
+    Prestige,
+    RestartWave,
     ReturnToMainMenu,
 }
 
@@ -67,13 +84,20 @@ fn run_loop(rx: mpsc::Receiver<EngineCommand>, app: AppHandle) {
     sim.phase = GamePhase::MainMenu;
 
     let tick_duration = Duration::from_secs_f64(1.0 / config::TICK_RATE as f64);
+    let mut watchdog = TickWatchdog::new(tick_duration);
 
-    // Resolve saves directory for auto-save
-    let saves_dir = app
+    // Resolve saves directory for auto-save; the prestige profile lives beside it
+    let data_dir = app
         .path()
         .app_data_dir()
-        .unwrap_or_else(|_| PathBuf::from("."))
-        .join("saves");
+        .unwrap_or_else(|_| PathBuf::from("."));
+    let saves_dir = data_dir.join("saves");
+    let mut player_profile = profile::load_profile(&data_dir);
+    // Without a key nothing is saved: an unsigned save would fail to verify later
+    let save_key = integrity::load_or_create_key(&data_dir);
+    if let Err(e) = &save_key {
+        eprintln!("{e}; saving is disabled this session");
+    }
 
     // Emit initial snapshot (MainMenu phase — no campaign emit until NewGame)
     let snapshot = sim.build_snapshot();
@@ -86,20 +110,12 @@ fn run_loop(rx: mpsc::Receiver<EngineCommand>, app: AppHandle) {
         while let Ok(cmd) = rx.try_recv() {
             match cmd {
                 EngineCommand::StartWave => {
-                    if sim.phase == GamePhase::Strategic {
+                    if sim.phase == GamePhase::Strategic && sim.campaign.pending_event.is_none() {
                         sim.start_wave();
                     }
                 }
                 EngineCommand::ContinueToStrategic => {
-                    if sim.phase == GamePhase::WaveResult {
-                        // Sync ECS state back to campaign, calculate income
-                        sim.sync_to_campaign();
-                        let income = sim.apply_wave_income();
-                        sim.phase = GamePhase::Strategic;
-
-                        // Rebuild world from updated campaign state
-                        sim.rebuild_world();
-
+                    if let Some(income) = sim.continue_to_strategic() {
                         let snapshot = sim.build_snapshot();
                         let _ = app.emit("game:state_snapshot", &snapshot);
 
@@ -130,6 +146,27 @@ fn run_loop(rx: mpsc::Receiver<EngineCommand>, app: AppHandle) {
                             let _ = app.emit("campaign:state_update", &campaign);
                         }
                 }
+                EngineCommand::FormAlliance { region_id } => {
+                    if sim.phase == GamePhase::Strategic
+                        && sim.form_alliance(region_id).is_ok() {
+                            let snapshot = sim.build_snapshot();
+                            let _ = app.emit("game:state_snapshot", &snapshot);
+                            let campaign = sim.build_campaign_snapshot();
+                            let _ = app.emit("campaign:state_update", &campaign);
+                        }
+                }
+                EngineCommand::LeaseBattery {
+                    region_id,
+                    slot_index,
+                } => {
+                    if sim.phase == GamePhase::Strategic
+                        && sim.lease_battery(region_id, slot_index).is_ok() {
+                            let snapshot = sim.build_snapshot();
+                            let _ = app.emit("game:state_snapshot", &snapshot);
+                            let campaign = sim.build_campaign_snapshot();
+                            let _ = app.emit("campaign:state_update", &campaign);
+                        }
+                }
                 EngineCommand::RestockAllBatteries => {
                     if sim.phase == GamePhase::Strategic
                         && sim.restock_all_batteries().is_ok() {
@@ -167,30 +204,62 @@ fn run_loop(rx: mpsc::Receiver<EngineCommand>, app: AppHandle) {
                         }
                     }
                 }
+                EngineCommand::ResolveStrategicEvent { accept } => {
+                    if sim.phase == GamePhase::Strategic
+                        && sim.resolve_strategic_event(accept).is_ok() {
+                            let snapshot = sim.build_snapshot();
+                            let _ = app.emit("game:state_snapshot", &snapshot);
+                            let campaign = sim.build_campaign_snapshot();
+                            let _ = app.emit("campaign:state_update", &campaign);
+                        }
+                }
                 EngineCommand::GetCampaignState => {
                     let campaign = sim.build_campaign_snapshot();
                     let _ = app.emit("campaign:state_update", &campaign);
                 }
+                EngineCommand::GetCampaignStats => {
+                    let stats = sim.campaign_stats();
+                    let _ = app.emit("campaign:stats", &stats);
+                }
+                EngineCommand::PredictImpacts => {
+                    let predictions = sim.predict_impacts();
+                    let _ = app.emit("game:impact_predictions", &predictions);
+                }
                 EngineCommand::SaveGame {
                     slot_name,
                     app_data_dir,
                 } => {
                     let data = sim.to_save_data(&slot_name);
-                    if let Err(e) = save_load::save_to_file(&app_data_dir, &slot_name, &data) {
+                    let saved = save_key
+                        .as_ref()
+                        .map_err(Clone::clone)
+                        .and_then(|key| save_load::save_to_file(&app_data_dir, &slot_name, &data, key));
+                    if let Err(e) = saved {
                         eprintln!("Failed to save game: {e}");
                     }
                 }
                 EngineCommand::LoadGame { save_data } => {
-                    sim = Simulation::from_save_data(save_data);
+                    sim = Simulation::from_save_data(*save_data);
+                    sim.profile_prestige_level = player_profile.prestige_level;
 
                     let snapshot = sim.build_snapshot();
                     let _ = app.emit("game:state_snapshot", &snapshot);
                     let campaign = sim.build_campaign_snapshot();
                     let _ = app.emit("campaign:state_update", &campaign);
                 }
-                EngineCommand::NewGame => {
-                    sim = Simulation::new();
+                EngineCommand::NewGame { endless, difficulty, map } => {
+                    let campaign = match map {
+                        Some(map) => CampaignState::from_map(*map),
+                        None => CampaignState::default(),
+                    };
+                    sim = Simulation::new_with_campaign(
+                        campaign
+                            .with_difficulty(difficulty)
+                            .apply_profile(&player_profile, endless),
+                        42,
+                    );
                     sim.setup_world();
+                    sim.profile_prestige_level = player_profile.prestige_level;
                     sim.phase = GamePhase::Strategic;
 
                     let snapshot = sim.build_snapshot();
@@ -198,6 +267,29 @@ fn run_loop(rx: mpsc::Receiver<EngineCommand>, app: AppHandle) {
                     let campaign = sim.build_campaign_snapshot();
                     let _ = app.emit("campaign:state_update", &campaign);
                 }
+                EngineCommand::Prestige => {
+                    if sim.phase == GamePhase::Strategic
+                        && sim.prestige_reset(&mut player_profile).is_ok()
+                    {
+                        if let Err(e) = profile::save_profile(&data_dir, &player_profile) {
+                            eprintln!("Failed to save profile: {e}");
+                        }
+
+                        let snapshot = sim.build_snapshot();
+                        let _ = app.emit("game:state_snapshot", &snapshot);
+                        let campaign = sim.build_campaign_snapshot();
+                        let _ = app.emit("campaign:state_update", &campaign);
+                    }
+                }
+                EngineCommand::RestartWave => {
+                    match sim.restart_wave() {
+                        Ok(()) => {
+                            let snapshot = sim.build_snapshot();
+                            let _ = app.emit("game:state_snapshot", &snapshot);
+                        }
+                        Err(e) => eprintln!("Failed to restart wave: {e}"),
+                    }
+                }
                 EngineCommand::ReturnToMainMenu => {
                     sim = Simulation::new();
                     sim.setup_world();
@@ -206,16 +298,37 @@ fn run_loop(rx: mpsc::Receiver<EngineCommand>, app: AppHandle) {
                     let snapshot = sim.build_snapshot();
                     let _ = app.emit("game:state_snapshot", &snapshot);
                 }
+                EngineCommand::Player(PlayerCommand::Pause) => {
+                    if sim.pause() {
+                        let snapshot = sim.build_snapshot();
+                        let _ = app.emit("game:state_snapshot", &snapshot);
+                    }
+                }
+                EngineCommand::Player(PlayerCommand::Resume) => {
+                    sim.resume();
+                }
                 EngineCommand::Player(player_cmd) => {
-                    sim.push_command(player_cmd);
+                    if !sim.paused {
+                        sim.push_command(player_cmd);
+                    }
                 }
             }
         }
 
+        // While paused, keep the frontend fed with the frozen state
+        if sim.phase == GamePhase::WaveActive && sim.paused {
+            let snapshot = sim.build_snapshot();
+            let _ = app.emit("game:state_snapshot", &snapshot);
+        }
+
         // Only tick when a wave is active
-        if sim.phase == GamePhase::WaveActive {
+        if sim.phase == GamePhase::WaveActive && !sim.paused {
+            let degraded = sim.degraded;
             let snapshot = sim.tick();
-            let _ = app.emit("game:state_snapshot", &snapshot);
+            // Degraded mode halves snapshot traffic; the simulation itself never skips a tick
+            if !degraded || snapshot.tick.is_multiple_of(config::DEGRADED_SNAPSHOT_INTERVAL) {
+                let _ = app.emit("game:state_snapshot", snapshot);
+            }
 
             // Emit discrete game events
             for event in sim.drain_events() {
@@ -236,18 +349,46 @@ fn run_loop(rx: mpsc::Receiver<EngineCommand>, app: AppHandle) {
 
                         // Auto-save after each wave
                         let autosave = sim.to_save_data("autosave");
-                        if let Err(e) = save_load::save_to_file(&saves_dir, "autosave", &autosave) {
+                        let saved = save_key
+                            .as_ref()
+                            .map_err(Clone::clone)
+                            .and_then(|key| save_load::save_to_file(&saves_dir, "autosave", &autosave, key));
+                        if let Err(e) = saved {
                             eprintln!("Auto-save failed: {e}");
                         }
                     }
                     GameEvent::MirvSplit(e) => {
                         let _ = app.emit("game:mirv_split", e);
                     }
+                    GameEvent::MirvBusKilled(e) => {
+                        let _ = app.emit("game:mirv_bus_killed", e);
+                    }
+                    GameEvent::InterceptorSelfDestruct(e) => {
+                        let _ = app.emit("game:interceptor_self_destruct", e);
+                    }
+                    GameEvent::ThreatExited(e) => {
+                        let _ = app.emit("game:threat_exited", e);
+                    }
+                    GameEvent::SalvoShortfall(e) => {
+                        let _ = app.emit("game:salvo_shortfall", e);
+                    }
                 }
             }
         }
 
         let elapsed = start.elapsed();
+        if sim.phase == GamePhase::WaveActive && !sim.paused && watchdog.record(elapsed) {
+            sim.degraded = watchdog.degraded();
+            if sim.degraded {
+                eprintln!(
+                    "Tick budget exceeded for {} ticks (worst {:?}); entering degraded mode",
+                    config::WATCHDOG_OVERRUN_TICKS,
+                    watchdog.worst_tick
+                );
+            } else {
+                eprintln!("Tick times recovered; leaving degraded mode ({} overruns total)", watchdog.total_overruns);
+            }
+        }
         if elapsed < tick_duration {
             thread::sleep(tick_duration - elapsed);
         }
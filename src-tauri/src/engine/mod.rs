@@ -1,3 +1,4 @@
 pub mod config;
 pub mod game_loop;
 pub mod simulation;
+pub mod watchdog;
@@ -1,5 +1,9 @@
+use crate::campaign::diplomacy;
 use crate::campaign::economy;
-use crate::campaign::territory::RegionId;
+use crate::campaign::grading::{self, Medal};
+use crate::campaign::stats::{CampaignStats, ResourceCategory};
+use crate::campaign::strategic_events::{self, StrategicEvent, WavePreview};
+use crate::campaign::territory::{RegionId, TerrainType};
 use crate::campaign::upgrades::{self, UpgradeAxis};
 use crate::campaign::wave_composer;
 use crate::ecs::components::*;
@@ -7,6 +11,7 @@ use crate::ecs::entity::EntityId;
 use crate::ecs::world::World;
 use crate::engine::config;
 use crate::events::game_events::{GameEvent, WaveCompleteEvent};
+use crate::persistence::profile::PlayerProfile;
 use crate::persistence::save_load::SaveData;
 use crate::state::weather::{self, WeatherState};
 use crate::state::campaign_state::{
@@ -15,13 +20,26 @@ use crate::state::campaign_state::{
 };
 use crate::state::game_state::GamePhase;
 use crate::state::snapshot::StateSnapshot;
-use crate::state::wave_state::WaveState;
+use crate::state::wave_state::{WaveDefinition, WaveState};
 use crate::systems;
-use crate::systems::input_system::PlayerCommand;
+use crate::systems::arc_prediction::{self, ImpactPrediction, ImpactPredictions};
+use crate::systems::input_system::{LaunchContext, PendingLaunch, PlayerCommand};
+use crate::systems::state_snapshot::SnapshotLod;
 use rand::SeedableRng;
 use rand_chacha::ChaChaRng;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+/// Scratch Vecs the gather-then-apply systems refill each tick. Their capacity
+/// grows to fit the busiest wave and is then reused, so steady-state ticks don't allocate.
+#[derive(Debug, Default)]
+struct TickBuffers {
+    cleanup: systems::cleanup::CleanupBuffers,
+    collision: systems::collision::CollisionBuffers,
+    detonation: systems::detonation::DetonationBuffers,
+    detection: systems::detection::DetectionBuffers,
+    trail: systems::trail::TrailBuffers,
+}
+
 /// Top-level simulation orchestrator.
 /// Owns the ECS World and runs systems in the correct order each tick.
 pub struct Simulation {
@@ -36,8 +54,27 @@ pub struct Simulation {
     pub city_ids: Vec<EntityId>,
     pub battery_ids: Vec<EntityId>,
     pub input_queue: Vec<PlayerCommand>,
+    /// Salvo shots assigned to batteries and waiting to fire
+    pub salvo_queue: Vec<PendingLaunch>,
     pending_events: Vec<GameEvent>,
     pub campaign: CampaignState,
+    /// Set by the game loop watchdog when ticks run over budget; detection is batched
+    pub degraded: bool,
+    /// Mid-wave pause: the game loop stops ticking but keeps emitting snapshots
+    pub paused: bool,
+    /// Snapshot level-of-detail policy
+    pub snapshot_lod: SnapshotLod,
+    /// Prestige level of the player's profile, kept in sync by the game loop.
+    /// Only a campaign started at this level may be reset, so reloading a save
+    /// from before a reset can't earn the same prestige twice.
+    pub profile_prestige_level: u32,
+    /// Working storage reused by the systems every tick
+    buffers: TickBuffers,
+    /// Snapshot rebuilt in place by tick()
+    snapshot: StateSnapshot,
+    /// Campaign as it stood when the current wave started, restored by restart_wave
+    pre_wave_campaign: Option<CampaignState>,
+    wave_restarts: u32,
 }
 
 impl Simulation {
@@ -58,8 +95,17 @@ impl Simulation {
             city_ids: Vec::new(),
             battery_ids: Vec::new(),
             input_queue: Vec::new(),
+            salvo_queue: Vec::new(),
             pending_events: Vec::new(),
             campaign: CampaignState::default(),
+            degraded: false,
+            paused: false,
+            snapshot_lod: SnapshotLod::default(),
+            profile_prestige_level: 0,
+            buffers: TickBuffers::default(),
+            snapshot: StateSnapshot::default(),
+            pre_wave_campaign: None,
+            wave_restarts: 0,
         }
     }
 
@@ -76,8 +122,17 @@ impl Simulation {
             city_ids: Vec::new(),
             battery_ids: Vec::new(),
             input_queue: Vec::new(),
+            salvo_queue: Vec::new(),
             pending_events: Vec::new(),
             campaign,
+            degraded: false,
+            paused: false,
+            snapshot_lod: SnapshotLod::default(),
+            profile_prestige_level: 0,
+            buffers: TickBuffers::default(),
+            snapshot: StateSnapshot::default(),
+            pre_wave_campaign: None,
+            wave_restarts: 0,
         }
     }
 
@@ -97,7 +152,8 @@ impl Simulation {
     }
 
     /// Reconstruct a Simulation from saved data.
-    pub fn from_save_data(data: SaveData) -> Self {
+    pub fn from_save_data(mut data: SaveData) -> Self {
+        data.campaign.migrate_stats();
         // Re-seed RNG offset by wave_number so future waves diverge from earlier saves
         let rng_seed = data.seed.wrapping_add(data.wave_number as u64 * 1000);
         let mut sim = Self {
@@ -112,8 +168,17 @@ impl Simulation {
             city_ids: Vec::new(),
             battery_ids: Vec::new(),
             input_queue: Vec::new(),
+            salvo_queue: Vec::new(),
             pending_events: Vec::new(),
             campaign: data.campaign,
+            degraded: false,
+            paused: false,
+            snapshot_lod: SnapshotLod::default(),
+            profile_prestige_level: 0,
+            buffers: TickBuffers::default(),
+            snapshot: StateSnapshot::default(),
+            pre_wave_campaign: None,
+            wave_restarts: 0,
         };
         sim.setup_world();
         sim
@@ -135,8 +200,8 @@ impl Simulation {
 
     /// Spawn ECS entities from current campaign state.
     fn spawn_from_campaign(&mut self) {
-        let owned = self.campaign.owned_regions.clone();
-        for rid in &owned {
+        let defended = self.campaign.defended_regions();
+        for rid in &defended {
             let region = self.campaign.get_region(*rid).unwrap().clone();
 
             for (i, city) in region.cities.iter().enumerate() {
@@ -149,16 +214,15 @@ impl Simulation {
                     .unwrap_or(config::CITY_MAX_HEALTH);
 
                 let id = self.world.spawn();
-                let idx = id.index as usize;
-                self.world.transforms[idx] = Some(Transform {
+                self.world.insert(id, Transform {
                     x: city.x,
                     y: city.y,
                     rotation: 0.0,
                 });
-                self.world.markers[idx] = Some(EntityMarker {
+                self.world.insert(id, EntityMarker {
                     kind: EntityKind::City,
                 });
-                self.world.healths[idx] = Some(Health {
+                self.world.insert(id, Health {
                     current: health,
                     max: config::CITY_MAX_HEALTH,
                 });
@@ -175,21 +239,25 @@ impl Simulation {
                     .iter()
                     .find(|(r, si, _)| *r == *rid && *si == i)
                     .map(|(_, _, a)| *a)
-                    .unwrap_or(config::BATTERY_MAX_AMMO);
+                    .unwrap_or(self.campaign.battery_max_ammo);
 
                 let id = self.world.spawn();
-                let idx = id.index as usize;
-                self.world.transforms[idx] = Some(Transform {
+                self.world.insert(id, Transform {
                     x: slot.x,
                     y: slot.y,
                     rotation: 0.0,
                 });
-                self.world.markers[idx] = Some(EntityMarker {
+                self.world.insert(id, EntityMarker {
                     kind: EntityKind::Battery,
                 });
-                self.world.battery_states[idx] = Some(BatteryState {
+                self.world.insert(id, BatteryState {
                     ammo,
-                    max_ammo: config::BATTERY_MAX_AMMO,
+                    max_ammo: self.campaign.battery_max_ammo,
+                    reload_remaining: 0,
+                    arc_min: config::BATTERY_ARC_MIN,
+                    arc_max: config::BATTERY_ARC_MAX,
+                    in_flight: 0,
+                    radar_multiplier: region.terrain.radar_multiplier(),
                 });
                 self.battery_ids.push(id);
             }
@@ -197,31 +265,40 @@ impl Simulation {
     }
 
     /// Copy city health and battery ammo from ECS back to campaign state.
+    /// Damage taken by allied cities since the last sync worsens relations.
     pub fn sync_to_campaign(&mut self) {
-        let owned = self.campaign.owned_regions.clone();
+        let defended = self.campaign.defended_regions();
+        let mut allied_damage = 0.0_f32;
         let mut city_idx = 0;
-        for rid in &owned {
+        for rid in &defended {
             let region = self.campaign.get_region(*rid).unwrap().clone();
+            let allied = self.campaign.allied_regions.contains(rid);
             for i in 0..region.cities.len() {
                 if city_idx < self.city_ids.len() {
                     let eid = self.city_ids[city_idx];
-                    if self.world.is_alive(eid)
-                        && let Some(h) = &self.world.healths[eid.index as usize]
+                    if let Some(h) = self.world.healths.get(eid)
                             && let Some(entry) = self
                                 .campaign
                                 .city_healths
                                 .iter_mut()
                                 .find(|(r, ci, _)| *r == *rid && *ci == i)
                             {
+                                if allied {
+                                    allied_damage += entry.2 - h.current;
+                                }
                                 entry.2 = h.current;
                             }
                 }
                 city_idx += 1;
             }
         }
+        if allied_damage > 0.0 {
+            self.campaign.relations =
+                diplomacy::relations_after_damage(self.campaign.relations, allied_damage);
+        }
 
         let mut bat_idx = 0;
-        for rid in &owned {
+        for rid in &defended {
             let region = self.campaign.get_region(*rid).unwrap().clone();
             for (i, slot) in region.battery_slots.iter().enumerate() {
                 if !slot.occupied {
@@ -229,8 +306,7 @@ impl Simulation {
                 }
                 if bat_idx < self.battery_ids.len() {
                     let eid = self.battery_ids[bat_idx];
-                    if self.world.is_alive(eid)
-                        && let Some(bs) = &self.world.battery_states[eid.index as usize]
+                    if let Some(bs) = self.world.battery_states.get(eid)
                             && let Some(entry) = self
                                 .campaign
                                 .battery_ammo
@@ -245,13 +321,32 @@ impl Simulation {
         }
     }
 
+    /// Leave the wave result screen: bank the wave into the campaign, roll the
+    /// next strategic event and forecast, and rebuild the world from campaign
+    /// state. Returns the wave income, or None if no wave result is showing.
+    pub fn continue_to_strategic(&mut self) -> Option<u32> {
+        if self.phase != GamePhase::WaveResult {
+            return None;
+        }
+        self.sync_to_campaign();
+        let income = self.apply_wave_income();
+        self.roll_strategic_event();
+        self.roll_weather_forecast();
+        self.phase = GamePhase::Strategic;
+        self.rebuild_world();
+        Some(income)
+    }
+
     /// Calculate and add wave income to resources. Returns the income earned.
     pub fn apply_wave_income(&mut self) -> u32 {
         let city_data: Vec<(u32, f32, f32)> = {
             let mut data = Vec::new();
-            for rid in &self.campaign.owned_regions {
+            for rid in &self.campaign.defended_regions() {
                 let region = self.campaign.get_region(*rid).unwrap();
-                let multiplier = region.resource_multiplier;
+                let mut multiplier = region.resource_multiplier * region.terrain.income_multiplier();
+                if self.campaign.allied_regions.contains(rid) {
+                    multiplier *= config::ALLIED_INCOME_SHARE;
+                }
                 for (i, city) in region.cities.iter().enumerate() {
                     let health = self
                         .campaign
@@ -266,8 +361,9 @@ impl Simulation {
             }
             data
         };
-        let income = economy::calculate_wave_income(&city_data);
+        let income = economy::apply_difficulty(economy::calculate_wave_income(&city_data), self.campaign.difficulty);
         self.campaign.resources += income;
+        self.campaign.stats.record_earned(ResourceCategory::WaveIncome, income);
         self.campaign.total_waves_survived += 1;
         income
     }
@@ -302,10 +398,54 @@ impl Simulation {
             ));
         }
 
-        self.campaign.resources -= cost;
+        self.campaign.spend(cost, ResourceCategory::Expansion);
         self.campaign.owned_regions.push(target_rid);
 
-        let region = self.campaign.get_region(target_rid).unwrap().clone();
+        // Annexing an ally keeps its tracked city health and leased batteries
+        if let Some(pos) = self.campaign.allied_regions.iter().position(|r| *r == target_rid) {
+            self.campaign.allied_regions.remove(pos);
+        } else {
+            let region = self.campaign.get_region(target_rid).unwrap().clone();
+            for (i, _) in region.cities.iter().enumerate() {
+                self.campaign
+                    .city_healths
+                    .push((target_rid, i, config::CITY_MAX_HEALTH));
+            }
+        }
+
+        self.rebuild_world();
+        Ok(())
+    }
+
+    /// Form a mutual defense alliance with an adjacent unowned region.
+    pub fn form_alliance(&mut self, region_id: u32) -> Result<(), String> {
+        let target_rid = RegionId(region_id);
+
+        if self.campaign.owned_regions.contains(&target_rid) {
+            return Err("Region already owned".into());
+        }
+        if self.campaign.allied_regions.contains(&target_rid) {
+            return Err("Region already allied".into());
+        }
+        if !self.campaign.alliance_candidates().iter().any(|r| r.id == target_rid) {
+            return Err("Region not adjacent to owned territory".into());
+        }
+
+        let region = self
+            .campaign
+            .get_region(target_rid)
+            .ok_or("Region not found")?
+            .clone();
+        let cost = diplomacy::alliance_cost(region.expansion_cost, self.campaign.relations);
+        if self.campaign.resources < cost {
+            return Err(format!(
+                "Insufficient resources: have {}, need {}",
+                self.campaign.resources, cost
+            ));
+        }
+
+        self.campaign.spend(cost, ResourceCategory::Alliances);
+        self.campaign.allied_regions.push(target_rid);
         for (i, _) in region.cities.iter().enumerate() {
             self.campaign
                 .city_healths
@@ -316,6 +456,44 @@ impl Simulation {
         Ok(())
     }
 
+    /// Lease a battery slot in an allied region.
+    pub fn lease_battery(&mut self, region_id: u32, slot_index: u32) -> Result<(), String> {
+        let rid = RegionId(region_id);
+
+        if !self.campaign.allied_regions.contains(&rid) {
+            return Err("Region not allied".into());
+        }
+
+        let cost = diplomacy::lease_cost(self.campaign.cost_table.place_battery, self.campaign.relations);
+        if self.campaign.resources < cost {
+            return Err(format!(
+                "Insufficient resources: have {}, need {}",
+                self.campaign.resources, cost
+            ));
+        }
+
+        let region = self
+            .campaign
+            .get_region_mut(rid)
+            .ok_or("Region not found")?;
+        let slot = region
+            .battery_slots
+            .get_mut(slot_index as usize)
+            .ok_or("Invalid slot index")?;
+        if slot.occupied {
+            return Err("Slot already occupied".into());
+        }
+
+        slot.occupied = true;
+        self.campaign.spend(cost, ResourceCategory::Batteries);
+        self.campaign
+            .battery_ammo
+            .push((rid, slot_index as usize, self.campaign.battery_max_ammo));
+
+        self.rebuild_world();
+        Ok(())
+    }
+
     /// Place a battery at an available slot.
     pub fn place_battery(&mut self, region_id: u32, slot_index: u32) -> Result<(), String> {
         let rid = RegionId(region_id);
@@ -345,10 +523,10 @@ impl Simulation {
         }
 
         slot.occupied = true;
-        self.campaign.resources -= cost;
+        self.campaign.spend(cost, ResourceCategory::Batteries);
         self.campaign
             .battery_ammo
-            .push((rid, slot_index as usize, config::BATTERY_MAX_AMMO));
+            .push((rid, slot_index as usize, self.campaign.battery_max_ammo));
 
         self.rebuild_world();
         Ok(())
@@ -362,8 +540,7 @@ impl Simulation {
         // Find which batteries need restocking
         let mut to_restock: Vec<(usize, u32)> = Vec::new();
         for (i, &bid) in self.battery_ids.iter().enumerate() {
-            if self.world.is_alive(bid)
-                && let Some(bs) = &self.world.battery_states[bid.index as usize]
+            if let Some(bs) = self.world.battery_states.get(bid)
                     && bs.ammo < bs.max_ammo
             {
                 to_restock.push((i, bs.max_ammo));
@@ -383,14 +560,12 @@ impl Simulation {
         }
 
         for (battery_idx, max_ammo) in &to_restock {
-            let bid = self.battery_ids[*battery_idx];
-            self.world.battery_states[bid.index as usize]
-                .as_mut()
-                .unwrap()
-                .ammo = *max_ammo;
+            if let Some(bs) = self.world.battery_states.get_mut(self.battery_ids[*battery_idx]) {
+                bs.ammo = *max_ammo;
+            }
             self.sync_battery_ammo_at(*battery_idx, *max_ammo);
         }
-        self.campaign.resources -= total_cost;
+        self.campaign.spend(total_cost, ResourceCategory::Restock);
 
         Ok(())
     }
@@ -406,9 +581,7 @@ impl Simulation {
         }
 
         let (damage, max_health) = {
-            let h = self.world.healths[cid.index as usize]
-                .as_ref()
-                .ok_or("No health component")?;
+            let h = self.world.healths.get(cid).ok_or("No health component")?;
             (h.max - h.current, h.max)
         };
 
@@ -424,11 +597,10 @@ impl Simulation {
             ));
         }
 
-        self.campaign.resources -= cost;
-        self.world.healths[cid.index as usize]
-            .as_mut()
-            .unwrap()
-            .current = max_health;
+        self.campaign.spend(cost, ResourceCategory::Repairs);
+        if let Some(h) = self.world.healths.get_mut(cid) {
+            h.current = max_health;
+        }
         self.sync_city_health_at(city_index as usize, max_health);
 
         Ok(())
@@ -436,15 +608,18 @@ impl Simulation {
 
     /// Unlock a new interceptor type.
     pub fn unlock_interceptor(&mut self, itype: InterceptorType) -> Result<(), String> {
-        let cost = self.campaign.tech_tree.unlock(itype, self.wave_number, self.campaign.resources)?;
-        self.campaign.resources -= cost;
+        let cost = self
+            .campaign
+            .tech_tree
+            .unlock(itype, self.wave_number, self.campaign.resources, self.campaign.difficulty)?;
+        self.campaign.spend(cost, ResourceCategory::Research);
         Ok(())
     }
 
     /// Upgrade an interceptor type on a given axis.
     pub fn upgrade_interceptor(&mut self, itype: InterceptorType, axis: UpgradeAxis) -> Result<(), String> {
         let cost = self.campaign.tech_tree.apply_upgrade(itype, axis, self.campaign.resources)?;
-        self.campaign.resources -= cost;
+        self.campaign.spend(cost, ResourceCategory::Research);
         Ok(())
     }
 
@@ -463,6 +638,7 @@ impl Simulation {
             .iter()
             .map(|region| {
                 let owned = self.campaign.owned_regions.contains(&region.id);
+                let allied = self.campaign.allied_regions.contains(&region.id);
                 let expandable = expandable_ids.contains(&region.id.0);
 
                 let cities: Vec<CitySnapshotCampaign> = region
@@ -470,7 +646,7 @@ impl Simulation {
                     .iter()
                     .enumerate()
                     .map(|(i, city)| {
-                        let health = if owned {
+                        let health = if owned || allied {
                             self.campaign
                                 .city_healths
                                 .iter()
@@ -503,7 +679,7 @@ impl Simulation {
                                 .find(|(r, si, _)| *r == region.id && *si == i)
                                 .map(|(_, _, a)| *a)
                                 .unwrap_or(0);
-                            (Some(a), Some(config::BATTERY_MAX_AMMO))
+                            (Some(a), Some(self.campaign.battery_max_ammo))
                         } else {
                             (None, None)
                         };
@@ -522,6 +698,7 @@ impl Simulation {
                     name: region.name.clone(),
                     terrain: format!("{:?}", region.terrain),
                     owned,
+                    allied,
                     expandable,
                     cities,
                     battery_slots,
@@ -553,11 +730,31 @@ impl Simulation {
             }
         }
 
+        for r in self.campaign.alliance_candidates() {
+            let cost = diplomacy::alliance_cost(r.expansion_cost, self.campaign.relations);
+            if self.campaign.resources >= cost {
+                available_actions.push(AvailableAction::FormAlliance {
+                    region_id: r.id.0,
+                    cost,
+                });
+            }
+        }
+
+        let lease_cost = diplomacy::lease_cost(self.campaign.cost_table.place_battery, self.campaign.relations);
+        for (rid, si, _) in self.campaign.leasable_battery_slots() {
+            if self.campaign.resources >= lease_cost {
+                available_actions.push(AvailableAction::LeaseBattery {
+                    region_id: rid.0,
+                    slot_index: si as u32,
+                    cost: lease_cost,
+                });
+            }
+        }
+
         // Count batteries needing restock and offer a single "restock all" action
         let mut restock_count: u32 = 0;
         for &bid in &self.battery_ids {
-            if self.world.is_alive(bid)
-                && let Some(bs) = &self.world.battery_states[bid.index as usize]
+            if let Some(bs) = self.world.battery_states.get(bid)
                     && bs.ammo < bs.max_ammo
             {
                 restock_count += 1;
@@ -572,8 +769,7 @@ impl Simulation {
         }
 
         for (i, &cid) in self.city_ids.iter().enumerate() {
-            if self.world.is_alive(cid)
-                && let Some(h) = &self.world.healths[cid.index as usize] {
+            if let Some(h) = self.world.healths.get(cid) {
                     let damage = h.max - h.current;
                     if damage > 0.0 {
                         let cost = (damage * self.campaign.cost_table.repair_cost_per_hp as f32)
@@ -593,8 +789,9 @@ impl Simulation {
 
         // Tech tree unlock actions
         for itype in &[InterceptorType::Sprint, InterceptorType::Exoatmospheric, InterceptorType::AreaDenial] {
-            if self.campaign.tech_tree.can_unlock(*itype, self.wave_number, self.campaign.resources) {
-                let (min_wave, cost) = upgrades::unlock_gate(*itype);
+            let difficulty = self.campaign.difficulty;
+            if self.campaign.tech_tree.can_unlock(*itype, self.wave_number, self.campaign.resources, difficulty) {
+                let (min_wave, cost) = upgrades::unlock_gate_for(*itype, difficulty);
                 available_actions.push(AvailableAction::UnlockInterceptor {
                     interceptor_type: itype.as_str().to_string(),
                     cost,
@@ -625,7 +822,20 @@ impl Simulation {
             }
         }
 
-        available_actions.push(AvailableAction::StartWave);
+        // Prestige: reset the campaign for permanent profile buffs
+        if self.campaign.prestige_level == self.profile_prestige_level
+            && self.campaign.total_waves_survived >= config::PRESTIGE_MIN_WAVES
+            && self.campaign.prestige_level < config::PRESTIGE_MAX_LEVEL
+        {
+            available_actions.push(AvailableAction::Prestige {
+                next_level: self.campaign.prestige_level + 1,
+            });
+        }
+
+        // A pending strategic event must be resolved before the next wave
+        if self.campaign.pending_event.is_none() {
+            available_actions.push(AvailableAction::StartWave);
+        }
 
         // Build tech tree snapshot
         let tech_tree = TechTreeSnapshot {
@@ -654,19 +864,262 @@ impl Simulation {
             regions,
             available_actions,
             tech_tree,
+            allied_region_ids: self.campaign.allied_regions.iter().map(|r| r.0).collect(),
+            relations: self.campaign.relations,
+            pending_event: self.campaign.pending_event.clone(),
+            intel_preview: self.campaign.intel_preview.clone(),
+            weather_forecast: self.campaign.weather_forecast,
+            wave_scores: self.campaign.wave_scores.clone(),
+            medals: self.campaign.medals.clone(),
+            endless: self.campaign.endless,
+            prestige_level: self.campaign.prestige_level,
+            difficulty: self.campaign.difficulty.as_str().to_string(),
+            theater: self.campaign.theater,
             wave_income: None,
         }
     }
 
+    /// Aggregate lifetime campaign stats for the statistics screen.
+    pub fn campaign_stats(&self) -> CampaignStats {
+        self.campaign.stats.aggregate()
+    }
+
+    /// Publish a forecast of the next wave's weather, so the strategic phase
+    /// can plan around the wind. The actual weather is derived from the seed
+    /// when the wave begins and is never stored.
+    pub fn roll_weather_forecast(&mut self) {
+        let wave_number = self.wave_number + 1;
+        self.campaign.weather_forecast = Some(weather::forecast_for_wave(self.seed, wave_number));
+    }
+
+    /// Roll for a strategic event between waves using the seeded RNG.
+    /// Does nothing if an event is already awaiting a decision.
+    pub fn roll_strategic_event(&mut self) {
+        if self.campaign.pending_event.is_some() {
+            return;
+        }
+
+        let mut armed_batteries = Vec::new();
+        for (i, &bid) in self.battery_ids.iter().enumerate() {
+            if self.world.battery_states.get(bid)
+                    .is_some_and(|bs| bs.ammo > 0)
+            {
+                armed_batteries.push(self.battery_index_to_region(i));
+            }
+        }
+
+        let mut living_cities = Vec::new();
+        for rid in &self.campaign.owned_regions {
+            let region = self.campaign.get_region(*rid).unwrap();
+            for i in 0..region.cities.len() {
+                let alive = self
+                    .campaign
+                    .city_healths
+                    .iter()
+                    .any(|(r, ci, h)| *r == *rid && *ci == i && *h > 0.0);
+                if alive {
+                    living_cities.push((*rid, i));
+                }
+            }
+        }
+
+        self.campaign.pending_event =
+            strategic_events::roll_event(&mut self.rng, &armed_batteries, &living_cities);
+    }
+
+    /// Accept or decline the pending strategic event.
+    /// Accepting pays the event's cost; declining takes the event's downside (if any).
+    pub fn resolve_strategic_event(&mut self, accept: bool) -> Result<(), String> {
+        let event = self
+            .campaign
+            .pending_event
+            .clone()
+            .ok_or("No strategic event pending")?;
+
+        if accept {
+            let cost = event.cost();
+            if self.campaign.resources < cost {
+                return Err(format!(
+                    "Insufficient resources: have {}, need {}",
+                    self.campaign.resources, cost
+                ));
+            }
+            self.campaign.spend(cost, ResourceCategory::Events);
+        }
+
+        match event {
+            StrategicEvent::Sabotage {
+                region_id,
+                slot_index,
+                ammo_lost,
+                ..
+            } => {
+                if !accept
+                    && let Some(entry) = self
+                        .campaign
+                        .battery_ammo
+                        .iter_mut()
+                        .find(|(r, si, _)| *r == region_id && *si == slot_index)
+                {
+                    entry.2 = entry.2.saturating_sub(ammo_lost);
+                }
+            }
+            StrategicEvent::IntelWindfall { .. } => {
+                if accept {
+                    self.campaign.intel_preview = Some(self.preview_next_wave());
+                }
+            }
+            StrategicEvent::RefugeeInflux {
+                region_id,
+                city_index,
+                population,
+                ..
+            } => {
+                if accept
+                    && let Some(city) = self
+                        .campaign
+                        .get_region_mut(region_id)
+                        .and_then(|r| r.cities.get_mut(city_index))
+                {
+                    city.population += population;
+                }
+            }
+        }
+
+        self.campaign.pending_event = None;
+        self.rebuild_world();
+        Ok(())
+    }
+
+    /// Reset the campaign in exchange for a prestige level on the profile.
+    /// The new campaign keeps the endless setting, difficulty and custom map, and
+    /// starts with the raised buffs.
+    pub fn prestige_reset(&mut self, profile: &mut PlayerProfile) -> Result<(), String> {
+        let waves = self.campaign.total_waves_survived;
+        if self.campaign.prestige_level != profile.prestige_level {
+            return Err(format!(
+                "Campaign was started at prestige level {} but the profile is at {}; it has already been reset",
+                self.campaign.prestige_level, profile.prestige_level
+            ));
+        }
+        if !profile.can_prestige(waves) {
+            return Err(format!(
+                "Prestige requires {} waves survived (have {waves}) and level below {}",
+                config::PRESTIGE_MIN_WAVES,
+                config::PRESTIGE_MAX_LEVEL
+            ));
+        }
+
+        profile.prestige_level += 1;
+        profile.best_waves_survived = profile.best_waves_survived.max(waves);
+        self.profile_prestige_level = profile.prestige_level;
+
+        let fresh = match self.campaign.map.take() {
+            Some(map) => CampaignState::from_map(map),
+            None => CampaignState::default(),
+        };
+        self.campaign = fresh
+            .with_difficulty(self.campaign.difficulty)
+            .apply_profile(profile, self.campaign.endless);
+        self.tick = 0;
+        self.wave_number = 0;
+        self.wave = None;
+        self.weather = WeatherState::default();
+        self.phase = GamePhase::Strategic;
+        self.rebuild_world();
+        Ok(())
+    }
+
+    /// Wave definition for `wave_number`, scaled for difficulty and, when enabled, endless mode.
+    fn compose_wave(&self, wave_number: u32, weather: &WeatherState) -> WaveDefinition {
+        let def = wave_composer::compose_wave(wave_number, &self.owned_terrains(), weather);
+        let def = wave_composer::apply_difficulty(def, self.campaign.difficulty);
+        if self.campaign.endless {
+            wave_composer::apply_endless_scaling(def, wave_number)
+        } else {
+            def
+        }
+    }
+
+    /// Expected composition of the next wave, under its forecast weather when
+    /// one has been rolled and clear weather otherwise.
+    pub fn preview_next_wave(&self) -> WavePreview {
+        let wave_number = self.wave_number + 1;
+        let weather = match self.campaign.weather_forecast {
+            Some(f) if f.wave_number == wave_number => weather::weather_for_wave(self.seed, wave_number),
+            _ => WeatherState::default(),
+        };
+        let def = self.compose_wave(wave_number, &weather);
+        WavePreview {
+            wave_number,
+            missile_count: def.missile_count,
+            mirv_count: def.mirv_count,
+            sea_skimmer_count: def.sea_skimmer_count,
+        }
+    }
+
+    fn owned_terrains(&self) -> Vec<TerrainType> {
+        self.campaign
+            .owned_regions
+            .iter()
+            .filter_map(|rid| self.campaign.get_region(*rid).map(|r| r.terrain))
+            .collect()
+    }
+
     /// Begin the next wave using wave composer.
     pub fn start_wave(&mut self) {
+        self.pre_wave_campaign = Some(self.campaign.clone());
+        self.wave_restarts = 0;
+        self.begin_wave();
+    }
+
+    /// Throw away the current wave and replay it from the campaign state captured
+    /// at start_wave, with a fresh RNG seed offset.
+    pub fn restart_wave(&mut self) -> Result<(), String> {
+        if !matches!(self.phase, GamePhase::WaveActive | GamePhase::WaveResult) {
+            return Err("No wave in progress to restart".into());
+        }
+        let campaign = self.pre_wave_campaign.clone().ok_or("No pre-wave state captured")?;
+
+        self.campaign = campaign;
+        self.wave_number -= 1;
+        self.wave_restarts += 1;
+        let seed = self
+            .seed
+            .wrapping_add(self.wave_number as u64 * 1000)
+            .wrapping_add(self.wave_restarts as u64 * config::RESTART_SEED_OFFSET);
+        self.rng = ChaChaRng::seed_from_u64(seed);
+        self.wave = None;
+        self.paused = false;
+        self.input_queue.clear();
+        self.salvo_queue.clear();
+        self.pending_events.clear();
+        self.rebuild_world();
+        self.begin_wave();
+        Ok(())
+    }
+
+    /// Pause the active wave. Returns false if there is no wave to pause.
+    pub fn pause(&mut self) -> bool {
+        if self.phase != GamePhase::WaveActive {
+            return false;
+        }
+        self.paused = true;
+        true
+    }
+
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    fn begin_wave(&mut self) {
         self.wave_number += 1;
-        self.weather = weather::generate_weather(&mut self.rng, self.wave_number);
-        let def = wave_composer::compose_wave(
-            self.wave_number,
-            self.campaign.owned_regions.len() as u32,
-            &self.weather,
-        );
+        // Same stream the forecast was drawn from, so it describes this weather
+        self.campaign.weather_forecast = None;
+        self.weather = weather::weather_for_wave(self.seed, self.wave_number);
+        self.campaign.intel_preview = None;
+        self.salvo_queue.clear();
+        let def = self.compose_wave(self.wave_number, &self.weather);
         self.wave = Some(WaveState::new(def));
         self.phase = GamePhase::WaveActive;
     }
@@ -684,23 +1137,84 @@ impl Simulation {
     /// Build a snapshot without advancing the simulation.
     pub fn build_snapshot(&self) -> StateSnapshot {
         let phase_str = format!("{:?}", self.phase);
-        let mut snapshot = systems::state_snapshot::build(&self.world, self.tick, self.wave_number, &phase_str);
+        let mut snapshot = systems::state_snapshot::build(
+            &self.world,
+            self.tick,
+            self.wave_number,
+            &phase_str,
+            &self.snapshot_lod,
+        );
         snapshot.weather = Some(self.weather.condition.as_str().to_string());
         snapshot.wind_x = Some(self.weather.wind_x);
+        snapshot.degraded = self.degraded;
+        snapshot.paused = self.paused;
         snapshot
     }
 
-    /// Advance the simulation by one fixed timestep.
-    pub fn tick(&mut self) -> StateSnapshot {
-        let launched = systems::input_system::run(
+    /// Predict ground impacts for every enemy missile the player can currently see.
+    pub fn predict_impacts(&self) -> ImpactPredictions {
+        let mut impacts = Vec::new();
+        for (id, marker) in self.world.markers.iter() {
+            if marker.kind != EntityKind::Missile || !self.world.detected.contains(id) {
+                continue;
+            }
+            let (Some(t), Some(v), Some(b)) = (
+                self.world.transforms.get(id),
+                self.world.velocities.get(id),
+                self.world.ballistics.get(id),
+            ) else {
+                continue;
+            };
+            if let Some((x, time_to_impact)) =
+                arc_prediction::predict_missile_impact(
+                    t.x,
+                    t.y,
+                    v.vx,
+                    v.vy,
+                    b,
+                    self.weather.wind_x,
+                    &self.campaign.theater,
+                )
+            {
+                impacts.push(ImpactPrediction {
+                    entity_id: id.index,
+                    generation: id.generation,
+                    x,
+                    y: config::GROUND_Y,
+                    time_to_impact,
+                    is_mirv: self.world.mirv_carriers.contains(id),
+                });
+            }
+        }
+        ImpactPredictions {
+            tick: self.tick,
+            impacts,
+        }
+    }
+
+    /// Advance the simulation by one fixed timestep and return the resulting
+    /// snapshot, which is rebuilt in place each tick.
+    pub fn tick(&mut self) -> &StateSnapshot {
+        let launch_ctx = LaunchContext {
+            battery_ids: &self.battery_ids,
+            tech_tree: &self.campaign.tech_tree,
+            wind_x: self.weather.wind_x,
+            theater: &self.campaign.theater,
+        };
+        let input = systems::input_system::run(
             &mut self.world,
             &mut self.input_queue,
-            &self.battery_ids,
-            &self.campaign.tech_tree,
+            &mut self.salvo_queue,
+            &launch_ctx,
+            self.tick,
         );
         if let Some(ref mut wave) = self.wave {
-            wave.interceptors_launched += launched;
+            wave.interceptors_launched += input.launched.len() as u32;
+        }
+        for itype in input.launched {
+            self.campaign.stats.record_launch(itype);
         }
+        self.pending_events.extend(input.events);
 
         if let Some(ref mut wave) = self.wave {
             systems::wave_spawner::run(
@@ -716,17 +1230,26 @@ impl Simulation {
         systems::drag::run(&mut self.world);
         systems::wind::run(&mut self.world, &self.weather);
         systems::movement::run(&mut self.world);
+        let trail_points = if self.degraded {
+            config::DEGRADED_TRAIL_POINTS
+        } else {
+            config::TRAIL_MAX_POINTS
+        };
+        systems::trail::run(&mut self.world, self.tick, trail_points, &mut self.buffers.trail);
 
         let mirv_result = systems::mirv_split::run(&mut self.world, self.tick);
         self.pending_events.extend(mirv_result.events);
 
-        let collision_result = systems::collision::run(&mut self.world, self.tick);
+        let collision_result = systems::collision::run(&mut self.world, self.tick, &mut self.buffers.collision);
         self.pending_events.extend(collision_result.events);
         if let Some(ref mut wave) = self.wave {
             wave.missiles_destroyed += collision_result.missiles_destroyed;
         }
+        for itype in collision_result.kill_sources {
+            self.campaign.stats.record_kill(itype);
+        }
 
-        let detonation_result = systems::detonation::run(&mut self.world, self.tick);
+        let detonation_result = systems::detonation::run(&mut self.world, self.tick, &mut self.buffers.detonation);
         self.pending_events.extend(detonation_result.events);
         if let Some(ref mut wave) = self.wave {
             wave.missiles_impacted += detonation_result.missiles_impacted;
@@ -735,16 +1258,56 @@ impl Simulation {
         systems::shockwave_system::run(&mut self.world);
 
         let damage_events = systems::damage::run(&mut self.world, &self.city_ids, self.tick);
+        if let Some(ref mut wave) = self.wave {
+            for event in &damage_events {
+                if let GameEvent::CityDamaged(e) = event {
+                    wave.city_damage += e.damage;
+                }
+            }
+        }
         self.pending_events.extend(damage_events);
 
-        systems::detection::run(&mut self.world, &self.battery_ids, &self.weather);
+        // Detection is the heaviest per-tick pass; batch it while the loop is degraded
+        if !self.degraded || self.tick.is_multiple_of(config::DEGRADED_DETECTION_INTERVAL) {
+            systems::detection::run(&mut self.world, &self.battery_ids, &self.weather, &mut self.buffers.detection);
+        }
 
-        systems::cleanup::run(&mut self.world);
+        let cleanup_result = systems::cleanup::run(
+            &mut self.world,
+            &self.campaign.theater,
+            self.tick,
+            &mut self.buffers.cleanup,
+        );
+        self.pending_events.extend(cleanup_result.events);
+        if let Some(ref mut wave) = self.wave {
+            wave.missiles_exited += cleanup_result.missiles_exited;
+        }
 
         self.check_wave_complete();
 
         self.tick += 1;
-        self.build_snapshot()
+        self.refresh_snapshot();
+        &self.snapshot
+    }
+
+    /// Rebuild the reusable snapshot in place.
+    fn refresh_snapshot(&mut self) {
+        let snapshot = &mut self.snapshot;
+        systems::state_snapshot::build_into(
+            snapshot,
+            &self.world,
+            self.tick,
+            self.wave_number,
+            self.phase.as_str(),
+            &self.snapshot_lod,
+        );
+        let condition = self.weather.condition.as_str();
+        if snapshot.weather.as_deref() != Some(condition) {
+            snapshot.weather = Some(condition.to_string());
+        }
+        snapshot.wind_x = Some(self.weather.wind_x);
+        snapshot.degraded = self.degraded;
+        snapshot.paused = self.paused;
     }
 
     fn check_wave_complete(&mut self) {
@@ -757,20 +1320,12 @@ impl Simulation {
             return;
         }
 
-        let missiles_alive = self.world.alive_entities().iter().any(|&idx| {
-            self.world.markers[idx]
-                .as_ref()
-                .is_some_and(|m| m.kind == EntityKind::Missile)
-        });
+        let missiles_alive = self.world.markers.iter().any(|(_, m)| m.kind == EntityKind::Missile);
         if missiles_alive {
             return;
         }
 
-        let shockwaves_alive = self.world.alive_entities().iter().any(|&idx| {
-            self.world.markers[idx]
-                .as_ref()
-                .is_some_and(|m| m.kind == EntityKind::Shockwave)
-        });
+        let shockwaves_alive = self.world.markers.iter().any(|(_, m)| m.kind == EntityKind::Shockwave);
         if shockwaves_alive {
             return;
         }
@@ -779,21 +1334,44 @@ impl Simulation {
             .city_ids
             .iter()
             .filter(|&&id| {
-                self.world.is_alive(id)
-                    && self.world.healths[id.index as usize]
-                        .as_ref()
+                self.world.healths.get(id)
                         .is_some_and(|h| h.current > 0.0)
             })
             .count() as u32;
 
         let wave = self.wave.as_ref().unwrap();
+        let score = grading::grade_wave(
+            self.wave_number,
+            wave.missiles_destroyed,
+            wave.missiles_impacted,
+            wave.missiles_exited,
+            wave.interceptors_launched,
+            wave.city_damage,
+        );
+        self.campaign.wave_scores.push(score.clone());
+        self.campaign.stats.record_wave(
+            wave.missiles_destroyed,
+            wave.missiles_impacted,
+            self.city_ids.len() as u32,
+            cities_remaining,
+        );
+        let new_medals: Vec<Medal> = grading::earned_medals(&self.campaign.wave_scores)
+            .into_iter()
+            .filter(|m| !self.campaign.medals.contains(m))
+            .collect();
+        self.campaign.medals.extend(new_medals.iter().copied());
+
         self.pending_events
             .push(GameEvent::WaveComplete(WaveCompleteEvent {
                 wave_number: self.wave_number,
                 missiles_destroyed: wave.missiles_destroyed,
                 missiles_impacted: wave.missiles_impacted,
+                missiles_exited: wave.missiles_exited,
                 interceptors_launched: wave.interceptors_launched,
                 cities_remaining,
+                grade: score.grade.as_str().to_string(),
+                score: score.score,
+                new_medals: new_medals.iter().map(|m| m.as_str().to_string()).collect(),
                 tick: self.tick,
             }));
 
@@ -803,7 +1381,7 @@ impl Simulation {
 
     fn battery_index_to_region(&self, battery_idx: usize) -> (RegionId, usize) {
         let mut idx = 0;
-        for rid in &self.campaign.owned_regions {
+        for rid in &self.campaign.defended_regions() {
             let region = self.campaign.get_region(*rid).unwrap();
             for (i, slot) in region.battery_slots.iter().enumerate() {
                 if slot.occupied {
@@ -819,7 +1397,7 @@ impl Simulation {
 
     fn city_index_to_region(&self, city_idx: usize) -> (RegionId, usize) {
         let mut idx = 0;
-        for rid in &self.campaign.owned_regions {
+        for rid in &self.campaign.defended_regions() {
             let region = self.campaign.get_region(*rid).unwrap();
             for i in 0..region.cities.len() {
                 if idx == city_idx {
@@ -0,0 +1,103 @@
+use std::time::Duration;
+
+use crate::engine::config;
+
+/// Watches game loop tick times against the tick budget.
+///
+/// After WATCHDOG_OVERRUN_TICKS consecutive overruns the loop enters degraded
+/// mode and sheds load; it recovers after WATCHDOG_RECOVERY_TICKS consecutive
+/// ticks within budget.
+#[derive(Debug, Clone)]
+pub struct TickWatchdog {
+    budget: Duration,
+    consecutive_overruns: u32,
+    healthy_streak: u32,
+    pub total_overruns: u64,
+    pub worst_tick: Duration,
+    degraded: bool,
+}
+
+impl TickWatchdog {
+    pub fn new(budget: Duration) -> Self {
+        Self {
+            budget,
+            consecutive_overruns: 0,
+            healthy_streak: 0,
+            total_overruns: 0,
+            worst_tick: Duration::ZERO,
+            degraded: false,
+        }
+    }
+
+    pub fn degraded(&self) -> bool {
+        self.degraded
+    }
+
+    /// Record one tick's duration. Returns true if degraded mode was entered or left.
+    pub fn record(&mut self, elapsed: Duration) -> bool {
+        self.worst_tick = self.worst_tick.max(elapsed);
+
+        if elapsed > self.budget {
+            self.total_overruns += 1;
+            self.consecutive_overruns += 1;
+            self.healthy_streak = 0;
+            if !self.degraded && self.consecutive_overruns >= config::WATCHDOG_OVERRUN_TICKS {
+                self.degraded = true;
+                return true;
+            }
+        } else {
+            self.consecutive_overruns = 0;
+            self.healthy_streak += 1;
+            if self.degraded && self.healthy_streak >= config::WATCHDOG_RECOVERY_TICKS {
+                self.degraded = false;
+                return true;
+            }
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BUDGET: Duration = Duration::from_millis(16);
+    const SLOW: Duration = Duration::from_millis(25);
+    const FAST: Duration = Duration::from_millis(5);
+
+    #[test]
+    fn sustained_overruns_enter_degraded_mode() {
+        let mut wd = TickWatchdog::new(BUDGET);
+        for _ in 0..config::WATCHDOG_OVERRUN_TICKS - 1 {
+            assert!(!wd.record(SLOW));
+        }
+        assert!(wd.record(SLOW));
+        assert!(wd.degraded());
+        assert_eq!(wd.total_overruns, config::WATCHDOG_OVERRUN_TICKS as u64);
+    }
+
+    #[test]
+    fn isolated_overruns_are_tolerated() {
+        let mut wd = TickWatchdog::new(BUDGET);
+        for _ in 0..200 {
+            wd.record(SLOW);
+            wd.record(FAST);
+        }
+        assert!(!wd.degraded());
+        assert_eq!(wd.worst_tick, SLOW);
+    }
+
+    #[test]
+    fn recovers_after_healthy_streak() {
+        let mut wd = TickWatchdog::new(BUDGET);
+        for _ in 0..config::WATCHDOG_OVERRUN_TICKS {
+            wd.record(SLOW);
+        }
+        for _ in 0..config::WATCHDOG_RECOVERY_TICKS - 1 {
+            wd.record(FAST);
+        }
+        assert!(wd.degraded());
+        assert!(wd.record(FAST));
+        assert!(!wd.degraded());
+    }
+}
@@ -3,6 +3,8 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DetonationEvent {
     pub entity_id: u32,
+    /// Generation of the entity slot, as in EntitySnapshot
+    pub generation: u32,
     pub x: f32,
     pub y: f32,
     pub yield_force: f32,
@@ -12,11 +14,41 @@ pub struct DetonationEvent {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImpactEvent {
     pub entity_id: u32,
+    pub generation: u32,
     pub x: f32,
     pub y: f32,
     pub tick: u64,
 }
 
+/// An interceptor left the theater and self-destructed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InterceptorSelfDestructEvent {
+    pub entity_id: u32,
+    pub generation: u32,
+    pub x: f32,
+    pub y: f32,
+    pub tick: u64,
+}
+
+/// A missile left the theater without impacting: turned away rather than a hit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreatExitedEvent {
+    pub entity_id: u32,
+    pub generation: u32,
+    pub x: f32,
+    pub y: f32,
+    pub tick: u64,
+}
+
+/// A salvo got fewer shots than requested: too few batteries could engage
+/// when it was assigned, or an assigned battery was lost before firing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SalvoShortfallEvent {
+    pub requested: u32,
+    pub assigned: u32,
+    pub tick: u64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CityDamagedEvent {
     pub city_id: u32,
@@ -30,20 +62,38 @@ pub struct WaveCompleteEvent {
     pub wave_number: u32,
     pub missiles_destroyed: u32,
     pub missiles_impacted: u32,
+    /// Missiles that left the theater without impacting
+    pub missiles_exited: u32,
     pub interceptors_launched: u32,
     pub cities_remaining: u32,
+    pub grade: String,
+    pub score: u32,
+    /// Medals first earned by completing this wave
+    pub new_medals: Vec<String>,
     pub tick: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MirvSplitEvent {
     pub carrier_id: u32,
+    pub carrier_generation: u32,
     pub x: f32,
     pub y: f32,
     pub child_count: u32,
     pub tick: u64,
 }
 
+/// A MIRV bus destroyed before splitting; its warheads never deploy.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MirvBusKilledEvent {
+    pub carrier_id: u32,
+    pub carrier_generation: u32,
+    pub x: f32,
+    pub y: f32,
+    pub children_prevented: u32,
+    pub tick: u64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum GameEvent {
     Detonation(DetonationEvent),
@@ -51,4 +101,8 @@ pub enum GameEvent {
     CityDamaged(CityDamagedEvent),
     WaveComplete(WaveCompleteEvent),
     MirvSplit(MirvSplitEvent),
+    MirvBusKilled(MirvBusKilledEvent),
+    InterceptorSelfDestruct(InterceptorSelfDestructEvent),
+    ThreatExited(ThreatExitedEvent),
+    SalvoShortfall(SalvoShortfallEvent),
 }
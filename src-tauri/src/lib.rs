@@ -14,22 +14,33 @@ pub fn run() {
         .invoke_handler(tauri::generate_handler![
             commands::ping,
             commands::tactical::launch_interceptor,
+            commands::tactical::salvo_intercept,
+            commands::tactical::pause_wave,
+            commands::tactical::resume_wave,
             commands::tactical::predict_arc,
+            commands::tactical::predict_impacts,
             commands::campaign::start_wave,
             commands::campaign::continue_to_strategic,
             commands::campaign::expand_region,
             commands::campaign::place_battery,
+            commands::campaign::form_alliance,
+            commands::campaign::lease_battery,
             commands::campaign::restock_all_batteries,
             commands::campaign::repair_city,
             commands::campaign::unlock_interceptor,
             commands::campaign::upgrade_interceptor,
+            commands::campaign::resolve_strategic_event,
             commands::campaign::get_campaign_state,
+            commands::campaign::get_campaign_stats,
             commands::campaign::new_game,
+            commands::campaign::prestige_reset,
+            commands::campaign::restart_wave,
             commands::campaign::return_to_main_menu,
             commands::persistence::save_game,
             commands::persistence::load_game,
             commands::persistence::list_saves,
             commands::persistence::delete_save,
+            commands::persistence::list_maps,
         ])
         .setup(|app| {
             // Start game loop on background thread
@@ -0,0 +1,126 @@
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::fs;
+use std::io::{ErrorKind, Write};
+use std::path::Path;
+
+type HmacSha256 = Hmac<Sha256>;
+
+const KEY_FILE: &str = "integrity.key";
+const KEY_LEN: usize = 32;
+
+/// Result of checking a save file's signature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IntegrityStatus {
+    /// Signed by this machine and unchanged since
+    Verified,
+    /// Written before saves were signed
+    Unsigned,
+    /// Signature doesn't match: hand-edited, corrupted, or from another machine
+    Tampered,
+}
+
+/// Load this machine's save-signing key from `dir`, generating one on first use.
+/// A key file that exists but can't be read or parsed is an error, never
+/// replaced: a new key would flag every existing save as tampered.
+pub fn load_or_create_key(dir: &Path) -> Result<Vec<u8>, String> {
+    let path = dir.join(KEY_FILE);
+    match fs::read_to_string(&path) {
+        Ok(hex) => {
+            return decode_hex(hex.trim())
+                .filter(|key| key.len() == KEY_LEN)
+                .ok_or_else(|| format!("Integrity key {} is corrupt; move it aside to create a new one", path.display()));
+        }
+        Err(e) if e.kind() != ErrorKind::NotFound => {
+            return Err(format!("Failed to read integrity key {}: {e}", path.display()));
+        }
+        Err(_) => {}
+    }
+
+    let mut key = vec![0u8; KEY_LEN];
+    rand::thread_rng().fill_bytes(&mut key);
+    fs::create_dir_all(dir).map_err(|e| format!("Failed to create key directory: {e}"))?;
+    // create_new so a key written concurrently is never clobbered
+    let mut file = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&path)
+        .map_err(|e| format!("Failed to create integrity key: {e}"))?;
+    file.write_all(encode_hex(&key).as_bytes())
+        .map_err(|e| format!("Failed to write integrity key: {e}"))?;
+    Ok(key)
+}
+
+/// HMAC-SHA256 of `payload`, hex encoded.
+pub fn sign(key: &[u8], payload: &[u8]) -> String {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts any key length");
+    mac.update(payload);
+    encode_hex(&mac.finalize().into_bytes())
+}
+
+/// Check `signature` against `payload` in constant time.
+pub fn verify(key: &[u8], payload: &[u8], signature: &str) -> bool {
+    let Some(expected) = decode_hex(signature) else {
+        return false;
+    };
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts any key length");
+    mac.update(payload);
+    mac.verify_slice(&expected).is_ok()
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signature_verifies_only_unchanged_payload() {
+        let key = [7u8; KEY_LEN];
+        let sig = sign(&key, b"wave 5");
+        assert!(verify(&key, b"wave 5", &sig));
+        assert!(!verify(&key, b"wave 6", &sig));
+        assert!(!verify(&[8u8; KEY_LEN], b"wave 5", &sig));
+        assert!(!verify(&key, b"wave 5", "not hex"));
+    }
+
+    #[test]
+    fn key_is_created_once_and_reused() {
+        let dir = std::env::temp_dir().join("deterrence_test_integrity_key");
+        let _ = fs::remove_dir_all(&dir);
+
+        let first = load_or_create_key(&dir).unwrap();
+        let second = load_or_create_key(&dir).unwrap();
+        assert_eq!(first.len(), KEY_LEN);
+        assert_eq!(first, second);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn corrupt_key_is_reported_not_replaced() {
+        let dir = std::env::temp_dir().join("deterrence_test_integrity_corrupt");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join(KEY_FILE), "not a key").unwrap();
+
+        assert!(load_or_create_key(&dir).is_err());
+        assert_eq!(fs::read_to_string(dir.join(KEY_FILE)).unwrap(), "not a key");
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
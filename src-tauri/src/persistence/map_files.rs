@@ -0,0 +1,115 @@
+use crate::campaign::custom_map::{self, CustomMap};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+/// Listing entry for a map file. Invalid maps are listed with their error
+/// so authors can see why a map won't load.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MapMetadata {
+    pub file_name: String,
+    pub name: String,
+    pub description: String,
+    pub region_count: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Path of a map inside the maps directory. The name must be a bare file
+/// stem: separators, `..` and absolute paths would escape the directory.
+fn map_path(dir: &Path, file_name: &str) -> Result<PathBuf, String> {
+    let mut components = Path::new(file_name).components();
+    let bare = matches!(components.next(), Some(Component::Normal(_))) && components.next().is_none();
+    if !bare || file_name.contains(['/', '\\']) {
+        return Err(format!("Invalid map name '{file_name}'"));
+    }
+    Ok(dir.join(format!("{}.json", file_name)))
+}
+
+/// Read and validate a custom map.
+pub fn load_map(dir: &Path, file_name: &str) -> Result<CustomMap, String> {
+    let json = fs::read_to_string(map_path(dir, file_name)?)
+        .map_err(|e| format!("Failed to read map file: {e}"))?;
+    custom_map::parse_map(&json)
+}
+
+pub fn list_maps(dir: &Path) -> Vec<MapMetadata> {
+    let entries = match fs::read_dir(dir) {
+        Ok(e) => e,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut maps = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Some(file_name) = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .filter(|_| path.extension().is_some_and(|ext| ext == "json"))
+            .map(str::to_string)
+        else {
+            continue;
+        };
+        let meta = match load_map(dir, &file_name) {
+            Ok(map) => MapMetadata {
+                file_name,
+                name: map.name,
+                description: map.description,
+                region_count: map.regions.len() as u32,
+                error: None,
+            },
+            Err(e) => MapMetadata {
+                name: file_name.clone(),
+                file_name,
+                description: String::new(),
+                region_count: 0,
+                error: Some(e),
+            },
+        };
+        maps.push(meta);
+    }
+    maps.sort_by(|a, b| a.file_name.cmp(&b.file_name));
+    maps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::campaign::territory::define_regions;
+
+    #[test]
+    fn list_and_load_maps() {
+        let dir = std::env::temp_dir().join("deterrence_test_map_files");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let map = CustomMap {
+            name: "Islands".into(),
+            description: "Test map".into(),
+            regions: define_regions(),
+            theater: None,
+        };
+        fs::write(map_path(&dir, "islands").unwrap(), serde_json::to_string(&map).unwrap()).unwrap();
+        fs::write(map_path(&dir, "broken").unwrap(), "{ not json").unwrap();
+
+        let maps = list_maps(&dir);
+        assert_eq!(maps.len(), 2);
+        assert!(maps[0].error.is_some(), "Broken map should list its error");
+        assert_eq!(maps[1].name, "Islands");
+        assert_eq!(maps[1].region_count, 5);
+
+        assert!(load_map(&dir, "islands").is_ok());
+        assert!(load_map(&dir, "broken").is_err());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn map_names_cannot_leave_the_maps_dir() {
+        let dir = Path::new("maps");
+        for name in ["../secrets", "..", "sub/map", "sub\\map", "/etc/passwd", "", "."] {
+            assert!(map_path(dir, name).is_err(), "{name:?} should be rejected");
+        }
+        assert_eq!(map_path(dir, "islands").unwrap(), dir.join("islands.json"));
+    }
+}
@@ -1 +1,4 @@
+pub mod integrity;
+pub mod map_files;
+pub mod profile;
 pub mod save_load;
@@ -0,0 +1,86 @@
+use crate::engine::config;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+const PROFILE_FILE: &str = "profile.json";
+
+/// Player profile that persists across campaigns, stored apart from save slots.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PlayerProfile {
+    pub prestige_level: u32,
+    /// Most waves survived in any campaign that was reset for prestige
+    #[serde(default)]
+    pub best_waves_survived: u32,
+}
+
+impl PlayerProfile {
+    pub fn bonus_resources(&self) -> u32 {
+        self.prestige_level * config::PRESTIGE_RESOURCES_PER_LEVEL
+    }
+
+    pub fn bonus_ammo(&self) -> u32 {
+        self.prestige_level * config::PRESTIGE_AMMO_PER_LEVEL
+    }
+
+    /// Whether a campaign that survived `waves_survived` waves can be reset for prestige.
+    pub fn can_prestige(&self, waves_survived: u32) -> bool {
+        waves_survived >= config::PRESTIGE_MIN_WAVES && self.prestige_level < config::PRESTIGE_MAX_LEVEL
+    }
+}
+
+/// Load the profile from `dir`. A missing or unreadable profile starts fresh.
+pub fn load_profile(dir: &Path) -> PlayerProfile {
+    fs::read_to_string(dir.join(PROFILE_FILE))
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+pub fn save_profile(dir: &Path, profile: &PlayerProfile) -> Result<(), String> {
+    fs::create_dir_all(dir).map_err(|e| format!("Failed to create profile directory: {e}"))?;
+    let json = serde_json::to_string_pretty(profile)
+        .map_err(|e| format!("Failed to serialize profile: {e}"))?;
+    fs::write(dir.join(PROFILE_FILE), json).map_err(|e| format!("Failed to write profile: {e}"))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_profile_loads_default() {
+        let dir = std::env::temp_dir().join("deterrence_test_profile_missing");
+        let _ = fs::remove_dir_all(&dir);
+        assert_eq!(load_profile(&dir), PlayerProfile::default());
+    }
+
+    #[test]
+    fn save_and_load_profile() {
+        let dir = std::env::temp_dir().join("deterrence_test_profile_roundtrip");
+        let _ = fs::remove_dir_all(&dir);
+
+        let profile = PlayerProfile {
+            prestige_level: 3,
+            best_waves_survived: 22,
+        };
+        save_profile(&dir, &profile).unwrap();
+        assert_eq!(load_profile(&dir), profile);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn prestige_requires_min_waves_and_caps_level() {
+        let fresh = PlayerProfile::default();
+        assert!(!fresh.can_prestige(config::PRESTIGE_MIN_WAVES - 1));
+        assert!(fresh.can_prestige(config::PRESTIGE_MIN_WAVES));
+
+        let maxed = PlayerProfile {
+            prestige_level: config::PRESTIGE_MAX_LEVEL,
+            best_waves_survived: 0,
+        };
+        assert!(!maxed.can_prestige(100));
+    }
+}
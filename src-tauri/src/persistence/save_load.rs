@@ -1,3 +1,4 @@
+use crate::persistence::integrity::{self, IntegrityStatus};
 use crate::state::campaign_state::CampaignState;
 use serde::{Deserialize, Serialize};
 use std::fs;
@@ -22,25 +23,62 @@ pub struct SaveMetadata {
     pub resources: u32,
 }
 
+/// Field holding the save's HMAC, alongside the SaveData fields.
+const SIGNATURE_FIELD: &str = "signature";
+
 fn save_path(dir: &Path, slot: &str) -> std::path::PathBuf {
     dir.join(format!("{}.json", slot))
 }
 
-pub fn save_to_file(dir: &Path, slot: &str, data: &SaveData) -> Result<(), String> {
+/// Canonical bytes a save is signed over. serde_json::Value objects keep
+/// their keys sorted, so the same data always yields the same bytes.
+fn signing_payload(value: &serde_json::Value) -> String {
+    value.to_string()
+}
+
+/// Write a save signed with `key`.
+pub fn save_to_file(dir: &Path, slot: &str, data: &SaveData, key: &[u8]) -> Result<(), String> {
+    if key.is_empty() {
+        return Err("No integrity key; refusing to write an unsigned save".into());
+    }
     fs::create_dir_all(dir).map_err(|e| format!("Failed to create save directory: {e}"))?;
     let path = save_path(dir, slot);
-    let json = serde_json::to_string_pretty(data)
+    let mut value =
+        serde_json::to_value(data).map_err(|e| format!("Failed to serialize save data: {e}"))?;
+    let signature = integrity::sign(key, signing_payload(&value).as_bytes());
+    if let serde_json::Value::Object(fields) = &mut value {
+        fields.insert(SIGNATURE_FIELD.into(), signature.into());
+    }
+    let json = serde_json::to_string_pretty(&value)
         .map_err(|e| format!("Failed to serialize save data: {e}"))?;
     fs::write(&path, json).map_err(|e| format!("Failed to write save file: {e}"))?;
     Ok(())
 }
 
-pub fn load_from_file(dir: &Path, slot: &str) -> Result<SaveData, String> {
+/// Read a save and check its signature against `key`. The caller decides
+/// what to do with a save that isn't `Verified`.
+pub fn load_from_file(dir: &Path, slot: &str, key: &[u8]) -> Result<(SaveData, IntegrityStatus), String> {
     let path = save_path(dir, slot);
     let json = fs::read_to_string(&path).map_err(|e| format!("Failed to read save file: {e}"))?;
-    let data: SaveData =
+    let mut value: serde_json::Value =
         serde_json::from_str(&json).map_err(|e| format!("Failed to parse save data: {e}"))?;
-    Ok(data)
+
+    let signature = value
+        .as_object_mut()
+        .and_then(|fields| fields.remove(SIGNATURE_FIELD));
+    let status = match signature {
+        None => IntegrityStatus::Unsigned,
+        Some(serde_json::Value::String(sig))
+            if integrity::verify(key, signing_payload(&value).as_bytes(), &sig) =>
+        {
+            IntegrityStatus::Verified
+        }
+        Some(_) => IntegrityStatus::Tampered,
+    };
+
+    let data: SaveData =
+        serde_json::from_value(value).map_err(|e| format!("Failed to parse save data: {e}"))?;
+    Ok((data, status))
 }
 
 pub fn list_saves(dir: &Path) -> Vec<SaveMetadata> {
@@ -81,6 +119,8 @@ mod tests {
     use super::*;
     use std::time::{SystemTime, UNIX_EPOCH};
 
+    const KEY: &[u8] = b"test-machine-key";
+
     fn make_save_data(slot: &str, wave: u32) -> SaveData {
         SaveData {
             campaign: CampaignState::default(),
@@ -115,8 +155,9 @@ mod tests {
         let _ = fs::remove_dir_all(&dir);
 
         let data = make_save_data("slot1", 3);
-        save_to_file(&dir, "slot1", &data).unwrap();
-        let loaded = load_from_file(&dir, "slot1").unwrap();
+        save_to_file(&dir, "slot1", &data, KEY).unwrap();
+        let (loaded, status) = load_from_file(&dir, "slot1", KEY).unwrap();
+        assert_eq!(status, IntegrityStatus::Verified);
         assert_eq!(loaded.wave_number, 3);
         assert_eq!(loaded.seed, 42);
         assert_eq!(loaded.campaign.resources, data.campaign.resources);
@@ -124,6 +165,68 @@ mod tests {
         let _ = fs::remove_dir_all(&dir);
     }
 
+    #[test]
+    fn edited_save_is_flagged_tampered() {
+        let dir = std::env::temp_dir().join("deterrence_test_save_tampered");
+        let _ = fs::remove_dir_all(&dir);
+
+        save_to_file(&dir, "edited", &make_save_data("edited", 3), KEY).unwrap();
+        let json = fs::read_to_string(save_path(&dir, "edited")).unwrap();
+        let resources = CampaignState::default().resources;
+        let edited = json.replacen(
+            &format!("\"resources\": {resources}"),
+            "\"resources\": 99999",
+            1,
+        );
+        assert_ne!(json, edited);
+        fs::write(save_path(&dir, "edited"), edited).unwrap();
+
+        let (data, status) = load_from_file(&dir, "edited", KEY).unwrap();
+        assert_eq!(status, IntegrityStatus::Tampered);
+        assert_eq!(data.campaign.resources, 99999);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn save_without_key_is_refused() {
+        let dir = std::env::temp_dir().join("deterrence_test_save_no_key");
+        let _ = fs::remove_dir_all(&dir);
+
+        assert!(save_to_file(&dir, "slot1", &make_save_data("slot1", 3), &[]).is_err());
+        assert!(!save_path(&dir, "slot1").exists());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn save_from_other_machine_is_flagged_tampered() {
+        let dir = std::env::temp_dir().join("deterrence_test_save_other_key");
+        let _ = fs::remove_dir_all(&dir);
+
+        save_to_file(&dir, "shared", &make_save_data("shared", 3), KEY).unwrap();
+        let (_, status) = load_from_file(&dir, "shared", b"another-machine").unwrap();
+        assert_eq!(status, IntegrityStatus::Tampered);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn legacy_unsigned_save_still_loads() {
+        let dir = std::env::temp_dir().join("deterrence_test_save_unsigned");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let json = serde_json::to_string_pretty(&make_save_data("legacy", 4)).unwrap();
+        fs::write(save_path(&dir, "legacy"), json).unwrap();
+
+        let (data, status) = load_from_file(&dir, "legacy", KEY).unwrap();
+        assert_eq!(status, IntegrityStatus::Unsigned);
+        assert_eq!(data.wave_number, 4);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
     #[test]
     fn list_saves_empty() {
         let dir = std::env::temp_dir().join("deterrence_test_list_empty");
@@ -139,11 +242,11 @@ mod tests {
 
         let mut data1 = make_save_data("early", 2);
         data1.timestamp = 1000;
-        save_to_file(&dir, "early", &data1).unwrap();
+        save_to_file(&dir, "early", &data1, KEY).unwrap();
 
         let mut data2 = make_save_data("late", 8);
         data2.timestamp = 2000;
-        save_to_file(&dir, "late", &data2).unwrap();
+        save_to_file(&dir, "late", &data2, KEY).unwrap();
 
         let saves = list_saves(&dir);
         assert_eq!(saves.len(), 2);
@@ -160,7 +263,7 @@ mod tests {
         let _ = fs::remove_dir_all(&dir);
 
         let data = make_save_data("todelete", 1);
-        save_to_file(&dir, "todelete", &data).unwrap();
+        save_to_file(&dir, "todelete", &data, KEY).unwrap();
         assert!(save_path(&dir, "todelete").exists());
 
         delete_save(&dir, "todelete").unwrap();
@@ -1,9 +1,16 @@
 use serde::{Deserialize, Serialize};
 
+use crate::campaign::difficulty::Difficulty;
 use crate::campaign::economy::CostTable;
-use crate::campaign::territory::{BatterySlot, CityDef, Region, RegionId};
+use crate::campaign::grading::{Medal, WaveScore};
+use crate::campaign::stats::{LifetimeStats, ResourceCategory};
+use crate::campaign::strategic_events::{StrategicEvent, WavePreview};
+use crate::campaign::custom_map::CustomMap;
+use crate::campaign::territory::{BatterySlot, CityDef, Region, RegionId, TheaterBounds};
 use crate::campaign::upgrades::TechTree;
 use crate::engine::config;
+use crate::persistence::profile::PlayerProfile;
+use crate::state::weather::WeatherForecast;
 
 /// Persistent campaign state that survives across waves.
 /// City health and battery ammo are stored here between waves,
@@ -21,45 +28,170 @@ pub struct CampaignState {
     pub battery_ammo: Vec<(RegionId, usize, u32)>,
     /// Tech tree: unlocked interceptor types and upgrades
     pub tech_tree: TechTree,
+    /// Regions allied by treaty: their cities are defended and their slots can be leased
+    #[serde(default)]
+    pub allied_regions: Vec<RegionId>,
+    /// Relations meter with allies (0..RELATIONS_MAX). Lower relations raise diplomacy costs.
+    #[serde(default = "default_relations")]
+    pub relations: f32,
+    /// Strategic event awaiting the player's decision (blocks starting the next wave)
+    #[serde(default)]
+    pub pending_event: Option<StrategicEvent>,
+    /// Next-wave preview bought from an intel windfall; cleared when the wave starts
+    #[serde(default)]
+    pub intel_preview: Option<WavePreview>,
+    /// Graded result of every completed wave
+    #[serde(default)]
+    pub wave_scores: Vec<WaveScore>,
+    /// Medals earned so far this campaign
+    #[serde(default)]
+    pub medals: Vec<Medal>,
+    /// Endless mode: waves keep scaling past the normal campaign difficulty floors
+    #[serde(default)]
+    pub endless: bool,
+    /// Prestige level of the profile this campaign was started with
+    #[serde(default)]
+    pub prestige_level: u32,
+    /// Ammo capacity of every battery, including prestige bonuses
+    #[serde(default = "default_battery_max_ammo")]
+    pub battery_max_ammo: u32,
+    /// Bounds outside which entities leave the theater
+    #[serde(default)]
+    pub theater: TheaterBounds,
+    /// Difficulty preset chosen at NewGame
+    #[serde(default)]
+    pub difficulty: Difficulty,
+    /// Forecast for the next wave, shown in the strategic phase
+    #[serde(default)]
+    pub weather_forecast: Option<WeatherForecast>,
+    /// Lifetime totals for the statistics screen
+    #[serde(default)]
+    pub stats: LifetimeStats,
+    /// Custom map the campaign was started on; a prestige reset restarts on it
+    #[serde(default)]
+    pub map: Option<CustomMap>,
+}
+
+fn default_relations() -> f32 {
+    config::RELATIONS_START
+}
+
+fn default_battery_max_ammo() -> u32 {
+    config::BATTERY_MAX_AMMO
 }
 
 impl Default for CampaignState {
     fn default() -> Self {
-        let regions = crate::campaign::territory::define_regions();
+        Self::from_regions(crate::campaign::territory::define_regions())
+    }
+}
+
+impl CampaignState {
+    /// New campaign over the given region layout. The first region is the homeland.
+    pub fn from_regions(regions: Vec<Region>) -> Self {
+        let homeland = &regions[0];
+        let homeland_id = homeland.id;
 
         // Initialize health for homeland cities
         let mut city_healths = Vec::new();
-        let homeland = &regions[0];
         for (i, _city) in homeland.cities.iter().enumerate() {
-            city_healths.push((RegionId(0), i, config::CITY_MAX_HEALTH));
+            city_healths.push((homeland_id, i, config::CITY_MAX_HEALTH));
         }
 
         // Initialize ammo for homeland batteries (occupied slots)
         let mut battery_ammo = Vec::new();
         for (i, slot) in homeland.battery_slots.iter().enumerate() {
             if slot.occupied {
-                battery_ammo.push((RegionId(0), i, config::BATTERY_MAX_AMMO));
+                battery_ammo.push((homeland_id, i, config::BATTERY_MAX_AMMO));
             }
         }
 
         Self {
             resources: 100,
-            owned_regions: vec![RegionId(0)],
+            owned_regions: vec![homeland_id],
             regions,
             cost_table: CostTable::default(),
             total_waves_survived: 0,
             city_healths,
             battery_ammo,
             tech_tree: TechTree::default(),
+            allied_regions: Vec::new(),
+            relations: config::RELATIONS_START,
+            pending_event: None,
+            intel_preview: None,
+            wave_scores: Vec::new(),
+            medals: Vec::new(),
+            endless: false,
+            prestige_level: 0,
+            battery_max_ammo: config::BATTERY_MAX_AMMO,
+            theater: TheaterBounds::default(),
+            difficulty: Difficulty::Normal,
+            weather_forecast: None,
+            stats: LifetimeStats::default(),
+            map: None,
         }
     }
-}
 
-impl CampaignState {
-    /// Get all city definitions and their health across owned regions.
+    /// New campaign on a custom map, using its theater bounds if it defines them.
+    pub fn from_map(map: CustomMap) -> Self {
+        let mut cs = Self::from_regions(map.regions.clone());
+        cs.theater = map.theater.unwrap_or_default();
+        cs.map = Some(map);
+        cs
+    }
+
+    /// New campaign with the profile's prestige buffs applied.
+    pub fn with_profile(profile: &PlayerProfile, endless: bool) -> Self {
+        Self::default().apply_profile(profile, endless)
+    }
+
+    /// Apply prestige buffs and the endless setting to a fresh campaign.
+    pub fn apply_profile(mut self, profile: &PlayerProfile, endless: bool) -> Self {
+        self.endless = endless;
+        self.prestige_level = profile.prestige_level;
+        self.resources += profile.bonus_resources();
+        self.stats.record_earned(ResourceCategory::PrestigeBonus, profile.bonus_resources());
+        self.battery_max_ammo += profile.bonus_ammo();
+        for (_, _, ammo) in &mut self.battery_ammo {
+            *ammo = self.battery_max_ammo;
+        }
+        self
+    }
+
+    /// Deduct an already-validated cost and record what it was spent on.
+    pub fn spend(&mut self, cost: u32, category: ResourceCategory) {
+        self.resources -= cost;
+        self.stats.record_spent(category, cost);
+    }
+
+    /// Backfill stats for saves made before they were tracked.
+    pub fn migrate_stats(&mut self) {
+        if self.stats.waves_recorded == 0 && (self.total_waves_survived > 0 || !self.wave_scores.is_empty()) {
+            self.stats = LifetimeStats::from_wave_scores(&self.wave_scores, self.total_waves_survived);
+        }
+    }
+
+    /// Set the difficulty preset and the costs that depend on it.
+    pub fn with_difficulty(mut self, difficulty: Difficulty) -> Self {
+        self.difficulty = difficulty;
+        self.cost_table = CostTable::for_difficulty(difficulty);
+        self
+    }
+
+    /// Regions whose cities and batteries are projected into the tactical world:
+    /// owned regions first, then allies.
+    pub fn defended_regions(&self) -> Vec<RegionId> {
+        self.owned_regions
+            .iter()
+            .chain(self.allied_regions.iter())
+            .copied()
+            .collect()
+    }
+
+    /// Get all city definitions and their health across defended regions.
     pub fn active_cities(&self) -> Vec<(&CityDef, f32)> {
         let mut result = Vec::new();
-        for rid in &self.owned_regions {
+        for rid in &self.defended_regions() {
             let region = self.get_region(*rid).unwrap();
             for (i, city) in region.cities.iter().enumerate() {
                 let health = self
@@ -74,10 +206,10 @@ impl CampaignState {
         result
     }
 
-    /// Get all occupied battery slots and their ammo across owned regions.
+    /// Get all occupied (or leased) battery slots and their ammo across defended regions.
     pub fn active_batteries(&self) -> Vec<(&BatterySlot, u32)> {
         let mut result = Vec::new();
-        for rid in &self.owned_regions {
+        for rid in &self.defended_regions() {
             let region = self.get_region(*rid).unwrap();
             for (i, slot) in region.battery_slots.iter().enumerate() {
                 if slot.occupied {
@@ -108,6 +240,28 @@ impl CampaignState {
         result
     }
 
+    /// Get unoccupied battery slots in allied regions that can be leased.
+    pub fn leasable_battery_slots(&self) -> Vec<(RegionId, usize, &BatterySlot)> {
+        let mut result = Vec::new();
+        for rid in &self.allied_regions {
+            let region = self.get_region(*rid).unwrap();
+            for (i, slot) in region.battery_slots.iter().enumerate() {
+                if !slot.occupied {
+                    result.push((*rid, i, slot));
+                }
+            }
+        }
+        result
+    }
+
+    /// Get expandable regions that are not yet allied.
+    pub fn alliance_candidates(&self) -> Vec<&Region> {
+        self.expandable_regions()
+            .into_iter()
+            .filter(|r| !self.allied_regions.contains(&r.id))
+            .collect()
+    }
+
     /// Get regions adjacent to owned territory that can be expanded into.
     pub fn expandable_regions(&self) -> Vec<&Region> {
         let mut result = Vec::new();
@@ -145,6 +299,21 @@ pub struct CampaignSnapshot {
     pub regions: Vec<RegionSnapshot>,
     pub available_actions: Vec<AvailableAction>,
     pub tech_tree: TechTreeSnapshot,
+    pub allied_region_ids: Vec<u32>,
+    pub relations: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_event: Option<StrategicEvent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub intel_preview: Option<WavePreview>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weather_forecast: Option<WeatherForecast>,
+    pub wave_scores: Vec<WaveScore>,
+    pub medals: Vec<Medal>,
+    pub endless: bool,
+    pub prestige_level: u32,
+    pub difficulty: String,
+    /// Bounds the frontend passes back when asking for arc predictions
+    pub theater: TheaterBounds,
     /// Income from the last completed wave (only set on transition to Strategic)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub wave_income: Option<u32>,
@@ -156,6 +325,7 @@ pub struct RegionSnapshot {
     pub name: String,
     pub terrain: String,
     pub owned: bool,
+    pub allied: bool,
     pub expandable: bool,
     pub cities: Vec<CitySnapshotCampaign>,
     pub battery_slots: Vec<BatterySlotSnapshot>,
@@ -186,10 +356,13 @@ pub struct BatterySlotSnapshot {
 pub enum AvailableAction {
     ExpandRegion { region_id: u32, cost: u32 },
     PlaceBattery { region_id: u32, slot_index: u32, cost: u32 },
+    FormAlliance { region_id: u32, cost: u32 },
+    LeaseBattery { region_id: u32, slot_index: u32, cost: u32 },
     RestockAllBatteries { count: u32, cost: u32 },
     RepairCity { region_id: u32, city_index: u32, cost: u32, health_to_restore: f32 },
     UnlockInterceptor { interceptor_type: String, cost: u32, min_wave: u32 },
     UpgradeInterceptor { interceptor_type: String, axis: String, cost: u32, current_level: u32 },
+    Prestige { next_level: u32 },
     StartWave,
 }
 
@@ -249,6 +422,28 @@ mod tests {
         assert_eq!(available.len(), 0);
     }
 
+    #[test]
+    fn defended_regions_list_owned_before_allied() {
+        let mut cs = CampaignState::default();
+        cs.allied_regions.push(RegionId(2));
+        assert_eq!(cs.defended_regions(), vec![RegionId(0), RegionId(2)]);
+        assert_eq!(cs.alliance_candidates().len(), 1);
+        assert_eq!(cs.leasable_battery_slots().len(), 1);
+    }
+
+    #[test]
+    fn profile_buffs_starting_resources_and_ammo() {
+        let profile = PlayerProfile {
+            prestige_level: 2,
+            best_waves_survived: 0,
+        };
+        let cs = CampaignState::with_profile(&profile, true);
+        assert!(cs.endless);
+        assert_eq!(cs.resources, 100 + 2 * config::PRESTIGE_RESOURCES_PER_LEVEL);
+        assert_eq!(cs.battery_max_ammo, config::BATTERY_MAX_AMMO + 2 * config::PRESTIGE_AMMO_PER_LEVEL);
+        assert!(cs.battery_ammo.iter().all(|(_, _, a)| *a == cs.battery_max_ammo));
+    }
+
     #[test]
     fn city_health_initialized() {
         let cs = CampaignState::default();
@@ -11,6 +11,20 @@ pub enum GamePhase {
     Paused,
 }
 
+impl GamePhase {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GamePhase::MainMenu => "MainMenu",
+            GamePhase::Strategic => "Strategic",
+            GamePhase::WaveActive => "WaveActive",
+            GamePhase::WaveResult => "WaveResult",
+            GamePhase::RegionLost => "RegionLost",
+            GamePhase::CampaignOver => "CampaignOver",
+            GamePhase::Paused => "Paused",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameState {
     pub phase: GamePhase,
@@ -1,5 +1,6 @@
 pub mod campaign_state;
 pub mod game_state;
+pub mod schema;
 pub mod snapshot;
 pub mod wave_state;
 pub mod weather;
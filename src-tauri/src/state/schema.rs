@@ -0,0 +1,129 @@
+//! JSON schemas and TypeScript definitions for the IPC payloads, generated
+//! from the Rust types. `cargo run --bin export-types` writes them under
+//! `src/types/generated`; a test fails when the committed copies drift.
+
+use schemars::SchemaGenerator;
+use schemars::schema::{InstanceType, RootSchema, Schema, SchemaObject, SingleOrVec};
+use schemars::schema_for;
+use std::fmt::Write;
+
+use crate::state::snapshot::{EntityExtra, StateSnapshot};
+use crate::systems::input_system::PlayerCommand;
+
+/// Where the generated files live, relative to the src-tauri manifest
+pub const GENERATED_DIR: &str = "../src/types/generated";
+
+const TS_HEADER: &str = "// Generated from the Rust IPC types by `cargo run --bin export-types`. Do not edit.\n";
+
+/// JSON schema of each exported payload, by type name.
+pub fn json_schemas() -> Vec<(&'static str, RootSchema)> {
+    vec![
+        ("PlayerCommand", schema_for!(PlayerCommand)),
+        ("StateSnapshot", schema_for!(StateSnapshot)),
+        ("EntityExtra", schema_for!(EntityExtra)),
+    ]
+}
+
+/// TypeScript definitions for the exported payloads and every type they reference.
+pub fn typescript() -> String {
+    let mut generator = SchemaGenerator::default();
+    generator.subschema_for::<PlayerCommand>();
+    generator.subschema_for::<StateSnapshot>();
+    generator.subschema_for::<EntityExtra>();
+
+    let mut out = String::from(TS_HEADER);
+    for (name, schema) in generator.definitions() {
+        out.push('\n');
+        if let Some(doc) = description(schema) {
+            let _ = writeln!(out, "/** {doc} */");
+        }
+        let _ = writeln!(out, "export type {name} = {};", ts_type(schema, 0));
+    }
+    out
+}
+
+/// Every generated file as (path under GENERATED_DIR, contents).
+pub fn generated_files() -> Vec<(String, String)> {
+    let mut files = vec![("ipc.ts".to_string(), typescript())];
+    for (name, schema) in json_schemas() {
+        let json = serde_json::to_string_pretty(&schema).expect("schemas serialize");
+        files.push((format!("schema/{name}.schema.json"), json + "\n"));
+    }
+    files
+}
+
+fn description(schema: &Schema) -> Option<String> {
+    let Schema::Object(obj) = schema else { return None };
+    let doc = obj.metadata.as_ref()?.description.as_ref()?;
+    Some(doc.split_whitespace().collect::<Vec<_>>().join(" "))
+}
+
+fn join(schemas: &[Schema], sep: &str, indent: usize) -> String {
+    schemas.iter().map(|s| ts_type(s, indent)).collect::<Vec<_>>().join(sep)
+}
+
+fn ts_type(schema: &Schema, indent: usize) -> String {
+    let Schema::Object(obj) = schema else {
+        return "unknown".to_string();
+    };
+    if let Some(reference) = &obj.reference {
+        return reference.trim_start_matches("#/definitions/").to_string();
+    }
+    if let Some(values) = &obj.enum_values {
+        return values.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(" | ");
+    }
+    if let Some(sub) = &obj.subschemas {
+        if let Some(variants) = sub.one_of.as_ref().or(sub.any_of.as_ref()) {
+            return join(variants, " | ", indent);
+        }
+        // A documented reference is wrapped in a single-entry allOf
+        if let Some([inner]) = sub.all_of.as_deref() {
+            return ts_type(inner, indent);
+        }
+    }
+    match &obj.instance_type {
+        Some(SingleOrVec::Single(t)) => instance_type(obj, **t, indent),
+        Some(SingleOrVec::Vec(types)) => types
+            .iter()
+            .map(|t| instance_type(obj, *t, indent))
+            .collect::<Vec<_>>()
+            .join(" | "),
+        None => "unknown".to_string(),
+    }
+}
+
+fn instance_type(obj: &SchemaObject, t: InstanceType, indent: usize) -> String {
+    match t {
+        InstanceType::Null => "null".to_string(),
+        InstanceType::Boolean => "boolean".to_string(),
+        InstanceType::Number | InstanceType::Integer => "number".to_string(),
+        InstanceType::String => "string".to_string(),
+        InstanceType::Array => match obj.array.as_ref().and_then(|a| a.items.as_ref()) {
+            Some(SingleOrVec::Single(item)) => {
+                let item = ts_type(item, indent);
+                if item.contains('|') { format!("({item})[]") } else { format!("{item}[]") }
+            }
+            Some(SingleOrVec::Vec(items)) => format!("[{}]", join(items, ", ", indent)),
+            None => "unknown[]".to_string(),
+        },
+        InstanceType::Object => ts_object(obj, indent),
+    }
+}
+
+fn ts_object(obj: &SchemaObject, indent: usize) -> String {
+    let Some(object) = &obj.object else {
+        return "Record<string, unknown>".to_string();
+    };
+    let pad = "  ".repeat(indent + 1);
+    let mut out = String::from("{\n");
+    for (key, schema) in &object.properties {
+        if let Some(doc) = description(schema) {
+            let _ = writeln!(out, "{pad}/** {doc} */");
+        }
+        let optional = if object.required.contains(key) { "" } else { "?" };
+        let _ = writeln!(out, "{pad}{key}{optional}: {};", ts_type(schema, indent + 1));
+    }
+    out.push_str(&"  ".repeat(indent));
+    out.push('}');
+    out
+}
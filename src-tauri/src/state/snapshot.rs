@@ -1,6 +1,8 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum EntityType {
     Missile,
     Interceptor,
@@ -9,9 +11,11 @@ pub enum EntityType {
     Battery,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct EntitySnapshot {
     pub id: u32,
+    /// Generation of the entity slot; (id, generation) is unique over a session
+    pub generation: u32,
     pub entity_type: EntityType,
     pub x: f32,
     pub y: f32,
@@ -19,18 +23,40 @@ pub struct EntitySnapshot {
     pub vx: f32,
     pub vy: f32,
     pub extra: Option<EntityExtra>,
+    /// Recent positions for missiles and interceptors, oldest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub trail: Vec<(f32, f32)>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub enum EntityExtra {
     Shockwave { radius: f32, max_radius: f32 },
     City { health: f32, max_health: f32 },
-    Battery { ammo: u32, max_ammo: u32 },
-    Interceptor { burn_remaining: f32, burn_time: f32, interceptor_type: String },
-    Missile { is_mirv: bool, detected_by_radar: bool, detected_by_glow: bool },
+    Battery {
+        ammo: u32,
+        max_ammo: u32,
+        reload_remaining: f32,
+        reload_time: f32,
+        in_flight: u32,
+        max_in_flight: u32,
+        arc_min: f32,
+        arc_max: f32,
+    },
+    Interceptor { burn_remaining: f32, burn_time: f32, interceptor_type: Cow<'static, str> },
+    Missile {
+        is_mirv: bool,
+        warhead_type: Cow<'static, str>,
+        detected_by_radar: bool,
+        detected_by_glow: bool,
+        /// MIRV bus only: estimated split altitude and seconds until split
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        estimated_split_altitude: Option<f32>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        split_countdown: Option<f32>,
+    },
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct StateSnapshot {
     pub tick: u64,
     pub wave_number: u32,
@@ -40,4 +66,9 @@ pub struct StateSnapshot {
     pub weather: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub wind_x: Option<f32>,
+    /// The game loop is over its tick budget and shedding load
+    #[serde(default)]
+    pub degraded: bool,
+    #[serde(default)]
+    pub paused: bool,
 }
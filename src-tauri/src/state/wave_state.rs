@@ -8,6 +8,8 @@ pub struct WaveDefinition {
     pub flight_time_max: f32,
     pub mirv_count: u32,
     pub mirv_child_count: u32,
+    /// Low-altitude missiles entering from the side (coastal regions)
+    pub sea_skimmer_count: u32,
 }
 
 impl WaveDefinition {
@@ -22,6 +24,7 @@ impl WaveDefinition {
             flight_time_max: (config::MISSILE_FLIGHT_TIME_MAX - wave_number as f32 * 0.5).max(5.0),
             mirv_count: 0,
             mirv_child_count: 0,
+            sea_skimmer_count: 0,
         }
     }
 }
@@ -32,9 +35,13 @@ pub struct WaveState {
     pub missiles_spawned: u32,
     pub missiles_destroyed: u32,
     pub missiles_impacted: u32,
+    pub missiles_exited: u32,
     pub interceptors_launched: u32,
     pub mirv_spawned: u32,
+    pub sea_skimmers_spawned: u32,
     pub spawn_timer: u32,
+    /// Total HP of city damage taken this wave (for grading)
+    pub city_damage: f32,
 }
 
 impl WaveState {
@@ -44,9 +51,12 @@ impl WaveState {
             missiles_spawned: 0,
             missiles_destroyed: 0,
             missiles_impacted: 0,
+            missiles_exited: 0,
             interceptors_launched: 0,
             mirv_spawned: 0,
+            sea_skimmers_spawned: 0,
             spawn_timer: 0,
+            city_damage: 0.0,
         }
     }
 
@@ -1,4 +1,4 @@
-use rand::Rng;
+use rand::{Rng, SeedableRng};
 use rand_chacha::ChaChaRng;
 use serde::{Deserialize, Serialize};
 
@@ -13,6 +13,8 @@ pub enum WeatherCondition {
 }
 
 impl WeatherCondition {
+    const ORDERED: [WeatherCondition; 4] = [Self::Clear, Self::Overcast, Self::Storm, Self::Severe];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             Self::Clear => "Clear",
@@ -84,6 +86,81 @@ pub fn generate_weather(rng: &mut ChaChaRng, wave_number: u32) -> WeatherState {
     }
 }
 
+/// Strategic-phase forecast for the next wave's weather.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WeatherForecast {
+    pub wave_number: u32,
+    pub condition: WeatherCondition,
+    /// Forecast wind range; always contains the actual wind
+    pub wind_x_min: f32,
+    pub wind_x_max: f32,
+    /// Chance the forecast condition is right
+    pub confidence: f32,
+}
+
+/// The weather stream for one wave of a campaign. The wave's actual weather is
+/// its first draw and the forecast noise comes after, so neither has to be saved.
+fn wave_weather_rng(seed: u64, wave_number: u32) -> ChaChaRng {
+    ChaChaRng::seed_from_u64(
+        seed.wrapping_add(wave_number as u64 * 1000)
+            .wrapping_add(config::WEATHER_SEED_OFFSET),
+    )
+}
+
+/// Actual weather of `wave_number` in the campaign seeded with `seed`.
+pub fn weather_for_wave(seed: u64, wave_number: u32) -> WeatherState {
+    generate_weather(&mut wave_weather_rng(seed, wave_number), wave_number)
+}
+
+/// Forecast of `wave_number`'s weather in the campaign seeded with `seed`.
+pub fn forecast_for_wave(seed: u64, wave_number: u32) -> WeatherForecast {
+    let mut rng = wave_weather_rng(seed, wave_number);
+    let actual = generate_weather(&mut rng, wave_number);
+    forecast(&mut rng, &actual, wave_number)
+}
+
+/// Forecast the already-rolled `actual` weather for `wave_number`. The condition
+/// is off by one step with probability 1 - WEATHER_FORECAST_ACCURACY and the wind
+/// range is centered on a noisy estimate. Waves before WEATHER_FIRST_WAVE are
+/// forecast exactly.
+pub fn forecast(rng: &mut ChaChaRng, actual: &WeatherState, wave_number: u32) -> WeatherForecast {
+    if wave_number < config::WEATHER_FIRST_WAVE {
+        return WeatherForecast {
+            wave_number,
+            condition: actual.condition,
+            wind_x_min: actual.wind_x,
+            wind_x_max: actual.wind_x,
+            confidence: 1.0,
+        };
+    }
+
+    let condition = if rng.r#gen::<f32>() < config::WEATHER_FORECAST_ACCURACY {
+        actual.condition
+    } else {
+        let idx = WeatherCondition::ORDERED
+            .iter()
+            .position(|c| *c == actual.condition)
+            .unwrap_or(0);
+        let shifted = match idx {
+            0 => 1,
+            i if i == WeatherCondition::ORDERED.len() - 1 => i - 1,
+            i if rng.gen_bool(0.5) => i + 1,
+            i => i - 1,
+        };
+        WeatherCondition::ORDERED[shifted]
+    };
+
+    let error = config::WEATHER_FORECAST_WIND_ERROR;
+    let estimate = actual.wind_x + rng.gen_range(-error..=error);
+    WeatherForecast {
+        wave_number,
+        condition,
+        wind_x_min: estimate - error,
+        wind_x_max: estimate + error,
+        confidence: config::WEATHER_FORECAST_ACCURACY,
+    }
+}
+
 /// Radar range multiplier based on weather condition.
 pub fn radar_multiplier(condition: WeatherCondition) -> f32 {
     match condition {
@@ -107,7 +184,6 @@ pub fn glow_visibility(condition: WeatherCondition) -> f32 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use rand::SeedableRng;
 
     #[test]
     fn weather_clear_before_wave_16() {
@@ -159,4 +235,30 @@ mod tests {
         assert_eq!(glow_visibility(WeatherCondition::Severe), 0.0);
         assert!(glow_visibility(WeatherCondition::Clear) > 0.0);
     }
+
+    #[test]
+    fn forecast_wind_range_contains_actual() {
+        let mut rng = ChaChaRng::seed_from_u64(7);
+        let mut correct = 0;
+        for wave in 16..216 {
+            let actual = generate_weather(&mut rng, wave);
+            let f = forecast(&mut rng, &actual, wave);
+            assert!(f.wind_x_min <= actual.wind_x && actual.wind_x <= f.wind_x_max);
+            if f.condition == actual.condition {
+                correct += 1;
+            }
+        }
+        // ~80% accurate over 200 samples
+        assert!((140..=185).contains(&correct), "Forecast accuracy off: {correct}/200");
+    }
+
+    #[test]
+    fn forecast_before_weather_waves_is_exact() {
+        let mut rng = ChaChaRng::seed_from_u64(7);
+        let actual = generate_weather(&mut rng, 3);
+        let f = forecast(&mut rng, &actual, 3);
+        assert_eq!(f.condition, WeatherCondition::Clear);
+        assert_eq!(f.confidence, 1.0);
+        assert_eq!(f.wind_x_min, f.wind_x_max);
+    }
 }
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::campaign::territory::TheaterBounds;
+use crate::ecs::components::Ballistic;
 use crate::engine::config::{self, InterceptorProfile};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -7,6 +9,41 @@ pub struct ArcPrediction {
     pub points: Vec<(f32, f32)>,
     pub time_to_target: f32,
     pub reaches_target: bool,
+    /// Point the interceptor steers toward. Equals the target unless a
+    /// launch solution has offset it to compensate for gravity and wind.
+    pub aim_x: f32,
+    pub aim_y: f32,
+}
+
+/// Geometry of one interceptor shot: where it launches, the point it steers
+/// toward, and the point it detonates relative to.
+#[derive(Debug, Clone, Copy)]
+pub struct ShotGeometry {
+    pub start_x: f32,
+    pub start_y: f32,
+    pub aim_x: f32,
+    pub aim_y: f32,
+    pub target_x: f32,
+    pub target_y: f32,
+}
+
+impl ShotGeometry {
+    /// A shot steered straight at its target, with no launch-solution offset.
+    pub fn direct(start_x: f32, start_y: f32, target_x: f32, target_y: f32) -> Self {
+        Self {
+            start_x,
+            start_y,
+            aim_x: target_x,
+            aim_y: target_y,
+            target_x,
+            target_y,
+        }
+    }
+
+    /// The same shot steered toward (aim_x, aim_y) instead.
+    pub fn with_aim(self, aim_x: f32, aim_y: f32) -> Self {
+        Self { aim_x, aim_y, ..self }
+    }
 }
 
 /// Predict the trajectory of an interceptor launched from (start_x, start_y)
@@ -21,9 +58,30 @@ pub fn predict_arc(
     target_y: f32,
     profile: &InterceptorProfile,
     wind_x: f32,
+    theater: &TheaterBounds,
 ) -> ArcPrediction {
-    let dx = target_x - start_x;
-    let dy = target_y - start_y;
+    predict_arc_with_aim(&ShotGeometry::direct(start_x, start_y, target_x, target_y), profile, wind_x, theater)
+}
+
+/// Predict the trajectory of an interceptor that steers toward the shot's aim
+/// point but detonates relative to its target, as launched interceptors do.
+/// The path ends at the ground or where the interceptor would leave `theater`.
+pub fn predict_arc_with_aim(
+    shot: &ShotGeometry,
+    profile: &InterceptorProfile,
+    wind_x: f32,
+    theater: &TheaterBounds,
+) -> ArcPrediction {
+    let ShotGeometry {
+        start_x,
+        start_y,
+        aim_x,
+        aim_y,
+        target_x,
+        target_y,
+    } = *shot;
+    let dx = aim_x - start_x;
+    let dy = aim_y - start_y;
     let dist = (dx * dx + dy * dy).sqrt().max(1.0);
     let dir_x = dx / dist;
     let dir_y = dy / dist;
@@ -45,8 +103,8 @@ pub fn predict_arc(
     for _ in 0..max_steps {
         // Thrust — matches thrust.rs
         if burn_remaining > 0.0 {
-            let tdx = target_x - x;
-            let tdy = target_y - y;
+            let tdx = aim_x - x;
+            let tdy = aim_y - y;
             let tdist = (tdx * tdx + tdy * tdy).sqrt();
             if tdist > 1e-6 {
                 let tdir_x = tdx / tdist;
@@ -116,11 +174,8 @@ pub fn predict_arc(
             }
         }
 
-        // OOB / ground check
-        if y <= config::GROUND_Y
-            || !(-config::OOB_MARGIN..=config::WORLD_WIDTH + config::OOB_MARGIN).contains(&x)
-            || y > config::WORLD_HEIGHT + config::OOB_MARGIN
-        {
+        // Ground / theater check — matches cleanup.rs
+        if y <= config::GROUND_Y || !theater.contains(x, y) {
             break;
         }
     }
@@ -129,7 +184,122 @@ pub fn predict_arc(
         points,
         time_to_target: time,
         reaches_target: reached_target,
+        aim_x,
+        aim_y,
+    }
+}
+
+/// Predicted ground impact for one enemy missile.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImpactPrediction {
+    pub entity_id: u32,
+    pub generation: u32,
+    pub x: f32,
+    pub y: f32,
+    pub time_to_impact: f32,
+    /// MIRV carriers split before impact; the point is where the bus would land.
+    pub is_mirv: bool,
+}
+
+/// Predicted impacts for all detected missiles at a given tick.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImpactPredictions {
+    pub tick: u64,
+    pub impacts: Vec<ImpactPrediction>,
+}
+
+/// Kinematic state of an unpowered enemy missile, for predicting where it will be.
+#[derive(Debug, Clone, Copy)]
+pub struct MissileTrack {
+    pub x: f32,
+    pub y: f32,
+    pub vx: f32,
+    pub vy: f32,
+    pub ballistic: Ballistic,
+}
+
+/// Predict where a missile will be `time` seconds from now, or None if it
+/// reaches the ground or leaves `theater` first.
+pub fn predict_missile_position(
+    track: &MissileTrack,
+    wind_x: f32,
+    theater: &TheaterBounds,
+    time: f32,
+) -> Option<(f32, f32)> {
+    let MissileTrack { mut x, mut y, mut vx, mut vy, ballistic } = *track;
+    let steps = (time / config::DT).round() as usize;
+    for _ in 0..steps {
+        step_unpowered(&mut x, &mut y, &mut vx, &mut vy, &ballistic, wind_x);
+        if y <= config::GROUND_Y || !theater.contains(x, y) {
+            return None;
+        }
+    }
+    Some((x, y))
+}
+
+/// Advance an unpowered body by one tick.
+/// Replicates gravity.rs, drag.rs, wind.rs and movement.rs.
+fn step_unpowered(x: &mut f32, y: &mut f32, vx: &mut f32, vy: &mut f32, ballistic: &Ballistic, wind_x: f32) {
+    // Gravity — matches gravity.rs
+    *vy -= config::GRAVITY * config::DT;
+
+    // Drag — matches drag.rs
+    let speed_sq = *vx * *vx + *vy * *vy;
+    let speed = speed_sq.sqrt();
+    if speed > 1e-6 {
+        let h = (*y - config::GROUND_Y).max(0.0);
+        let rho = config::AIR_DENSITY_SEA_LEVEL * (-h / config::ATMOSPHERE_SCALE_HEIGHT).exp();
+        let drag_accel = 0.5 * rho * speed_sq * ballistic.drag_coefficient
+            * ballistic.cross_section
+            / ballistic.mass;
+        let drag_factor = (drag_accel * config::DT / speed).min(0.99);
+        *vx -= *vx * drag_factor;
+        *vy -= *vy * drag_factor;
+    }
+
+    // Wind — matches wind.rs
+    if wind_x != 0.0 {
+        let altitude = (*y - config::GROUND_Y).max(0.0);
+        *vx += wind_x * altitude * config::WIND_ALTITUDE_FACTOR * config::DT;
+    }
+
+    // Movement — matches movement.rs
+    *x += *vx * config::DT;
+    *y += *vy * config::DT;
+}
+
+/// Predict where an unpowered missile at (x, y) moving at (vx, vy) will hit the ground.
+/// Replicates gravity.rs, drag.rs, wind.rs and movement.rs; returns None if the
+/// missile leaves `theater` or does not land within the prediction horizon.
+/// Result is (impact_x, time_to_impact).
+pub fn predict_missile_impact(
+    x: f32,
+    y: f32,
+    vx: f32,
+    vy: f32,
+    ballistic: &Ballistic,
+    wind_x: f32,
+    theater: &TheaterBounds,
+) -> Option<(f32, f32)> {
+    let (mut x, mut y, mut vx, mut vy) = (x, y, vx, vy);
+    let max_steps = (config::IMPACT_PREDICTION_HORIZON / config::DT) as usize;
+    let mut time = 0.0_f32;
+
+    for _ in 0..max_steps {
+        if y <= config::GROUND_Y {
+            return Some((x, time));
+        }
+
+        step_unpowered(&mut x, &mut y, &mut vx, &mut vy, ballistic, wind_x);
+        time += config::DT;
+
+        // Theater bounds — matches cleanup.rs
+        if !theater.contains(x, y) {
+            return None;
+        }
     }
+
+    None
 }
 
 #[cfg(test)]
@@ -141,16 +311,20 @@ mod tests {
         config::interceptor_profile(InterceptorType::Standard)
     }
 
+    fn theater() -> TheaterBounds {
+        TheaterBounds::default()
+    }
+
     #[test]
     fn arc_reaches_straight_up() {
-        let pred = predict_arc(160.0, config::GROUND_Y, 160.0, 400.0, &standard_profile(), 0.0);
+        let pred = predict_arc(160.0, config::GROUND_Y, 160.0, 400.0, &standard_profile(), 0.0, &theater());
         assert!(pred.reaches_target, "Should reach target directly above");
         assert!(pred.time_to_target > 0.5 && pred.time_to_target < 5.0);
     }
 
     #[test]
     fn arc_reaches_diagonal_target() {
-        let pred = predict_arc(160.0, config::GROUND_Y, 640.0, 400.0, &standard_profile(), 0.0);
+        let pred = predict_arc(160.0, config::GROUND_Y, 640.0, 400.0, &standard_profile(), 0.0, &theater());
         assert!(pred.reaches_target, "Should reach diagonal target");
         assert!(pred.time_to_target > 0.5 && pred.time_to_target < 8.0);
     }
@@ -158,13 +332,13 @@ mod tests {
     #[test]
     fn arc_unreachable_far_target() {
         // Target extremely far away — interceptor should run out of energy
-        let pred = predict_arc(160.0, config::GROUND_Y, 10000.0, 10000.0, &standard_profile(), 0.0);
+        let pred = predict_arc(160.0, config::GROUND_Y, 10000.0, 10000.0, &standard_profile(), 0.0, &theater());
         assert!(!pred.reaches_target, "Should not reach extremely far target");
     }
 
     #[test]
     fn arc_starts_at_battery_position() {
-        let pred = predict_arc(160.0, config::GROUND_Y, 640.0, 400.0, &standard_profile(), 0.0);
+        let pred = predict_arc(160.0, config::GROUND_Y, 640.0, 400.0, &standard_profile(), 0.0, &theater());
         assert!(!pred.points.is_empty());
         let (px, py) = pred.points[0];
         assert!((px - 160.0).abs() < 0.01);
@@ -173,7 +347,7 @@ mod tests {
 
     #[test]
     fn arc_has_reasonable_point_count() {
-        let pred = predict_arc(160.0, config::GROUND_Y, 640.0, 400.0, &standard_profile(), 0.0);
+        let pred = predict_arc(160.0, config::GROUND_Y, 640.0, 400.0, &standard_profile(), 0.0, &theater());
         assert!(
             pred.points.len() > 10,
             "Should have enough points for rendering: got {}",
@@ -183,7 +357,7 @@ mod tests {
 
     #[test]
     fn arc_from_right_battery() {
-        let pred = predict_arc(1120.0, config::GROUND_Y, 640.0, 400.0, &standard_profile(), 0.0);
+        let pred = predict_arc(1120.0, config::GROUND_Y, 640.0, 400.0, &standard_profile(), 0.0, &theater());
         assert!(pred.reaches_target, "Right battery should reach center target");
         let (px, _) = pred.points[0];
         assert!((px - 1120.0).abs() < 0.01);
@@ -191,9 +365,9 @@ mod tests {
 
     #[test]
     fn arc_sprint_faster_than_standard() {
-        let std_pred = predict_arc(160.0, config::GROUND_Y, 400.0, 200.0, &standard_profile(), 0.0);
+        let std_pred = predict_arc(160.0, config::GROUND_Y, 400.0, 200.0, &standard_profile(), 0.0, &theater());
         let sprint_profile = config::interceptor_profile(InterceptorType::Sprint);
-        let sprint_pred = predict_arc(160.0, config::GROUND_Y, 400.0, 200.0, &sprint_profile, 0.0);
+        let sprint_pred = predict_arc(160.0, config::GROUND_Y, 400.0, 200.0, &sprint_profile, 0.0, &theater());
         assert!(std_pred.reaches_target);
         assert!(sprint_pred.reaches_target);
         assert!(
@@ -206,9 +380,9 @@ mod tests {
 
     #[test]
     fn arc_sprint_vs_standard_different_trajectories() {
-        let std_pred = predict_arc(160.0, config::GROUND_Y, 640.0, 400.0, &standard_profile(), 0.0);
+        let std_pred = predict_arc(160.0, config::GROUND_Y, 640.0, 400.0, &standard_profile(), 0.0, &theater());
         let sprint_profile = config::interceptor_profile(InterceptorType::Sprint);
-        let sprint_pred = predict_arc(160.0, config::GROUND_Y, 640.0, 400.0, &sprint_profile, 0.0);
+        let sprint_pred = predict_arc(160.0, config::GROUND_Y, 640.0, 400.0, &sprint_profile, 0.0, &theater());
         // Different point counts indicate different trajectories
         assert_ne!(
             std_pred.points.len(),
@@ -219,8 +393,8 @@ mod tests {
 
     #[test]
     fn arc_prediction_with_wind() {
-        let no_wind = predict_arc(640.0, config::GROUND_Y, 640.0, 400.0, &standard_profile(), 0.0);
-        let with_wind = predict_arc(640.0, config::GROUND_Y, 640.0, 400.0, &standard_profile(), 15.0);
+        let no_wind = predict_arc(640.0, config::GROUND_Y, 640.0, 400.0, &standard_profile(), 0.0, &theater());
+        let with_wind = predict_arc(640.0, config::GROUND_Y, 640.0, 400.0, &standard_profile(), 15.0, &theater());
         // Wind should shift the final x position
         let no_wind_last = no_wind.points.last().unwrap().0;
         let wind_last = with_wind.points.last().unwrap().0;
@@ -229,4 +403,65 @@ mod tests {
             "Wind should curve the arc: no_wind_x={no_wind_last:.1}, wind_x={wind_last:.1}"
         );
     }
+
+    fn missile_ballistic() -> Ballistic {
+        Ballistic {
+            drag_coefficient: config::MISSILE_DRAG_COEFF,
+            mass: config::MISSILE_MASS,
+            cross_section: config::MISSILE_CROSS_SECTION,
+        }
+    }
+
+    #[test]
+    fn missile_falling_straight_down_impacts_below() {
+        let (x, t) = predict_missile_impact(400.0, 300.0, 0.0, -50.0, &missile_ballistic(), 0.0, &theater())
+            .expect("Missile should land");
+        assert!((x - 400.0).abs() < 0.01);
+        assert!(t > 0.0 && t < 5.0);
+    }
+
+    #[test]
+    fn missile_impact_drifts_with_wind() {
+        let calm = predict_missile_impact(400.0, 600.0, 0.0, -20.0, &missile_ballistic(), 0.0, &theater()).unwrap();
+        let windy = predict_missile_impact(400.0, 600.0, 0.0, -20.0, &missile_ballistic(), config::WIND_SPEED_OVERCAST, &theater()).unwrap();
+        assert!(windy.0 > calm.0 + 1.0, "Wind should push impact point: calm={}, windy={}", calm.0, windy.0);
+    }
+
+    #[test]
+    fn missile_position_follows_its_track() {
+        let track = MissileTrack {
+            x: 400.0,
+            y: 600.0,
+            vx: 30.0,
+            vy: -20.0,
+            ballistic: missile_ballistic(),
+        };
+        let (x, y) = predict_missile_position(&track, 0.0, &theater(), 1.0).expect("Still airborne after 1s");
+        assert!(x > 420.0 && x < 431.0, "x {x}");
+        assert!(y < 580.0, "y {y}");
+        assert!(predict_missile_position(&track, 0.0, &theater(), 60.0).is_none());
+    }
+
+    #[test]
+    fn narrow_theater_cuts_predictions_short() {
+        let narrow = TheaterBounds {
+            max_x: 500.0,
+            ..TheaterBounds::default()
+        };
+        let drifting = |theater: &TheaterBounds| {
+            predict_missile_impact(400.0, 600.0, 60.0, -20.0, &missile_ballistic(), 0.0, theater)
+        };
+        assert!(drifting(&theater()).is_some());
+        assert!(drifting(&narrow).is_none(), "Missile leaves the narrow theater before landing");
+
+        let pred = predict_arc(160.0, config::GROUND_Y, 900.0, 400.0, &standard_profile(), 0.0, &narrow);
+        assert!(!pred.reaches_target);
+        assert!(pred.points.iter().all(|&(x, _)| x <= 500.0 + 10.0));
+    }
+
+    #[test]
+    fn missile_leaving_world_has_no_impact() {
+        let pred = predict_missile_impact(1200.0, 600.0, 800.0, 0.0, &missile_ballistic(), 0.0, &theater());
+        assert!(pred.is_none());
+    }
 }
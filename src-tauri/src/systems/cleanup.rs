@@ -1,16 +1,38 @@
+use crate::campaign::territory::TheaterBounds;
+use crate::ecs::components::EntityKind;
 use crate::ecs::entity::EntityId;
 use crate::ecs::world::World;
-use crate::engine::config;
+use crate::events::game_events::{GameEvent, InterceptorSelfDestructEvent, ThreatExitedEvent};
 
-/// Remove entities that are expired (lifetime) or out of bounds.
-pub fn run(world: &mut World) {
-    let mut to_despawn: Vec<EntityId> = Vec::new();
+/// Working storage for cleanup, reused across ticks.
+#[derive(Debug, Default)]
+pub struct CleanupBuffers {
+    alive: Vec<EntityId>,
+    to_despawn: Vec<EntityId>,
+}
+
+pub struct CleanupResult {
+    pub events: Vec<GameEvent>,
+    pub missiles_exited: u32,
+}
+
+/// Remove entities that are expired (lifetime) or outside the theater.
+/// Interceptors leaving the theater self-destruct and missiles are reported
+/// as exiting, so neither disappears silently.
+pub fn run(world: &mut World, theater: &TheaterBounds, tick: u64, buffers: &mut CleanupBuffers) -> CleanupResult {
+    let mut result = CleanupResult {
+        events: Vec::new(),
+        missiles_exited: 0,
+    };
+    let CleanupBuffers { alive, to_despawn } = buffers;
+    world.alive_entities_into(alive);
+    to_despawn.clear();
 
-    for idx in world.alive_entities() {
+    for &id in alive.iter() {
         let mut should_despawn = false;
 
         // Check lifetime expiry
-        if let Some(ref mut lifetime) = world.lifetimes[idx] {
+        if let Some(lifetime) = world.lifetimes.get_mut(id) {
             if lifetime.remaining_ticks == 0 {
                 should_despawn = true;
             } else {
@@ -18,28 +40,43 @@ pub fn run(world: &mut World) {
             }
         }
 
-        // Check out of bounds
-        if let Some(ref transform) = world.transforms[idx] {
-            let margin = config::OOB_MARGIN;
-            if transform.x < -margin
-                || transform.x > config::WORLD_WIDTH + margin
-                || transform.y < -margin
-                || transform.y > config::WORLD_HEIGHT + margin
-            {
-                should_despawn = true;
+        // Check theater bounds
+        if let Some(transform) = world.transforms.get(id)
+            && !theater.contains(transform.x, transform.y)
+        {
+            should_despawn = true;
+            match world.markers.get(id).map(|m| m.kind) {
+                Some(EntityKind::Interceptor) => {
+                    result.events.push(GameEvent::InterceptorSelfDestruct(InterceptorSelfDestructEvent {
+                        entity_id: id.index,
+                        generation: id.generation,
+                        x: transform.x,
+                        y: transform.y,
+                        tick,
+                    }));
+                }
+                Some(EntityKind::Missile) => {
+                    result.missiles_exited += 1;
+                    result.events.push(GameEvent::ThreatExited(ThreatExitedEvent {
+                        entity_id: id.index,
+                        generation: id.generation,
+                        x: transform.x,
+                        y: transform.y,
+                        tick,
+                    }));
+                }
+                _ => {}
             }
         }
 
         if should_despawn {
-            // Reconstruct the EntityId from index
-            // We need to look up the generation from the allocator
-            if let Some(generation) = world.allocator.generation_of(idx as u32) {
-                to_despawn.push(EntityId::new(idx as u32, generation));
-            }
+            to_despawn.push(id);
         }
     }
 
-    for id in to_despawn {
+    for &id in to_despawn.iter() {
         world.despawn(id);
     }
+
+    result
 }
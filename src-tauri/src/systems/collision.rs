@@ -2,12 +2,27 @@ use crate::ecs::components::*;
 use crate::ecs::entity::EntityId;
 use crate::ecs::world::World;
 use crate::engine::config;
-use crate::events::game_events::{DetonationEvent, GameEvent};
+use crate::events::game_events::{DetonationEvent, GameEvent, MirvBusKilledEvent};
+
+/// Working storage for collision, reused across ticks.
+#[derive(Debug, Default)]
+pub struct CollisionBuffers {
+    /// (x, y, radius, force, source)
+    shockwaves: Vec<(f32, f32, f32, f32, Option<InterceptorType>)>,
+    /// (id, x, y, kind)
+    targets: Vec<(EntityId, f32, f32, EntityKind)>,
+    to_destroy: Vec<(EntityId, f32, f32, EntityKind, Option<InterceptorType>)>,
+    /// (id, push_x, push_y)
+    to_deflect: Vec<(EntityId, f32, f32)>,
+    aggregated_deflect: Vec<(EntityId, f32, f32)>,
+}
 
 pub struct CollisionResult {
     pub events: Vec<GameEvent>,
     pub missiles_destroyed: u32,
     pub interceptors_destroyed: u32,
+    /// Interceptor type credited for each missile destroyed, where there is one
+    pub kill_sources: Vec<InterceptorType>,
 }
 
 /// Check shockwave proximity against all destructible entities (missiles + interceptors).
@@ -16,56 +31,57 @@ pub struct CollisionResult {
 ///     Missiles trigger chain reaction shockwaves. Interceptors do not.
 ///   - Deflect zone (DESTROY_RATIO * radius <= dist < radius): push entity velocity
 ///     away from shockwave center.
-pub fn run(world: &mut World, tick: u64) -> CollisionResult {
+pub fn run(world: &mut World, tick: u64, buffers: &mut CollisionBuffers) -> CollisionResult {
     let mut result = CollisionResult {
         events: Vec::new(),
         missiles_destroyed: 0,
         interceptors_destroyed: 0,
+        kill_sources: Vec::new(),
     };
 
-    // Gather active shockwave data: (idx, x, y, radius, force)
-    let shockwaves: Vec<(usize, f32, f32, f32, f32)> = world
-        .alive_entities()
-        .iter()
-        .filter_map(|&idx| {
-            let marker = world.markers[idx].as_ref()?;
-            if marker.kind != EntityKind::Shockwave {
-                return None;
-            }
-            let t = world.transforms[idx].as_ref()?;
-            let sw = world.shockwaves[idx].as_ref()?;
-            Some((idx, t.x, t.y, sw.radius, sw.force))
-        })
-        .collect();
+    let CollisionBuffers {
+        shockwaves,
+        targets,
+        to_destroy,
+        to_deflect,
+        aggregated_deflect,
+    } = buffers;
+
+    // Gather active shockwave data: (x, y, radius, force, source)
+    shockwaves.clear();
+    shockwaves.extend(world.markers.iter().filter_map(|(id, marker)| {
+        if marker.kind != EntityKind::Shockwave {
+            return None;
+        }
+        let t = world.transforms.get(id)?;
+        let sw = world.shockwaves.get(id)?;
+        Some((t.x, t.y, sw.radius, sw.force, sw.source))
+    }));
 
     // Gather all destructible entities: missiles and interceptors
-    // Store: (idx, x, y, kind)
-    let targets: Vec<(usize, f32, f32, EntityKind)> = world
-        .alive_entities()
-        .iter()
-        .filter_map(|&idx| {
-            let marker = world.markers[idx].as_ref()?;
-            if marker.kind != EntityKind::Missile && marker.kind != EntityKind::Interceptor {
-                return None;
-            }
-            let t = world.transforms[idx].as_ref()?;
-            Some((idx, t.x, t.y, marker.kind))
-        })
-        .collect();
+    // Store: (id, x, y, kind)
+    targets.clear();
+    targets.extend(world.markers.iter().filter_map(|(id, marker)| {
+        if marker.kind != EntityKind::Missile && marker.kind != EntityKind::Interceptor {
+            return None;
+        }
+        let t = world.transforms.get(id)?;
+        Some((id, t.x, t.y, marker.kind))
+    }));
 
     // Determine destroy vs deflect for each target
-    let mut to_destroy: Vec<(usize, f32, f32, EntityKind)> = Vec::new();
-    let mut to_deflect: Vec<(usize, f32, f32)> = Vec::new(); // (idx, push_x, push_y)
+    to_destroy.clear();
+    to_deflect.clear();
 
     let destroy_ratio = config::SHOCKWAVE_DESTROY_RATIO;
 
-    for &(_sw_idx, sw_x, sw_y, sw_radius, sw_force) in &shockwaves {
+    for &(sw_x, sw_y, sw_radius, sw_force, source) in shockwaves.iter() {
         if sw_radius <= 0.0 {
             continue;
         }
         let destroy_radius = sw_radius * destroy_ratio;
 
-        for &(tgt_idx, tgt_x, tgt_y, kind) in &targets {
+        for &(tgt_id, tgt_x, tgt_y, kind) in targets.iter() {
             let dx = tgt_x - sw_x;
             let dy = tgt_y - sw_y;
             let dist_sq = dx * dx + dy * dy;
@@ -73,7 +89,7 @@ pub fn run(world: &mut World, tick: u64) -> CollisionResult {
 
             if dist < destroy_radius {
                 // Inner destroy zone
-                to_destroy.push((tgt_idx, tgt_x, tgt_y, kind));
+                to_destroy.push((tgt_id, tgt_x, tgt_y, kind, source));
             } else if dist < sw_radius {
                 // Outer deflect zone — push away from shockwave center
                 let norm = dist.max(0.01); // prevent div by zero
@@ -82,36 +98,35 @@ pub fn run(world: &mut World, tick: u64) -> CollisionResult {
                 let force_scale = sw_force * (1.0 - dist / sw_radius)
                     * config::SHOCKWAVE_DEFLECT_FORCE
                     * config::DT;
-                to_deflect.push((tgt_idx, push_x * force_scale, push_y * force_scale));
+                to_deflect.push((tgt_id, push_x * force_scale, push_y * force_scale));
             }
         }
     }
 
     // Deduplicate destroys (entity in range of multiple shockwaves)
-    to_destroy.sort_by_key(|&(idx, _, _, _)| idx);
+    to_destroy.sort_by_key(|&(id, _, _, _, _)| id.index);
     to_destroy.dedup_by_key(|entry| entry.0);
 
     // Aggregate deflection pushes per entity (may be pushed by multiple shockwaves)
-    to_deflect.sort_by_key(|&(idx, _, _)| idx);
-    let mut aggregated_deflect: Vec<(usize, f32, f32)> = Vec::new();
-    for (idx, px, py) in to_deflect {
+    to_deflect.sort_by_key(|&(id, _, _)| id.index);
+    aggregated_deflect.clear();
+    for &(id, px, py) in to_deflect.iter() {
         if let Some(last) = aggregated_deflect.last_mut()
-            && last.0 == idx
+            && last.0 == id
         {
             last.1 += px;
             last.2 += py;
             continue;
         }
-        aggregated_deflect.push((idx, px, py));
+        aggregated_deflect.push((id, px, py));
     }
 
-    // Remove entities that are being destroyed from the deflect list
-    let destroy_set: Vec<usize> = to_destroy.iter().map(|d| d.0).collect();
-    aggregated_deflect.retain(|&(idx, _, _)| !destroy_set.contains(&idx));
+    // Remove entities that are being destroyed from the deflect list (to_destroy is sorted by index)
+    aggregated_deflect.retain(|&(id, _, _)| to_destroy.binary_search_by_key(&id.index, |d| d.0.index).is_err());
 
     // Apply deflections to entity velocities
-    for (tgt_idx, push_x, push_y) in aggregated_deflect {
-        if let Some(vel) = &mut world.velocities[tgt_idx] {
+    for &(tgt_id, push_x, push_y) in aggregated_deflect.iter() {
+        if let Some(vel) = world.velocities.get_mut(tgt_id) {
             vel.vx += push_x;
             vel.vy += push_y;
         }
@@ -120,44 +135,68 @@ pub fn run(world: &mut World, tick: u64) -> CollisionResult {
     // Destroy entities and spawn chain reaction shockwaves (missiles only)
     let chain_mult = config::CHAIN_REACTION_MULTIPLIER;
 
-    for (tgt_idx, tgt_x, tgt_y, kind) in to_destroy {
-        let warhead = world.warheads[tgt_idx];
+    for &(tgt_id, tgt_x, tgt_y, kind, source) in to_destroy.iter() {
+        let warhead = world.warheads.get(tgt_id).copied();
+        let carrier = world.mirv_carriers.get(tgt_id).copied();
 
         // Despawn the entity
-        if let Some(generation) = world.allocator.generation_of(tgt_idx as u32) {
-            let eid = EntityId::new(tgt_idx as u32, generation);
-            world.despawn(eid);
-        }
+        world.despawn(tgt_id);
 
         match kind {
             EntityKind::Missile => {
                 result.missiles_destroyed += 1;
+                result.kill_sources.extend(source);
+
+                // Killing a bus before it splits takes all its warheads with it
+                if let Some(c) = carrier {
+                    result.events.push(GameEvent::MirvBusKilled(MirvBusKilledEvent {
+                        carrier_id: tgt_id.index,
+                        carrier_generation: tgt_id.generation,
+                        x: tgt_x,
+                        y: tgt_y,
+                        children_prevented: c.child_count,
+                        tick,
+                    }));
+                }
 
                 // Chain reaction: missiles trigger new shockwaves
                 if let Some(wh) = warhead {
                     let sw_id = world.spawn();
-                    let sw_idx = sw_id.index as usize;
-                    world.transforms[sw_idx] = Some(Transform {
-                        x: tgt_x,
-                        y: tgt_y,
-                        rotation: 0.0,
-                    });
-                    world.shockwaves[sw_idx] = Some(Shockwave {
-                        radius: 0.0,
-                        max_radius: wh.blast_radius_base * chain_mult,
-                        force: wh.yield_force * chain_mult,
-                        expansion_rate: config::SHOCKWAVE_EXPANSION_RATE,
-                        damage_applied: false,
-                    });
-                    world.markers[sw_idx] = Some(EntityMarker {
-                        kind: EntityKind::Shockwave,
-                    });
-                    world.lifetimes[sw_idx] = Some(Lifetime {
-                        remaining_ticks: config::SHOCKWAVE_LIFETIME_TICKS,
-                    });
+                    world.insert(
+                        sw_id,
+                        Transform {
+                            x: tgt_x,
+                            y: tgt_y,
+                            rotation: 0.0,
+                        },
+                    );
+                    world.insert(
+                        sw_id,
+                        Shockwave {
+                            radius: 0.0,
+                            max_radius: wh.blast_radius_base * chain_mult,
+                            force: wh.yield_force * chain_mult,
+                            expansion_rate: config::SHOCKWAVE_EXPANSION_RATE,
+                            damage_applied: false,
+                            source,
+                        },
+                    );
+                    world.insert(
+                        sw_id,
+                        EntityMarker {
+                            kind: EntityKind::Shockwave,
+                        },
+                    );
+                    world.insert(
+                        sw_id,
+                        Lifetime {
+                            remaining_ticks: config::SHOCKWAVE_LIFETIME_TICKS,
+                        },
+                    );
 
                     result.events.push(GameEvent::Detonation(DetonationEvent {
-                        entity_id: tgt_idx as u32,
+                        entity_id: tgt_id.index,
+                        generation: tgt_id.generation,
                         x: tgt_x,
                         y: tgt_y,
                         yield_force: wh.yield_force,
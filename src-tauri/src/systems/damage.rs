@@ -10,50 +10,48 @@ pub fn run(world: &mut World, city_ids: &[EntityId], tick: u64) -> Vec<GameEvent
     let mut events = Vec::new();
 
     // Gather shockwaves that haven't applied damage yet and are near ground level
-    let ground_shockwaves: Vec<(usize, f32, f32, f32, f32)> = world
-        .alive_entities()
+    let ground_shockwaves: Vec<(EntityId, f32, f32, f32, f32)> = world
+        .markers
         .iter()
-        .filter_map(|&idx| {
-            let marker = world.markers[idx].as_ref()?;
+        .filter_map(|(id, marker)| {
             if marker.kind != EntityKind::Shockwave {
                 return None;
             }
-            let sw = world.shockwaves[idx].as_ref()?;
+            let sw = world.shockwaves.get(id)?;
             if sw.damage_applied {
                 return None;
             }
-            let t = world.transforms[idx].as_ref()?;
+            let t = world.transforms.get(id)?;
             // Only ground-level shockwaves can damage cities
             if t.y > config::GROUND_Y + config::GROUND_IMPACT_DAMAGE_RADIUS {
                 return None;
             }
-            Some((idx, t.x, t.y, sw.max_radius, sw.force))
+            Some((id, t.x, t.y, sw.max_radius, sw.force))
         })
         .collect();
+    if ground_shockwaves.is_empty() {
+        return events;
+    }
 
     // Gather city data
-    let cities: Vec<(usize, u32, f32)> = city_ids
+    let cities: Vec<(EntityId, u32, f32)> = city_ids
         .iter()
         .enumerate()
         .filter_map(|(city_idx, &eid)| {
-            if !world.is_alive(eid) {
-                return None;
-            }
-            let idx = eid.index as usize;
-            let t = world.transforms[idx].as_ref()?;
-            let h = world.healths[idx].as_ref()?;
+            let t = world.transforms.get(eid)?;
+            let h = world.healths.get(eid)?;
             if h.current <= 0.0 {
                 return None;
             }
-            Some((idx, city_idx as u32, t.x))
+            Some((eid, city_idx as u32, t.x))
         })
         .collect();
 
     // Check each ground shockwave against each city
-    for &(sw_idx, sw_x, sw_y, _max_radius, _force) in &ground_shockwaves {
+    for &(sw_id, sw_x, sw_y, _max_radius, _force) in &ground_shockwaves {
         let damage_radius = config::GROUND_IMPACT_DAMAGE_RADIUS;
 
-        for &(city_world_idx, city_id, city_x) in &cities {
+        for &(city_eid, city_id, city_x) in &cities {
             let dx = city_x - sw_x;
             let dy = config::GROUND_Y - sw_y;
             let dist = (dx * dx + dy * dy).sqrt();
@@ -63,7 +61,7 @@ pub fn run(world: &mut World, city_ids: &[EntityId], tick: u64) -> Vec<GameEvent
                 let falloff = 1.0 - (dist / damage_radius);
                 let damage = config::GROUND_IMPACT_BASE_DAMAGE * falloff;
 
-                if let Some(ref mut health) = world.healths[city_world_idx] {
+                if let Some(health) = world.healths.get_mut(city_eid) {
                     health.current = (health.current - damage).max(0.0);
                     events.push(GameEvent::CityDamaged(CityDamagedEvent {
                         city_id,
@@ -76,7 +74,7 @@ pub fn run(world: &mut World, city_ids: &[EntityId], tick: u64) -> Vec<GameEvent
         }
 
         // Mark damage as applied
-        if let Some(ref mut sw) = world.shockwaves[sw_idx] {
+        if let Some(sw) = world.shockwaves.get_mut(sw_id) {
             sw.damage_applied = true;
         }
     }
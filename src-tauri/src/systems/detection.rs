@@ -3,31 +3,39 @@ use crate::ecs::entity::EntityId;
 use crate::ecs::world::World;
 use crate::engine::config;
 use crate::state::weather::{self, WeatherState};
+use crate::systems::mirv_split;
+
+/// Working storage for detection, reused across ticks.
+#[derive(Debug, Default)]
+pub struct DetectionBuffers {
+    alive: Vec<EntityId>,
+    /// (x, y, squared range) of each battery's radar
+    battery_radars: Vec<(f32, f32, f32)>,
+}
 
 /// Detection system: determines which missiles are visible to the player.
 ///
-/// - **Radar**: missiles within RADAR_BASE_RANGE * weather_multiplier of any battery are radar-detected
+/// - **Radar**: missiles within RADAR_BASE_RANGE * weather_multiplier * terrain_multiplier of any battery are radar-detected
 /// - **Glow**: missiles with ReentryGlow below altitude_threshold in clear/overcast weather are glow-detected
 /// - Cities, batteries, interceptors, and shockwaves are always detected
-pub fn run(world: &mut World, battery_ids: &[EntityId], weather: &WeatherState) {
+/// - Detected MIRV buses get a split-altitude estimate; undetected ones lose it
+pub fn run(world: &mut World, battery_ids: &[EntityId], weather: &WeatherState, buffers: &mut DetectionBuffers) {
     let radar_range = config::RADAR_BASE_RANGE * weather::radar_multiplier(weather.condition);
-    let radar_range_sq = radar_range * radar_range;
     let glow_vis = weather::glow_visibility(weather.condition);
 
-    // Collect battery positions for distance checks
-    let battery_positions: Vec<(f32, f32)> = battery_ids
-        .iter()
-        .filter_map(|&bid| {
-            if world.is_alive(bid) {
-                world.transforms[bid.index as usize].map(|t| (t.x, t.y))
-            } else {
-                None
-            }
-        })
-        .collect();
+    let DetectionBuffers { alive, battery_radars } = buffers;
 
-    for idx in world.alive_entities() {
-        let marker = match &world.markers[idx] {
+    // Collect battery positions and terrain-adjusted squared ranges for distance checks
+    battery_radars.clear();
+    battery_radars.extend(battery_ids.iter().filter_map(|&bid| {
+        let terrain_mult = world.battery_states.get(bid).map_or(1.0, |b| b.radar_multiplier);
+        let range = radar_range * terrain_mult;
+        world.transforms.get(bid).map(|t| (t.x, t.y, range * range))
+    }));
+
+    world.alive_entities_into(alive);
+    for &id in alive.iter() {
+        let marker = match world.markers.get(id) {
             Some(m) => m,
             None => continue,
         };
@@ -35,34 +43,44 @@ pub fn run(world: &mut World, battery_ids: &[EntityId], weather: &WeatherState)
         match marker.kind {
             // Cities, batteries, interceptors, shockwaves always detected
             EntityKind::City | EntityKind::Battery | EntityKind::Interceptor | EntityKind::Shockwave => {
-                world.detected[idx] = Some(Detected {
-                    by_radar: true,
-                    by_glow: false,
-                });
+                world.insert(
+                    id,
+                    Detected {
+                        by_radar: true,
+                        by_glow: false,
+                    },
+                );
             }
             EntityKind::Missile => {
-                let transform = match &world.transforms[idx] {
-                    Some(t) => t,
+                let transform = match world.transforms.get(id) {
+                    Some(t) => *t,
                     None => continue,
                 };
 
                 // Radar check: distance to any battery within effective range
-                let by_radar = battery_positions.iter().any(|&(bx, by)| {
+                let by_radar = battery_radars.iter().any(|&(bx, by, range_sq)| {
                     let dx = transform.x - bx;
                     let dy = transform.y - by;
-                    dx * dx + dy * dy <= radar_range_sq
+                    dx * dx + dy * dy <= range_sq
                 });
 
                 // Glow check: has ReentryGlow, below altitude threshold, weather permits
                 let by_glow = glow_vis > 0.0
-                    && world.reentry_glows[idx]
-                        .as_ref()
+                    && world
+                        .reentry_glows
+                        .get(id)
                         .is_some_and(|g| transform.y < g.altitude_threshold);
 
                 if by_radar || by_glow {
-                    world.detected[idx] = Some(Detected { by_radar, by_glow });
+                    world.insert(id, Detected { by_radar, by_glow });
                 } else {
-                    world.detected[idx] = None;
+                    world.detected.remove(id);
+                }
+
+                if let Some(carrier) = world.mirv_carriers.get_mut(id) {
+                    carrier.estimated_split_altitude = (by_radar || by_glow).then(|| {
+                        mirv_split::estimate_split_altitude(transform.y, carrier.split_altitude)
+                    });
                 }
             }
         }
@@ -85,20 +103,26 @@ mod tests {
 
     fn spawn_battery(world: &mut World, x: f32, y: f32) -> EntityId {
         let id = world.spawn();
-        let idx = id.index as usize;
-        world.transforms[idx] = Some(Transform { x, y, rotation: 0.0 });
-        world.markers[idx] = Some(EntityMarker { kind: EntityKind::Battery });
-        world.battery_states[idx] = Some(BatteryState { ammo: 10, max_ammo: 10 });
+        world.insert(id, Transform { x, y, rotation: 0.0 });
+        world.insert(id, EntityMarker { kind: EntityKind::Battery });
+        world.insert(id, BatteryState {
+            ammo: 10,
+            max_ammo: 10,
+            reload_remaining: 0,
+            arc_min: config::BATTERY_ARC_MIN,
+            arc_max: config::BATTERY_ARC_MAX,
+            in_flight: 0,
+            radar_multiplier: 1.0,
+        });
         id
     }
 
     fn spawn_missile(world: &mut World, x: f32, y: f32) -> EntityId {
         let id = world.spawn();
-        let idx = id.index as usize;
-        world.transforms[idx] = Some(Transform { x, y, rotation: 0.0 });
-        world.velocities[idx] = Some(Velocity { vx: 0.0, vy: -50.0 });
-        world.markers[idx] = Some(EntityMarker { kind: EntityKind::Missile });
-        world.warheads[idx] = Some(Warhead {
+        world.insert(id, Transform { x, y, rotation: 0.0 });
+        world.insert(id, Velocity { vx: 0.0, vy: -50.0 });
+        world.insert(id, EntityMarker { kind: EntityKind::Missile });
+        world.insert(id, Warhead {
             yield_force: 100.0,
             blast_radius_base: 40.0,
             warhead_type: WarheadType::Standard,
@@ -108,8 +132,7 @@ mod tests {
 
     fn spawn_missile_with_glow(world: &mut World, x: f32, y: f32, altitude_threshold: f32) -> EntityId {
         let id = spawn_missile(world, x, y);
-        let idx = id.index as usize;
-        world.reentry_glows[idx] = Some(ReentryGlow {
+        world.insert(id, ReentryGlow {
             intensity: 1.0,
             altitude_threshold,
         });
@@ -123,9 +146,9 @@ mod tests {
         // Missile at 300 units from battery (within 500 base range)
         let missile = spawn_missile(&mut world, 460.0, 50.0);
 
-        run(&mut world, &[bat], &clear_weather());
+        run(&mut world, &[bat], &clear_weather(), &mut DetectionBuffers::default());
 
-        let det = world.detected[missile.index as usize].as_ref().unwrap();
+        let det = world.detected.get(missile).unwrap();
         assert!(det.by_radar);
     }
 
@@ -136,9 +159,9 @@ mod tests {
         // Missile at 600 units from battery (beyond 500 base range)
         let missile = spawn_missile(&mut world, 760.0, 50.0);
 
-        run(&mut world, &[bat], &clear_weather());
+        run(&mut world, &[bat], &clear_weather(), &mut DetectionBuffers::default());
 
-        assert!(world.detected[missile.index as usize].is_none());
+        assert!(world.detected.get(missile).is_none());
     }
 
     #[test]
@@ -148,9 +171,9 @@ mod tests {
         // Missile far from battery but with glow below threshold
         let missile = spawn_missile_with_glow(&mut world, 900.0, 200.0, 300.0);
 
-        run(&mut world, &[bat], &clear_weather());
+        run(&mut world, &[bat], &clear_weather(), &mut DetectionBuffers::default());
 
-        let det = world.detected[missile.index as usize].as_ref().unwrap();
+        let det = world.detected.get(missile).unwrap();
         assert!(!det.by_radar); // too far for radar
         assert!(det.by_glow);
     }
@@ -162,9 +185,9 @@ mod tests {
         // Missile far from battery, above glow threshold
         let missile = spawn_missile_with_glow(&mut world, 900.0, 400.0, 300.0);
 
-        run(&mut world, &[bat], &clear_weather());
+        run(&mut world, &[bat], &clear_weather(), &mut DetectionBuffers::default());
 
-        assert!(world.detected[missile.index as usize].is_none());
+        assert!(world.detected.get(missile).is_none());
     }
 
     #[test]
@@ -179,9 +202,9 @@ mod tests {
             wind_x: 10.0,
             wind_y: 0.0,
         };
-        run(&mut world, &[bat], &storm);
+        run(&mut world, &[bat], &storm, &mut DetectionBuffers::default());
 
-        assert!(world.detected[missile.index as usize].is_none());
+        assert!(world.detected.get(missile).is_none());
     }
 
     #[test]
@@ -196,9 +219,9 @@ mod tests {
             wind_x: 20.0,
             wind_y: 0.0,
         };
-        run(&mut world, &[bat], &severe);
+        run(&mut world, &[bat], &severe, &mut DetectionBuffers::default());
 
-        assert!(world.detected[missile.index as usize].is_none());
+        assert!(world.detected.get(missile).is_none());
     }
 
     #[test]
@@ -206,14 +229,13 @@ mod tests {
         let mut world = World::new();
         let bat = spawn_battery(&mut world, 160.0, 50.0);
         let city = world.spawn();
-        let idx = city.index as usize;
-        world.transforms[idx] = Some(Transform { x: 640.0, y: 50.0, rotation: 0.0 });
-        world.markers[idx] = Some(EntityMarker { kind: EntityKind::City });
-        world.healths[idx] = Some(Health { current: 100.0, max: 100.0 });
+        world.insert(city, Transform { x: 640.0, y: 50.0, rotation: 0.0 });
+        world.insert(city, EntityMarker { kind: EntityKind::City });
+        world.insert(city, Health { current: 100.0, max: 100.0 });
 
-        run(&mut world, &[bat], &clear_weather());
+        run(&mut world, &[bat], &clear_weather(), &mut DetectionBuffers::default());
 
-        assert!(world.detected[idx].is_some());
+        assert!(world.detected.contains(city));
     }
 
     #[test]
@@ -221,14 +243,13 @@ mod tests {
         let mut world = World::new();
         let bat = spawn_battery(&mut world, 160.0, 50.0);
         let interceptor = world.spawn();
-        let idx = interceptor.index as usize;
-        world.transforms[idx] = Some(Transform { x: 800.0, y: 400.0, rotation: 0.0 });
-        world.markers[idx] = Some(EntityMarker { kind: EntityKind::Interceptor });
-        world.velocities[idx] = Some(Velocity { vx: 0.0, vy: 100.0 });
+        world.insert(interceptor, Transform { x: 800.0, y: 400.0, rotation: 0.0 });
+        world.insert(interceptor, EntityMarker { kind: EntityKind::Interceptor });
+        world.insert(interceptor, Velocity { vx: 0.0, vy: 100.0 });
 
-        run(&mut world, &[bat], &clear_weather());
+        run(&mut world, &[bat], &clear_weather(), &mut DetectionBuffers::default());
 
-        assert!(world.detected[idx].is_some());
+        assert!(world.detected.contains(interceptor));
     }
 
     #[test]
@@ -239,9 +260,9 @@ mod tests {
         // Missile near bat2 but far from bat1
         let missile = spawn_missile(&mut world, 900.0, 50.0);
 
-        run(&mut world, &[bat1, bat2], &clear_weather());
+        run(&mut world, &[bat1, bat2], &clear_weather(), &mut DetectionBuffers::default());
 
-        let det = world.detected[missile.index as usize].as_ref().unwrap();
+        let det = world.detected.get(missile).unwrap();
         assert!(det.by_radar);
     }
 
@@ -252,8 +273,8 @@ mod tests {
         // Missile very far from battery, no glow
         let missile = spawn_missile(&mut world, 1200.0, 600.0);
 
-        run(&mut world, &[bat], &clear_weather());
+        run(&mut world, &[bat], &clear_weather(), &mut DetectionBuffers::default());
 
-        assert!(world.detected[missile.index as usize].is_none());
+        assert!(world.detected.get(missile).is_none());
     }
 }
@@ -13,8 +13,8 @@ fn air_density(altitude: f32) -> f32 {
 /// Drag force: F = 0.5 * rho * v^2 * Cd * A
 /// Drag acceleration: a = F / m = 0.5 * rho * v^2 * Cd * A / m
 pub fn run(world: &mut World) {
-    for idx in world.alive_entities() {
-        let dominated_by_drag = match &world.markers[idx] {
+    for (id, vel) in world.velocities.iter_mut() {
+        let dominated_by_drag = match world.markers.get(id) {
             Some(m) => matches!(m.kind, EntityKind::Missile | EntityKind::Interceptor),
             None => false,
         };
@@ -23,30 +23,28 @@ pub fn run(world: &mut World) {
             continue;
         }
 
-        let (cd, mass, cross_section) = match world.ballistics[idx] {
+        let (cd, mass, cross_section) = match world.ballistics.get(id) {
             Some(b) => (b.drag_coefficient, b.mass, b.cross_section),
             None => continue,
         };
 
-        let altitude = match world.transforms[idx] {
+        let altitude = match world.transforms.get(id) {
             Some(t) => t.y,
             None => continue,
         };
 
-        if let Some(ref mut vel) = world.velocities[idx] {
-            let speed_sq = vel.vx * vel.vx + vel.vy * vel.vy;
-            let speed = speed_sq.sqrt();
-            if speed < 1e-6 {
-                continue;
-            }
+        let speed_sq = vel.vx * vel.vx + vel.vy * vel.vy;
+        let speed = speed_sq.sqrt();
+        if speed < 1e-6 {
+            continue;
+        }
 
-            let rho = air_density(altitude);
-            let drag_accel = 0.5 * rho * speed_sq * cd * cross_section / mass;
-            let drag_factor = (drag_accel * config::DT / speed).min(0.99);
+        let rho = air_density(altitude);
+        let drag_accel = 0.5 * rho * speed_sq * cd * cross_section / mass;
+        let drag_factor = (drag_accel * config::DT / speed).min(0.99);
 
-            vel.vx -= vel.vx * drag_factor;
-            vel.vy -= vel.vy * drag_factor;
-        }
+        vel.vx -= vel.vx * drag_factor;
+        vel.vy -= vel.vy * drag_factor;
     }
 }
 
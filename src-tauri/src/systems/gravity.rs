@@ -5,10 +5,10 @@ use crate::engine::config;
 /// Apply gravitational acceleration to all ballistic entities.
 /// In our coordinate system, positive Y is up, so gravity subtracts from vy.
 pub fn run(world: &mut World) {
-    for idx in world.alive_entities() {
+    for (id, vel) in world.velocities.iter_mut() {
         // Only apply gravity to entities with velocity and ballistic components
         // Skip shockwaves and static entities (cities, batteries)
-        let dominated_by_gravity = match &world.markers[idx] {
+        let dominated_by_gravity = match world.markers.get(id) {
             Some(m) => matches!(m.kind, EntityKind::Missile | EntityKind::Interceptor),
             None => false,
         };
@@ -17,9 +17,7 @@ pub fn run(world: &mut World) {
             continue;
         }
 
-        if let Some(ref mut vel) = world.velocities[idx]
-            && world.ballistics[idx].is_some()
-        {
+        if world.ballistics.contains(id) {
             vel.vy -= config::GRAVITY * config::DT;
         }
     }
@@ -1,9 +1,17 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::campaign::territory::TheaterBounds;
 use crate::campaign::upgrades::TechTree;
 use crate::ecs::components::*;
 use crate::ecs::entity::EntityId;
 use crate::ecs::world::World;
+use crate::engine::config;
+use crate::events::game_events::{GameEvent, SalvoShortfallEvent};
+use crate::systems::arc_prediction::MissileTrack;
+use crate::systems::launch_solution;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub enum PlayerCommand {
     LaunchInterceptor {
         battery_id: u32,
@@ -11,16 +19,70 @@ pub enum PlayerCommand {
         target_y: f32,
         interceptor_type: InterceptorType,
     },
+    /// Fire `count` interceptors at one point, or at the missile nearest it,
+    /// from the best-placed batteries. Shots are assigned once and staggered.
+    SalvoIntercept {
+        target_x: f32,
+        target_y: f32,
+        count: u32,
+        interceptor_type: InterceptorType,
+    },
+    /// Freeze the active wave; handled by the game loop, never queued
+    Pause,
+    Resume,
+}
+
+/// One interceptor launch with its solution already computed.
+#[derive(Debug, Clone)]
+pub struct LaunchOrder {
+    pub battery_id: u32,
+    pub interceptor_type: InterceptorType,
+    pub target_x: f32,
+    pub target_y: f32,
+    pub aim_x: f32,
+    pub aim_y: f32,
+}
+
+/// A salvo shot assigned to a battery, waiting for its launch tick.
+#[derive(Debug, Clone)]
+pub struct PendingLaunch {
+    pub order: LaunchOrder,
+    /// Ticks until the shot may fire; it then waits for reload and in-flight limits
+    pub ready_in: u32,
+}
+
+/// Shared inputs for planning and launching interceptors.
+pub struct LaunchContext<'a> {
+    pub battery_ids: &'a [EntityId],
+    pub tech_tree: &'a TechTree,
+    pub wind_x: f32,
+    /// Predicted paths end where interceptors would leave the theater
+    pub theater: &'a TheaterBounds,
+}
+
+#[derive(Debug, Default)]
+pub struct InputResult {
+    /// Type of each interceptor successfully launched this tick
+    pub launched: Vec<InterceptorType>,
+    pub events: Vec<GameEvent>,
 }
 
 /// Process queued player commands: spawn interceptors from batteries.
-/// Uses tech_tree.effective_profile() for physics values so upgrades apply.
-/// Returns the number of interceptors successfully launched this tick.
-pub fn run(world: &mut World, commands: &mut Vec<PlayerCommand>, battery_ids: &[EntityId], tech_tree: &TechTree) -> u32 {
-    let cmds: Vec<PlayerCommand> = std::mem::take(commands);
-    let mut launched = 0u32;
+/// Uses tech_tree.effective_profile() for physics values so upgrades apply,
+/// and a launch solution against the current wind to set the aim point.
+/// Salvo shots are queued in `pending` and fire as their batteries come ready.
+pub fn run(
+    world: &mut World,
+    commands: &mut Vec<PlayerCommand>,
+    pending: &mut Vec<PendingLaunch>,
+    ctx: &LaunchContext,
+    tick: u64,
+) -> InputResult {
+    let mut result = InputResult::default();
+
+    refresh_battery_status(world, ctx.battery_ids);
 
-    for cmd in cmds {
+    for cmd in std::mem::take(commands) {
         match cmd {
             PlayerCommand::LaunchInterceptor {
                 battery_id,
@@ -28,91 +90,372 @@ pub fn run(world: &mut World, commands: &mut Vec<PlayerCommand>, battery_ids: &[
                 target_y,
                 interceptor_type,
             } => {
-                let Some(&bat_eid) = battery_ids.get(battery_id as usize) else {
+                let Some(order) = aim_direct(world, ctx, battery_id, interceptor_type, target_x, target_y) else {
                     continue;
                 };
-                if !world.is_alive(bat_eid) {
-                    continue;
+                if launch_from(world, ctx, &order) {
+                    result.launched.push(interceptor_type);
                 }
-                let bat_idx = bat_eid.index as usize;
-
-                // Check ammo
-                let has_ammo = world.battery_states[bat_idx]
-                    .as_ref()
-                    .is_some_and(|b| b.ammo > 0);
-                if !has_ammo {
+            }
+            PlayerCommand::SalvoIntercept {
+                target_x,
+                target_y,
+                count,
+                interceptor_type,
+            } => {
+                if count == 0 {
                     continue;
                 }
+                let planned = plan_salvo(world, ctx, interceptor_type, target_x, target_y, count);
+                let assigned = planned.len() as u32;
+                if assigned < count {
+                    result.events.push(GameEvent::SalvoShortfall(SalvoShortfallEvent {
+                        requested: count,
+                        assigned,
+                        tick,
+                    }));
+                }
+                pending.extend(planned);
+            }
+            PlayerCommand::Pause | PlayerCommand::Resume => {}
+        }
+    }
 
-                // Decrement ammo
-                if let Some(ref mut bs) = world.battery_states[bat_idx] {
-                    bs.ammo -= 1;
+    // Fire salvo shots whose batteries are ready; the rest keep waiting
+    let mut waiting = Vec::with_capacity(pending.len());
+    for mut shot in std::mem::take(pending) {
+        if shot.ready_in > 0 {
+            shot.ready_in -= 1;
+            waiting.push(shot);
+            continue;
+        }
+        match battery_readiness(world, ctx, &shot.order) {
+            Readiness::Ready => {
+                if launch_from(world, ctx, &shot.order) {
+                    result.launched.push(shot.order.interceptor_type);
                 }
+            }
+            Readiness::Busy => waiting.push(shot),
+            Readiness::Unavailable => {
+                result.events.push(GameEvent::SalvoShortfall(SalvoShortfallEvent {
+                    requested: 1,
+                    assigned: 0,
+                    tick,
+                }));
+            }
+        }
+    }
+    *pending = waiting;
 
-                // Get battery position
-                let bat_pos = match world.transforms[bat_idx] {
-                    Some(t) => t,
-                    None => continue,
-                };
+    result
+}
+
+/// Solve a launch from `battery_id` straight at a fixed point.
+fn aim_direct(
+    world: &World,
+    ctx: &LaunchContext,
+    battery_id: u32,
+    interceptor_type: InterceptorType,
+    target_x: f32,
+    target_y: f32,
+) -> Option<LaunchOrder> {
+    let bat_pos = *world.transforms.get(*ctx.battery_ids.get(battery_id as usize)?)?;
+    let profile = ctx.tech_tree.effective_profile(interceptor_type);
+
+    // Offset the aim point to compensate for gravity drop and wind
+    let solution = launch_solution::solve(
+        bat_pos.x,
+        bat_pos.y,
+        target_x,
+        target_y,
+        &profile,
+        ctx.wind_x,
+        ctx.theater,
+    );
+    Some(LaunchOrder {
+        battery_id,
+        interceptor_type,
+        target_x,
+        target_y,
+        aim_x: solution.aim_x,
+        aim_y: solution.aim_y,
+    })
+}
+
+/// The live missile nearest (x, y) within the salvo pick radius, if any.
+fn threat_near(world: &World, x: f32, y: f32) -> Option<MissileTrack> {
+    let mut best: Option<(f32, MissileTrack)> = None;
+    for (id, marker) in world.markers.iter() {
+        if marker.kind != EntityKind::Missile {
+            continue;
+        }
+        let (Some(t), Some(v), Some(b)) = (world.transforms.get(id), world.velocities.get(id), world.ballistics.get(id))
+        else {
+            continue;
+        };
+        let dist = (t.x - x).hypot(t.y - y);
+        if dist <= config::SALVO_THREAT_PICK_RADIUS && best.as_ref().is_none_or(|(d, _)| dist < *d) {
+            best = Some((
+                dist,
+                MissileTrack {
+                    x: t.x,
+                    y: t.y,
+                    vx: v.vx,
+                    vy: v.vy,
+                    ballistic: *b,
+                },
+            ));
+        }
+    }
+    best.map(|(_, track)| track)
+}
+
+/// Assign a salvo once: up to `count` distinct batteries with ammo that can
+/// reach the target inside their arc, best launch solution first, then shortest
+/// range. A click near a missile leads it, allowing for each battery's reload
+/// and the stagger between shots. The returned shots keep the solutions found here.
+fn plan_salvo(
+    world: &World,
+    ctx: &LaunchContext,
+    interceptor_type: InterceptorType,
+    target_x: f32,
+    target_y: f32,
+    count: u32,
+) -> Vec<PendingLaunch> {
+    let profile = ctx.tech_tree.effective_profile(interceptor_type);
+    let threat = threat_near(world, target_x, target_y);
 
-                // Look up physics profile (with upgrades applied)
-                let profile = tech_tree.effective_profile(interceptor_type);
-
-                // Calculate initial direction toward target
-                let dx = target_x - bat_pos.x;
-                let dy = target_y - bat_pos.y;
-                let dist = (dx * dx + dy * dy).sqrt().max(1.0);
-                let dir_x = dx / dist;
-                let dir_y = dy / dist;
-
-                // Spawn interceptor entity
-                let id = world.spawn();
-                let idx = id.index as usize;
-
-                world.transforms[idx] = Some(Transform {
-                    x: bat_pos.x,
-                    y: bat_pos.y,
-                    rotation: dir_y.atan2(dir_x),
-                });
-
-                // Small initial velocity in target direction
-                world.velocities[idx] = Some(Velocity {
-                    vx: dir_x * 10.0,
-                    vy: dir_y * 10.0,
-                });
-
-                world.interceptors[idx] = Some(Interceptor {
-                    interceptor_type,
-                    thrust: profile.thrust,
-                    burn_time: profile.burn_time,
-                    burn_remaining: profile.burn_time,
-                    ceiling: profile.ceiling,
-                    battery_id,
+    // Solve one battery's shot, fired `ready_in` ticks from now
+    let solve_for = |bat_x: f32, bat_y: f32, ready_in: u32| -> Option<launch_solution::LeadSolution> {
+        match &threat {
+            Some(track) => {
+                let delay = ready_in as f32 * config::DT;
+                launch_solution::solve_lead(bat_x, bat_y, track, delay, &profile, ctx.wind_x, ctx.theater)
+            }
+            None => Some(launch_solution::LeadSolution {
+                target_x,
+                target_y,
+                solution: launch_solution::solve(
+                    bat_x,
+                    bat_y,
                     target_x,
                     target_y,
-                    proximity_fuse_radius: profile.proximity_fuse_radius,
-                });
-
-                world.ballistics[idx] = Some(Ballistic {
-                    drag_coefficient: profile.drag_coeff,
-                    mass: profile.mass,
-                    cross_section: profile.cross_section,
-                });
-
-                world.warheads[idx] = Some(Warhead {
-                    yield_force: profile.yield_force,
-                    blast_radius_base: profile.blast_radius,
-                    warhead_type: WarheadType::Standard,
-                });
-
-                world.markers[idx] = Some(EntityMarker {
-                    kind: EntityKind::Interceptor,
-                });
-
-                launched += 1;
+                    &profile,
+                    ctx.wind_x,
+                    ctx.theater,
+                ),
+            }),
+        }
+    };
+
+    let mut candidates = Vec::new();
+    for (i, &bat_eid) in ctx.battery_ids.iter().enumerate() {
+        let (Some(bat_pos), Some(bs)) = (world.transforms.get(bat_eid), world.battery_states.get(bat_eid)) else {
+            continue;
+        };
+        if bs.ammo == 0 {
+            continue;
+        }
+        let Some(lead) = solve_for(bat_pos.x, bat_pos.y, bs.reload_remaining) else {
+            continue;
+        };
+        let angle = (lead.target_y - bat_pos.y).atan2(lead.target_x - bat_pos.x);
+        if !lead.solution.converged || !in_arc(bs, angle) {
+            continue;
+        }
+        let range = (lead.target_x - bat_pos.x).hypot(lead.target_y - bat_pos.y);
+        candidates.push((i as u32, bs.reload_remaining, bat_pos, lead, range));
+    }
+    candidates.sort_by(|a, b| {
+        a.3.solution
+            .miss_distance
+            .total_cmp(&b.3.solution.miss_distance)
+            .then(a.4.total_cmp(&b.4))
+    });
+    candidates.truncate(count as usize);
+
+    let mut shots = Vec::with_capacity(candidates.len());
+    for (slot, (battery_id, reload, bat_pos, mut lead, _)) in candidates.into_iter().enumerate() {
+        let ready_in = reload.max(slot as u32 * config::SALVO_STAGGER_TICKS);
+        // A staggered shot fires later than it was solved for, so re-lead the moving missile
+        if ready_in > reload && threat.is_some() {
+            match solve_for(bat_pos.x, bat_pos.y, ready_in) {
+                Some(later) if later.solution.converged => lead = later,
+                _ => continue,
             }
         }
+        shots.push(PendingLaunch {
+            order: LaunchOrder {
+                battery_id,
+                interceptor_type,
+                target_x: lead.target_x,
+                target_y: lead.target_y,
+                aim_x: lead.solution.aim_x,
+                aim_y: lead.solution.aim_y,
+            },
+            ready_in,
+        });
     }
+    shots
+}
+
+enum Readiness {
+    Ready,
+    /// Reloading or at its in-flight limit: try again next tick
+    Busy,
+    /// Destroyed, out of ammo, or the target is outside its arc
+    Unavailable,
+}
+
+fn battery_readiness(world: &World, ctx: &LaunchContext, order: &LaunchOrder) -> Readiness {
+    let Some(&bat_eid) = ctx.battery_ids.get(order.battery_id as usize) else {
+        return Readiness::Unavailable;
+    };
+    let (Some(bat_pos), Some(bs)) = (world.transforms.get(bat_eid), world.battery_states.get(bat_eid)) else {
+        return Readiness::Unavailable;
+    };
+    let angle = (order.target_y - bat_pos.y).atan2(order.target_x - bat_pos.x);
+    if bs.ammo == 0 || !in_arc(bs, angle) {
+        Readiness::Unavailable
+    } else if can_launch(bs, angle) {
+        Readiness::Ready
+    } else {
+        Readiness::Busy
+    }
+}
 
-    launched
+/// Launch one interceptor as ordered.
+/// Returns false if the battery is missing or cannot fire.
+fn launch_from(world: &mut World, ctx: &LaunchContext, order: &LaunchOrder) -> bool {
+    let Some(&bat_eid) = ctx.battery_ids.get(order.battery_id as usize) else {
+        return false;
+    };
+
+    // Get battery position
+    let bat_pos = match world.transforms.get(bat_eid) {
+        Some(t) => *t,
+        None => return false,
+    };
+
+    // Check ammo, reload, in-flight limit and firing arc
+    let target_angle = (order.target_y - bat_pos.y).atan2(order.target_x - bat_pos.x);
+    let can_fire = world
+        .battery_states
+        .get(bat_eid)
+        .is_some_and(|b| can_launch(b, target_angle));
+    if !can_fire {
+        return false;
+    }
+
+    // Decrement ammo and start the reload cycle
+    if let Some(bs) = world.battery_states.get_mut(bat_eid) {
+        bs.ammo -= 1;
+        bs.reload_remaining = config::BATTERY_RELOAD_TICKS;
+        bs.in_flight += 1;
+    }
+
+    // Look up physics profile (with upgrades applied)
+    let profile = ctx.tech_tree.effective_profile(order.interceptor_type);
+
+    // Calculate initial direction toward aim point
+    let dx = order.aim_x - bat_pos.x;
+    let dy = order.aim_y - bat_pos.y;
+    let dist = (dx * dx + dy * dy).sqrt().max(1.0);
+    let dir_x = dx / dist;
+    let dir_y = dy / dist;
+
+    // Spawn interceptor entity
+    let id = world.spawn();
+
+    world.insert(
+        id,
+        Transform {
+            x: bat_pos.x,
+            y: bat_pos.y,
+            rotation: dir_y.atan2(dir_x),
+        },
+    );
+
+    // Small initial velocity in target direction
+    world.insert(
+        id,
+        Velocity {
+            vx: dir_x * 10.0,
+            vy: dir_y * 10.0,
+        },
+    );
+
+    world.insert(
+        id,
+        Interceptor {
+            interceptor_type: order.interceptor_type,
+            thrust: profile.thrust,
+            burn_time: profile.burn_time,
+            burn_remaining: profile.burn_time,
+            ceiling: profile.ceiling,
+            battery_id: order.battery_id,
+            target_x: order.target_x,
+            target_y: order.target_y,
+            aim_x: order.aim_x,
+            aim_y: order.aim_y,
+            proximity_fuse_radius: profile.proximity_fuse_radius,
+        },
+    );
+
+    world.insert(
+        id,
+        Ballistic {
+            drag_coefficient: profile.drag_coeff,
+            mass: profile.mass,
+            cross_section: profile.cross_section,
+        },
+    );
+
+    world.insert(
+        id,
+        Warhead {
+            yield_force: profile.yield_force,
+            blast_radius_base: profile.blast_radius,
+            warhead_type: WarheadType::Standard,
+        },
+    );
+
+    world.insert(
+        id,
+        EntityMarker {
+            kind: EntityKind::Interceptor,
+        },
+    );
+
+    true
+}
+
+/// Whether a battery may launch toward a target at `target_angle` (radians from +x).
+pub fn can_launch(battery: &BatteryState, target_angle: f32) -> bool {
+    battery.ammo > 0
+        && battery.reload_remaining == 0
+        && battery.in_flight < config::BATTERY_MAX_IN_FLIGHT
+        && in_arc(battery, target_angle)
+}
+
+fn in_arc(battery: &BatteryState, target_angle: f32) -> bool {
+    target_angle >= battery.arc_min && target_angle <= battery.arc_max
+}
+
+/// Tick down reload cooldowns and recount each battery's interceptors in flight.
+fn refresh_battery_status(world: &mut World, battery_ids: &[EntityId]) {
+    for &bat_eid in battery_ids {
+        if let Some(bs) = world.battery_states.get_mut(bat_eid) {
+            bs.reload_remaining = bs.reload_remaining.saturating_sub(1);
+            bs.in_flight = 0;
+        }
+    }
+
+    let World { interceptors, battery_states, .. } = world;
+    for (_, icpt) in interceptors.iter() {
+        let battery = battery_ids.get(icpt.battery_id as usize);
+        if let Some(bs) = battery.and_then(|&bat_eid| battery_states.get_mut(bat_eid)) {
+            bs.in_flight += 1;
+        }
+    }
 }
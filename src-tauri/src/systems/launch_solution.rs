@@ -0,0 +1,208 @@
+use serde::{Deserialize, Serialize};
+
+use crate::campaign::territory::TheaterBounds;
+use crate::engine::config::{self, InterceptorProfile};
+use crate::systems::arc_prediction::{self, ArcPrediction, MissileTrack, ShotGeometry};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LaunchSolution {
+    pub aim_x: f32,
+    pub aim_y: f32,
+    /// Closest predicted approach to the target point with this aim.
+    pub miss_distance: f32,
+    pub converged: bool,
+    /// Predicted flight time to the target's closest approach with this aim.
+    pub time_to_target: f32,
+}
+
+/// A launch solution against a moving missile, aimed where it will be.
+#[derive(Debug, Clone)]
+pub struct LeadSolution {
+    pub target_x: f32,
+    pub target_y: f32,
+    pub solution: LaunchSolution,
+}
+
+/// Compute where an interceptor should steer so that gravity drop and wind
+/// carry it onto (target_x, target_y). Pure physics computation — no ECS access.
+///
+/// Iteratively shifts the aim point by the predicted miss vector using
+/// arc_prediction, keeping the best aim found if the target is out of reach.
+pub fn solve(
+    start_x: f32,
+    start_y: f32,
+    target_x: f32,
+    target_y: f32,
+    profile: &InterceptorProfile,
+    wind_x: f32,
+    theater: &TheaterBounds,
+) -> LaunchSolution {
+    let shot = ShotGeometry::direct(start_x, start_y, target_x, target_y);
+    let mut aim_x = target_x;
+    let mut aim_y = target_y;
+    let mut best = LaunchSolution {
+        aim_x,
+        aim_y,
+        miss_distance: f32::MAX,
+        converged: false,
+        time_to_target: 0.0,
+    };
+
+    for _ in 0..config::LAUNCH_SOLUTION_MAX_ITERATIONS {
+        let pred = arc_prediction::predict_arc_with_aim(&shot.with_aim(aim_x, aim_y), profile, wind_x, theater);
+        let (miss_x, miss_y) = closest_approach(&pred, target_x, target_y);
+        let miss_distance = (miss_x * miss_x + miss_y * miss_y).sqrt();
+
+        if miss_distance < best.miss_distance {
+            best = LaunchSolution {
+                aim_x,
+                aim_y,
+                miss_distance,
+                converged: false,
+                time_to_target: pred.time_to_target,
+            };
+        }
+        if miss_distance <= config::LAUNCH_SOLUTION_TOLERANCE {
+            best.converged = true;
+            break;
+        }
+
+        aim_x += miss_x;
+        aim_y += miss_y;
+    }
+
+    best
+}
+
+/// Solve for the point where an interceptor launched `delay` seconds from now
+/// meets `track`. Each pass re-predicts the missile's position at launch delay
+/// plus the latest solution's flight time. Returns None if the missile lands
+/// before any interception.
+pub fn solve_lead(
+    start_x: f32,
+    start_y: f32,
+    track: &MissileTrack,
+    delay: f32,
+    profile: &InterceptorProfile,
+    wind_x: f32,
+    theater: &TheaterBounds,
+) -> Option<LeadSolution> {
+    let mut time = delay;
+    let mut lead = None;
+    for _ in 0..config::LAUNCH_LEAD_ITERATIONS {
+        let (target_x, target_y) = arc_prediction::predict_missile_position(track, wind_x, theater, time)?;
+        let solution = solve(start_x, start_y, target_x, target_y, profile, wind_x, theater);
+        time = delay + solution.time_to_target;
+        lead = Some(LeadSolution {
+            target_x,
+            target_y,
+            solution,
+        });
+    }
+    lead
+}
+
+/// Predict the arc an interceptor will fly once the launch solution is applied.
+pub fn predict_corrected_arc(
+    start_x: f32,
+    start_y: f32,
+    target_x: f32,
+    target_y: f32,
+    profile: &InterceptorProfile,
+    wind_x: f32,
+    theater: &TheaterBounds,
+) -> ArcPrediction {
+    let solution = solve(start_x, start_y, target_x, target_y, profile, wind_x, theater);
+    let shot = ShotGeometry::direct(start_x, start_y, target_x, target_y).with_aim(solution.aim_x, solution.aim_y);
+    arc_prediction::predict_arc_with_aim(&shot, profile, wind_x, theater)
+}
+
+/// Vector from the predicted path's closest point to the target.
+fn closest_approach(pred: &ArcPrediction, target_x: f32, target_y: f32) -> (f32, f32) {
+    pred.points
+        .iter()
+        .map(|&(px, py)| (target_x - px, target_y - py))
+        .min_by(|a, b| {
+            let da = a.0 * a.0 + a.1 * a.1;
+            let db = b.0 * b.0 + b.1 * b.1;
+            da.total_cmp(&db)
+        })
+        .unwrap_or((0.0, 0.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::components::InterceptorType;
+
+    fn standard_profile() -> InterceptorProfile {
+        config::interceptor_profile(InterceptorType::Standard)
+    }
+
+    fn theater() -> TheaterBounds {
+        TheaterBounds::default()
+    }
+
+    #[test]
+    fn no_wind_short_shot_keeps_aim_near_target() {
+        let sol = solve(160.0, config::GROUND_Y, 160.0, 400.0, &standard_profile(), 0.0, &theater());
+        assert!(sol.converged);
+        assert!((sol.aim_x - 160.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn wind_shifts_aim_upwind() {
+        // Positive wind pushes rightward, so the solution should aim left of the target
+        let sol = solve(160.0, config::GROUND_Y, 900.0, 600.0, &standard_profile(), 30.0, &theater());
+        assert!(sol.converged, "miss distance {}", sol.miss_distance);
+        assert!(sol.aim_x < 900.0, "aim_x {} should be upwind of target", sol.aim_x);
+    }
+
+    #[test]
+    fn corrected_arc_passes_closer_than_raw_arc_in_wind() {
+        let raw = arc_prediction::predict_arc(160.0, config::GROUND_Y, 900.0, 600.0, &standard_profile(), 30.0, &theater());
+        let corrected = predict_corrected_arc(160.0, config::GROUND_Y, 900.0, 600.0, &standard_profile(), 30.0, &theater());
+        let (rx, ry) = closest_approach(&raw, 900.0, 600.0);
+        let (cx, cy) = closest_approach(&corrected, 900.0, 600.0);
+        assert!(
+            cx * cx + cy * cy < config::LAUNCH_SOLUTION_TOLERANCE * config::LAUNCH_SOLUTION_TOLERANCE,
+            "Corrected arc should pass within the detonation radius"
+        );
+        assert!(
+            cx * cx + cy * cy < rx * rx + ry * ry,
+            "Corrected arc should pass closer to the target than the raw arc"
+        );
+    }
+
+    #[test]
+    fn long_shot_compensates_gravity_drop() {
+        let sol = solve(160.0, config::GROUND_Y, 1000.0, 300.0, &standard_profile(), 0.0, &theater());
+        assert!(sol.aim_y > 300.0, "aim_y {} should be above a long-range target", sol.aim_y);
+    }
+
+    #[test]
+    fn lead_aims_ahead_of_a_moving_missile() {
+        let track = MissileTrack {
+            x: 400.0,
+            y: 600.0,
+            vx: 60.0,
+            vy: -20.0,
+            ballistic: crate::ecs::components::Ballistic {
+                drag_coefficient: config::MISSILE_DRAG_COEFF,
+                mass: config::MISSILE_MASS,
+                cross_section: config::MISSILE_CROSS_SECTION,
+            },
+        };
+        let lead = solve_lead(640.0, config::GROUND_Y, &track, 0.5, &standard_profile(), 0.0, &theater())
+            .expect("Missile is still airborne");
+        assert!(lead.solution.converged);
+        assert!(lead.target_x > 400.0, "Lead point {} should be ahead of the missile", lead.target_x);
+        assert!(lead.target_y < 600.0);
+    }
+
+    #[test]
+    fn unreachable_target_does_not_converge() {
+        let sol = solve(160.0, config::GROUND_Y, 10000.0, 10000.0, &standard_profile(), 0.0, &theater());
+        assert!(!sol.converged);
+    }
+}
@@ -4,6 +4,27 @@ use crate::ecs::world::World;
 use crate::engine::config;
 use crate::events::game_events::{GameEvent, MirvSplitEvent};
 
+/// Estimate a bus's split altitude from its current height.
+/// Errs high (conservative), converging on the true value as the bus descends.
+pub fn estimate_split_altitude(current_y: f32, split_altitude: f32) -> f32 {
+    split_altitude + (current_y - split_altitude).max(0.0) * config::MIRV_ESTIMATE_ERROR_FACTOR
+}
+
+/// Seconds until a bus at (y, vy) descends through `split_altitude`, ignoring drag.
+/// Returns 0.0 if it is already descending below it.
+pub fn time_to_split(y: f32, vy: f32, split_altitude: f32) -> f32 {
+    if y <= split_altitude && vy < 0.0 {
+        return 0.0;
+    }
+    // Solve y + vy*t - 0.5*g*t² = split_altitude for the later (descending) root
+    let a = 0.5 * config::GRAVITY;
+    let disc = vy * vy + 4.0 * a * (y - split_altitude);
+    if disc < 0.0 {
+        return 0.0;
+    }
+    ((vy + disc.sqrt()) / (2.0 * a)).max(0.0)
+}
+
 pub struct MirvSplitResult {
     pub events: Vec<GameEvent>,
     pub splits: u32,
@@ -18,42 +39,35 @@ pub fn run(world: &mut World, tick: u64) -> MirvSplitResult {
     };
 
     // Gather carriers that should split
-    let mut to_split: Vec<(usize, f32, f32, f32, f32, MirvCarrier)> = Vec::new();
+    let mut to_split: Vec<(EntityId, f32, f32, f32, f32, MirvCarrier)> = Vec::new();
 
-    for idx in world.alive_entities() {
-        let marker = match &world.markers[idx] {
+    for (id, &carrier) in world.mirv_carriers.iter() {
+        let marker = match world.markers.get(id) {
             Some(m) => m,
             None => continue,
         };
         if marker.kind != EntityKind::Missile {
             continue;
         }
-        let carrier = match world.mirv_carriers[idx] {
-            Some(c) => c,
-            None => continue,
-        };
-        let transform = match &world.transforms[idx] {
+        let transform = match world.transforms.get(id) {
             Some(t) => *t,
             None => continue,
         };
-        let velocity = match &world.velocities[idx] {
+        let velocity = match world.velocities.get(id) {
             Some(v) => *v,
             None => continue,
         };
 
         // Split when descending below split altitude
         if transform.y <= carrier.split_altitude && velocity.vy < 0.0 {
-            to_split.push((idx, transform.x, transform.y, velocity.vx, velocity.vy, carrier));
+            to_split.push((id, transform.x, transform.y, velocity.vx, velocity.vy, carrier));
         }
     }
 
     // Process splits
-    for (carrier_idx, x, y, vx, vy, carrier) in to_split {
+    for (carrier_id, x, y, vx, vy, carrier) in to_split {
         // Despawn the carrier
-        if let Some(generation) = world.allocator.generation_of(carrier_idx as u32) {
-            let eid = EntityId::new(carrier_idx as u32, generation);
-            world.despawn(eid);
-        }
+        world.despawn(carrier_id);
 
         // Calculate base direction from carrier velocity
         let speed = (vx * vx + vy * vy).sqrt().max(1.0);
@@ -73,38 +87,38 @@ pub fn run(world: &mut World, tick: u64) -> MirvSplitResult {
             let child_vy = child_angle.sin() * speed;
 
             let child_id = world.spawn();
-            let cidx = child_id.index as usize;
 
-            world.transforms[cidx] = Some(Transform {
+            world.insert(child_id, Transform {
                 x,
                 y,
                 rotation: child_angle,
             });
-            world.velocities[cidx] = Some(Velocity {
+            world.insert(child_id, Velocity {
                 vx: child_vx,
                 vy: child_vy,
             });
-            world.ballistics[cidx] = Some(Ballistic {
+            world.insert(child_id, Ballistic {
                 drag_coefficient: config::MISSILE_DRAG_COEFF,
                 mass: config::MISSILE_MASS,
                 cross_section: config::MISSILE_CROSS_SECTION,
             });
-            world.warheads[cidx] = Some(Warhead {
+            world.insert(child_id, Warhead {
                 yield_force: config::MIRV_CHILD_YIELD,
                 blast_radius_base: config::MIRV_CHILD_BLAST_RADIUS,
                 warhead_type: WarheadType::Standard,
             });
-            world.markers[cidx] = Some(EntityMarker {
+            world.insert(child_id, EntityMarker {
                 kind: EntityKind::Missile,
             });
-            world.reentry_glows[cidx] = Some(ReentryGlow {
+            world.insert(child_id, ReentryGlow {
                 intensity: 1.0,
                 altitude_threshold: 200.0,
             });
         }
 
         result.events.push(GameEvent::MirvSplit(MirvSplitEvent {
-            carrier_id: carrier_idx as u32,
+            carrier_id: carrier_id.index,
+            carrier_generation: carrier_id.generation,
             x,
             y,
             child_count,
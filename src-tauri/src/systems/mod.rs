@@ -7,10 +7,12 @@ pub mod detonation;
 pub mod drag;
 pub mod gravity;
 pub mod input_system;
+pub mod launch_solution;
 pub mod movement;
 pub mod shockwave_system;
 pub mod state_snapshot;
 pub mod thrust;
+pub mod trail;
 pub mod wave_spawner;
 pub mod detection;
 pub mod wind;
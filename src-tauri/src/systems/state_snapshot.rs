@@ -1,24 +1,81 @@
 use crate::ecs::components::EntityKind;
 use crate::ecs::world::World;
+use crate::engine::config;
 use crate::state::snapshot::{EntityExtra, EntitySnapshot, EntityType, StateSnapshot};
+use crate::systems::mirv_split;
+use std::borrow::Cow;
+
+/// Level-of-detail policy for crowded skies: once more than `missile_threshold`
+/// missiles are alive, low-threat missiles send only the newest `trail_points`
+/// of their trail and no extra data. A missile is low-threat when it is high
+/// up, is not a MIRV bus and no interceptor is engaging it.
+#[derive(Debug, Clone, Copy)]
+pub struct SnapshotLod {
+    pub missile_threshold: usize,
+    pub threat_altitude: f32,
+    pub trail_points: usize,
+    /// An interceptor engages a missile whose path passes this close to its target point
+    pub engage_radius: f32,
+}
+
+impl Default for SnapshotLod {
+    fn default() -> Self {
+        Self {
+            missile_threshold: config::SNAPSHOT_LOD_MISSILE_THRESHOLD,
+            threat_altitude: config::SNAPSHOT_LOD_THREAT_ALTITUDE,
+            trail_points: config::SNAPSHOT_LOD_TRAIL_POINTS,
+            engage_radius: config::SNAPSHOT_LOD_ENGAGE_RADIUS,
+        }
+    }
+}
+
+/// Whether any interceptor in flight is aimed at a point on the missile's path.
+fn is_engaged(world: &World, x: f32, y: f32, vx: f32, vy: f32, radius: f32) -> bool {
+    let speed_sq = vx * vx + vy * vy;
+    world.interceptors.iter().any(|(_, i)| {
+        let (dx, dy) = (i.target_x - x, i.target_y - y);
+        // Closest approach of the straight-line path ahead of the missile
+        let t = if speed_sq > 0.0 { ((dx * vx + dy * vy) / speed_sq).max(0.0) } else { 0.0 };
+        let (cx, cy) = (dx - vx * t, dy - vy * t);
+        cx * cx + cy * cy <= radius * radius
+    })
+}
 
 /// Build a serializable StateSnapshot from the current world state.
-pub fn build(world: &World, tick: u64, wave_number: u32, phase: &str) -> StateSnapshot {
-    let mut entities = Vec::new();
+pub fn build(world: &World, tick: u64, wave_number: u32, phase: &str, lod: &SnapshotLod) -> StateSnapshot {
+    let mut snapshot = StateSnapshot::default();
+    build_into(&mut snapshot, world, tick, wave_number, phase, lod);
+    snapshot
+}
 
-    for idx in world.alive_entities() {
-        let marker = match &world.markers[idx] {
-            Some(m) => m,
-            None => continue,
-        };
+/// Rebuild `out` from the current world state, reusing its entity list and
+/// trail buffers so a steady-state tick doesn't allocate. Weather, wind and
+/// loop flags are left for the caller to set.
+pub fn build_into(
+    out: &mut StateSnapshot,
+    world: &World,
+    tick: u64,
+    wave_number: u32,
+    phase: &str,
+    lod: &SnapshotLod,
+) {
+    let missile_count = world
+        .markers
+        .iter()
+        .filter(|(_, m)| m.kind == EntityKind::Missile)
+        .count();
+    let lod_active = missile_count > lod.missile_threshold;
+    let mut count = 0;
 
-        let transform = match &world.transforms[idx] {
+    for (id, marker) in world.markers.iter() {
+        let transform = match world.transforms.get(id) {
             Some(t) => t,
             None => continue,
         };
 
-        let (vx, vy) = world.velocities[idx]
-            .as_ref()
+        let (vx, vy) = world
+            .velocities
+            .get(id)
             .map(|v| (v.vx, v.vy))
             .unwrap_or((0.0, 0.0));
 
@@ -30,57 +87,103 @@ pub fn build(world: &World, tick: u64, wave_number: u32, phase: &str) -> StateSn
             EntityKind::Battery => EntityType::Battery,
         };
 
+        let low_threat = lod_active
+            && marker.kind == EntityKind::Missile
+            && !world.mirv_carriers.contains(id)
+            && transform.y - config::GROUND_Y > lod.threat_altitude
+            && !is_engaged(world, transform.x, transform.y, vx, vy, lod.engage_radius);
+
         let extra = match marker.kind {
-            EntityKind::Shockwave => world.shockwaves[idx].as_ref().map(|s| EntityExtra::Shockwave {
+            EntityKind::Shockwave => world.shockwaves.get(id).map(|s| EntityExtra::Shockwave {
                 radius: s.radius,
                 max_radius: s.max_radius,
             }),
-            EntityKind::City => world.healths[idx].as_ref().map(|h| EntityExtra::City {
+            EntityKind::City => world.healths.get(id).map(|h| EntityExtra::City {
                 health: h.current,
                 max_health: h.max,
             }),
             EntityKind::Battery => {
-                world.battery_states[idx].as_ref().map(|b| EntityExtra::Battery {
+                world.battery_states.get(id).map(|b| EntityExtra::Battery {
                     ammo: b.ammo,
                     max_ammo: b.max_ammo,
+                    reload_remaining: b.reload_remaining as f32 * config::DT,
+                    reload_time: config::BATTERY_RELOAD_TICKS as f32 * config::DT,
+                    in_flight: b.in_flight,
+                    max_in_flight: config::BATTERY_MAX_IN_FLIGHT,
+                    arc_min: b.arc_min,
+                    arc_max: b.arc_max,
                 })
             }
             EntityKind::Interceptor => {
-                world.interceptors[idx].as_ref().map(|i| EntityExtra::Interceptor {
+                world.interceptors.get(id).map(|i| EntityExtra::Interceptor {
                     burn_remaining: i.burn_remaining,
                     burn_time: i.burn_time,
-                    interceptor_type: i.interceptor_type.as_str().to_string(),
+                    interceptor_type: Cow::Borrowed(i.interceptor_type.as_str()),
                 })
             }
+            // Low-threat tracks are drawn from position and trail alone
+            EntityKind::Missile if low_threat => None,
             EntityKind::Missile => {
                 // Always include all missiles — no radar gating
-                let is_mirv = world.mirv_carriers[idx].is_some();
+                let carrier = world.mirv_carriers.get(id);
+                let estimated_split_altitude = carrier.and_then(|c| c.estimated_split_altitude);
+                let split_countdown =
+                    estimated_split_altitude.map(|alt| mirv_split::time_to_split(transform.y, vy, alt));
+                let warhead_type = world
+                    .warheads
+                    .get(id)
+                    .map(|w| Cow::Borrowed(w.warhead_type.as_str()))
+                    .unwrap_or_default();
                 Some(EntityExtra::Missile {
-                    is_mirv,
+                    is_mirv: carrier.is_some(),
+                    warhead_type,
                     detected_by_radar: true,
                     detected_by_glow: false,
+                    estimated_split_altitude,
+                    split_countdown,
                 })
             }
         };
 
-        entities.push(EntitySnapshot {
-            id: idx as u32,
-            entity_type,
-            x: transform.x,
-            y: transform.y,
-            rotation: transform.rotation,
-            vx,
-            vy,
-            extra,
-        });
+        // Reuse the slot (and its trail buffer) left by the previous build
+        if count == out.entities.len() {
+            out.entities.push(EntitySnapshot {
+                id: 0,
+                generation: 0,
+                entity_type,
+                x: 0.0,
+                y: 0.0,
+                rotation: 0.0,
+                vx: 0.0,
+                vy: 0.0,
+                extra: None,
+                trail: Vec::new(),
+            });
+        }
+        let entity = &mut out.entities[count];
+        count += 1;
+
+        entity.id = id.index;
+        entity.generation = id.generation;
+        entity.entity_type = entity_type;
+        entity.x = transform.x;
+        entity.y = transform.y;
+        entity.rotation = transform.rotation;
+        entity.vx = vx;
+        entity.vy = vy;
+        entity.extra = extra;
+        entity.trail.clear();
+        if let Some(t) = world.trails.get(id) {
+            let keep = if low_threat { lod.trail_points } else { t.points.len() };
+            entity.trail.extend(t.points.iter().skip(t.points.len().saturating_sub(keep)).copied());
+        }
     }
+    out.entities.truncate(count);
 
-    StateSnapshot {
-        tick,
-        wave_number,
-        phase: phase.to_string(),
-        entities,
-        weather: None,
-        wind_x: None,
+    out.tick = tick;
+    out.wave_number = wave_number;
+    if out.phase != phase {
+        out.phase.clear();
+        out.phase.push_str(phase);
     }
 }
@@ -2,22 +2,21 @@ use crate::ecs::world::World;
 use crate::engine::config;
 
 /// Apply thrust to interceptors during their burn phase.
-/// Thrust is applied in the direction from launch position toward target.
+/// Thrust is applied in the direction from current position toward the aim point.
 pub fn run(world: &mut World) {
-    for idx in world.alive_entities() {
-        let interceptor = match world.interceptors[idx].as_mut() {
-            Some(i) if i.burn_remaining > 0.0 => i,
-            _ => continue,
-        };
+    for (id, interceptor) in world.interceptors.iter_mut() {
+        if interceptor.burn_remaining <= 0.0 {
+            continue;
+        }
 
-        let transform = match world.transforms[idx] {
-            Some(t) => t,
+        let transform = match world.transforms.get(id) {
+            Some(t) => *t,
             None => continue,
         };
 
-        // Calculate direction toward target
-        let dx = interceptor.target_x - transform.x;
-        let dy = interceptor.target_y - transform.y;
+        // Calculate direction toward aim point
+        let dx = interceptor.aim_x - transform.x;
+        let dy = interceptor.aim_y - transform.y;
         let dist = (dx * dx + dy * dy).sqrt();
 
         if dist < 1e-6 {
@@ -28,7 +27,7 @@ pub fn run(world: &mut World) {
         let dir_x = dx / dist;
         let dir_y = dy / dist;
 
-        if let Some(ref mut vel) = world.velocities[idx] {
+        if let Some(vel) = world.velocities.get_mut(id) {
             let thrust_accel = interceptor.thrust * config::DT;
             vel.vx += dir_x * thrust_accel;
             vel.vy += dir_y * thrust_accel;
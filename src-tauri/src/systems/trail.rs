@@ -0,0 +1,77 @@
+use crate::ecs::components::{EntityKind, Trail};
+use crate::ecs::entity::EntityId;
+use crate::ecs::world::World;
+use crate::engine::config;
+
+/// Working storage for trail sampling, reused across ticks.
+#[derive(Debug, Default)]
+pub struct TrailBuffers {
+    alive: Vec<EntityId>,
+}
+
+/// Sample missile and interceptor positions into their trails every
+/// TRAIL_SAMPLE_TICKS, keeping at most `max_points` per entity.
+pub fn run(world: &mut World, tick: u64, max_points: usize, buffers: &mut TrailBuffers) {
+    if !tick.is_multiple_of(config::TRAIL_SAMPLE_TICKS) {
+        return;
+    }
+
+    world.alive_entities_into(&mut buffers.alive);
+    for &id in buffers.alive.iter() {
+        let moving = matches!(
+            world.markers.get(id).map(|m| m.kind),
+            Some(EntityKind::Missile | EntityKind::Interceptor)
+        );
+        if !moving {
+            continue;
+        }
+        let Some(t) = world.transforms.get(id) else {
+            continue;
+        };
+        let point = (t.x, t.y);
+
+        if !world.trails.contains(id) {
+            world.insert(id, Trail::default());
+        }
+        let Some(trail) = world.trails.get_mut(id) else {
+            continue;
+        };
+        trail.points.push_back(point);
+        while trail.points.len() > max_points {
+            trail.points.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::components::{EntityMarker, Transform};
+    use crate::ecs::entity::EntityId;
+
+    fn spawn(world: &mut World, kind: EntityKind) -> EntityId {
+        let id = world.spawn();
+        world.insert(id, Transform { x: 0.0, y: 0.0, rotation: 0.0 });
+        world.insert(id, EntityMarker { kind });
+        id
+    }
+
+    #[test]
+    fn trail_samples_moving_entities_and_caps_length() {
+        let mut world = World::new();
+        let missile = spawn(&mut world, EntityKind::Missile);
+        let city = spawn(&mut world, EntityKind::City);
+        let mut buffers = TrailBuffers::default();
+
+        for tick in 0..100 {
+            world.transforms.get_mut(missile).unwrap().x = tick as f32;
+            run(&mut world, tick, 5, &mut buffers);
+        }
+
+        let points = &world.trails.get(missile).unwrap().points;
+        assert_eq!(points.len(), 5);
+        assert_eq!(points.back().unwrap().0, 98.0);
+        assert_eq!(points.front().unwrap().0, 90.0);
+        assert!(world.trails.get(city).is_none());
+    }
+}
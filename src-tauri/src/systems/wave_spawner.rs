@@ -31,11 +31,7 @@ pub fn run(
     // Pick a random alive city to target
     let alive_cities: Vec<&EntityId> = city_ids
         .iter()
-        .filter(|&&id| {
-            world.healths[id.index as usize]
-                .as_ref()
-                .is_some_and(|h| h.current > 0.0)
-        })
+        .filter(|&&id| world.healths.get(id).is_some_and(|h| h.current > 0.0))
         .collect();
 
     if alive_cities.is_empty() {
@@ -43,19 +39,35 @@ pub fn run(
     }
 
     let city_id = alive_cities[rng.gen_range(0..alive_cities.len())];
-    let city_pos = match world.transforms[city_id.index as usize] {
+    let city_pos = match world.transforms.get(*city_id).copied() {
         Some(t) => t,
         None => return,
     };
 
+    // MIRV carriers spawn first, then sea-skimmers, then standard missiles
+    let is_mirv = wave.mirv_spawned < wave.definition.mirv_count;
+    let is_sea_skimmer = !is_mirv && wave.sea_skimmers_spawned < wave.definition.sea_skimmer_count;
+
     // Random spawn position along top edge
-    let spawn_x: f32 = rng.gen_range(100.0..config::WORLD_WIDTH - 100.0);
-    let spawn_y: f32 = config::WORLD_HEIGHT;
+    let mut spawn_x: f32 = rng.gen_range(100.0..config::WORLD_WIDTH - 100.0);
+    let mut spawn_y: f32 = config::WORLD_HEIGHT;
 
     // Random flight time (controls arc profile)
-    let flight_time: f32 =
+    let mut flight_time: f32 =
         rng.gen_range(wave.definition.flight_time_min..wave.definition.flight_time_max);
 
+    // Sea-skimmers come in low from the side edge nearest their draw, on a flat, fast profile
+    if is_sea_skimmer {
+        wave.sea_skimmers_spawned += 1;
+        spawn_x = if spawn_x < config::WORLD_WIDTH * 0.5 {
+            0.0
+        } else {
+            config::WORLD_WIDTH
+        };
+        spawn_y = config::SEA_SKIMMER_ALTITUDE;
+        flight_time = wave.definition.flight_time_min;
+    }
+
     // Calculate initial velocity to arc toward target under gravity (no-drag approximation)
     // y(T) = y0 + vy*T - 0.5*g*T²  →  vy = (y_target - y0)/T + 0.5*g*T
     // x(T) = x0 + vx*T              →  vx = (x_target - x0)/T
@@ -66,51 +78,52 @@ pub fn run(
 
     // Spawn the missile entity
     let id = world.spawn();
-    let idx = id.index as usize;
 
-    world.transforms[idx] = Some(Transform {
+    world.insert(id, Transform {
         x: spawn_x,
         y: spawn_y,
         rotation: vy.atan2(vx),
     });
 
-    world.velocities[idx] = Some(Velocity { vx, vy });
+    world.insert(id, Velocity { vx, vy });
 
-    world.ballistics[idx] = Some(Ballistic {
+    world.insert(id, Ballistic {
         drag_coefficient: config::MISSILE_DRAG_COEFF,
         mass: config::MISSILE_MASS,
         cross_section: config::MISSILE_CROSS_SECTION,
     });
 
-    // Determine if this missile is a MIRV carrier
-    let is_mirv = wave.mirv_spawned < wave.definition.mirv_count;
     if is_mirv {
         wave.mirv_spawned += 1;
         let split_altitude = rng.gen_range(config::MIRV_SPLIT_ALTITUDE_MIN..config::MIRV_SPLIT_ALTITUDE_MAX);
-        world.mirv_carriers[idx] = Some(MirvCarrier {
+        world.insert(id, MirvCarrier {
             child_count: wave.definition.mirv_child_count,
             split_altitude,
             spread_angle: config::MIRV_SPREAD_ANGLE,
+            estimated_split_altitude: None,
         });
-        world.warheads[idx] = Some(Warhead {
+        world.insert(id, Warhead {
             yield_force: 0.0, // carrier itself has no warhead effect
             blast_radius_base: 0.0,
             warhead_type: WarheadType::Mirv,
         });
     } else {
-        world.warheads[idx] = Some(Warhead {
+        world.insert(id, Warhead {
             yield_force: config::WARHEAD_YIELD,
             blast_radius_base: config::WARHEAD_BLAST_RADIUS,
             warhead_type: WarheadType::Standard,
         });
     }
 
-    world.markers[idx] = Some(EntityMarker {
+    world.insert(id, EntityMarker {
         kind: EntityKind::Missile,
     });
 
-    world.reentry_glows[idx] = Some(ReentryGlow {
-        intensity: 1.0,
-        altitude_threshold: 200.0,
-    });
+    // Sea-skimmers never climb into reentry, so only radar can see them
+    if !is_sea_skimmer {
+        world.insert(id, ReentryGlow {
+            intensity: 1.0,
+            altitude_threshold: 200.0,
+        });
+    }
 }
@@ -10,8 +10,8 @@ pub fn run(world: &mut World, weather: &WeatherState) {
         return;
     }
 
-    for idx in world.alive_entities() {
-        let marker = match &world.markers[idx] {
+    for (id, vel) in world.velocities.iter_mut() {
+        let marker = match world.markers.get(id) {
             Some(m) => m,
             None => continue,
         };
@@ -21,16 +21,11 @@ pub fn run(world: &mut World, weather: &WeatherState) {
             continue;
         }
 
-        let y = match &world.transforms[idx] {
+        let y = match world.transforms.get(id) {
             Some(t) => t.y,
             None => continue,
         };
 
-        let vel = match &mut world.velocities[idx] {
-            Some(v) => v,
-            None => continue,
-        };
-
         // Wind effect scales with altitude
         let altitude = (y - config::GROUND_Y).max(0.0);
         let altitude_factor = altitude * config::WIND_ALTITUDE_FACTOR;
@@ -44,15 +39,15 @@ pub fn run(world: &mut World, weather: &WeatherState) {
 mod tests {
     use super::*;
     use crate::ecs::components::*;
+    use crate::ecs::entity::EntityId;
     use crate::state::weather::WeatherCondition;
 
-    fn setup_entity(world: &mut World, kind: EntityKind, x: f32, y: f32, vx: f32, vy: f32) -> usize {
+    fn setup_entity(world: &mut World, kind: EntityKind, x: f32, y: f32, vx: f32, vy: f32) -> EntityId {
         let id = world.spawn();
-        let idx = id.index as usize;
-        world.transforms[idx] = Some(Transform { x, y, rotation: 0.0 });
-        world.markers[idx] = Some(EntityMarker { kind });
-        world.velocities[idx] = Some(Velocity { vx, vy });
-        idx
+        world.insert(id, Transform { x, y, rotation: 0.0 });
+        world.insert(id, EntityMarker { kind });
+        world.insert(id, Velocity { vx, vy });
+        id
     }
 
     #[test]
@@ -63,7 +58,7 @@ mod tests {
         let weather = WeatherState::default(); // Clear, no wind
         run(&mut world, &weather);
 
-        let vel = world.velocities[idx].as_ref().unwrap();
+        let vel = world.velocities.get(idx).unwrap();
         assert_eq!(vel.vx, 0.0, "Zero wind should not change vx");
     }
 
@@ -79,7 +74,7 @@ mod tests {
         };
         run(&mut world, &weather);
 
-        let vel = world.velocities[idx].as_ref().unwrap();
+        let vel = world.velocities.get(idx).unwrap();
         assert!(vel.vx > 0.0, "Positive wind should increase vx, got {}", vel.vx);
     }
 
@@ -96,8 +91,8 @@ mod tests {
         };
         run(&mut world, &weather);
 
-        let low_vx = world.velocities[low_idx].as_ref().unwrap().vx;
-        let high_vx = world.velocities[high_idx].as_ref().unwrap().vx;
+        let low_vx = world.velocities.get(low_idx).unwrap().vx;
+        let high_vx = world.velocities.get(high_idx).unwrap().vx;
         assert!(
             high_vx > low_vx,
             "Higher altitude ({high_vx}) should get more wind than lower ({low_vx})"
@@ -116,7 +111,7 @@ mod tests {
         };
         run(&mut world, &weather);
 
-        let vel = world.velocities[idx].as_ref().unwrap();
+        let vel = world.velocities.get(idx).unwrap();
         assert_eq!(vel.vx, 0.0, "Wind should not affect cities");
     }
 }
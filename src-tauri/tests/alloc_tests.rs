@@ -0,0 +1,157 @@
+//! Steady-state ticks reuse their buffers: once warmed up, the snapshot
+//! builder and the gather-then-apply systems must not touch the heap.
+
+use deterrence_lib::ecs::components::*;
+use deterrence_lib::ecs::entity::EntityId;
+use deterrence_lib::engine::config;
+use deterrence_lib::engine::simulation::Simulation;
+use deterrence_lib::state::snapshot::{EntityType, StateSnapshot};
+use deterrence_lib::systems::{cleanup, collision, detonation, state_snapshot};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// Default campaign with its world set up.
+fn new_sim() -> Simulation {
+    let mut sim = Simulation::new();
+    sim.setup_world();
+    sim
+}
+
+fn spawn_missile(sim: &mut Simulation, x: f32, y: f32, vx: f32, vy: f32) -> EntityId {
+    let id = sim.world.spawn();
+    sim.world.insert(id, Transform { x, y, rotation: 0.0 });
+    sim.world.insert(id, Velocity { vx, vy });
+    sim.world.insert(id, Ballistic {
+        drag_coefficient: config::MISSILE_DRAG_COEFF,
+        mass: config::MISSILE_MASS,
+        cross_section: config::MISSILE_CROSS_SECTION,
+    });
+    sim.world.insert(id, Warhead {
+        yield_force: config::WARHEAD_YIELD,
+        blast_radius_base: config::WARHEAD_BLAST_RADIUS,
+        warhead_type: WarheadType::Standard,
+    });
+    sim.world.insert(id, EntityMarker {
+        kind: EntityKind::Missile,
+    });
+    id
+}
+
+/// Counts allocations per thread so tests running in parallel don't see each other's.
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+fn count_allocation() {
+    let _ = ALLOCATIONS.try_with(|c| c.set(c.get() + 1));
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count_allocation();
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count_allocation();
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn allocations_during(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+#[test]
+fn snapshot_rebuild_reuses_entities_and_trails() {
+    let mut sim = new_sim();
+    for i in 0..8 {
+        spawn_missile(&mut sim, 200.0 + i as f32 * 100.0, 700.0, 5.0, -20.0);
+    }
+    // Long enough for every trail to reach its cap
+    for _ in 0..80 {
+        sim.tick();
+    }
+
+    let mut snapshot = StateSnapshot::default();
+    let lod = state_snapshot::SnapshotLod::default();
+    state_snapshot::build_into(&mut snapshot, &sim.world, sim.tick, 1, "WaveActive", &lod);
+    sim.tick();
+
+    let allocations = allocations_during(|| {
+        state_snapshot::build_into(&mut snapshot, &sim.world, sim.tick, 1, "WaveActive", &lod);
+    });
+    assert_eq!(allocations, 0);
+    let missiles = snapshot.entities.iter().filter(|e| e.entity_type == EntityType::Missile).count();
+    assert_eq!(missiles, 8);
+}
+
+#[test]
+fn steady_state_tick_does_not_allocate() {
+    let mut sim = new_sim();
+    for i in 0..8 {
+        spawn_missile(&mut sim, 200.0 + i as f32 * 100.0, 700.0, 5.0, -20.0);
+    }
+    // Long enough for every trail to reach its cap, covering trail samples and detection
+    for _ in 0..80 {
+        sim.tick();
+    }
+
+    let allocations = allocations_during(|| {
+        for _ in 0..10 {
+            sim.tick();
+        }
+    });
+    assert_eq!(allocations, 0);
+    assert!(sim.drain_events().is_empty(), "Nothing should have happened in the measured ticks");
+}
+
+#[test]
+fn hot_systems_reuse_their_buffers() {
+    let mut sim = new_sim();
+    for i in 0..8 {
+        spawn_missile(&mut sim, 200.0 + i as f32 * 100.0, 700.0, 5.0, -20.0);
+    }
+    // A lingering shockwave far from every missile keeps the collision pass busy
+    let sw = sim.world.spawn();
+    sim.world.insert(sw, Transform { x: 640.0, y: 150.0, rotation: 0.0 });
+    sim.world.insert(sw, Shockwave {
+        radius: 20.0,
+        max_radius: 20.0,
+        force: 1.0,
+        expansion_rate: 0.0,
+        damage_applied: true,
+        source: None,
+    });
+    sim.world.insert(sw, EntityMarker { kind: EntityKind::Shockwave });
+    sim.world.insert(sw, Lifetime { remaining_ticks: 1000 });
+
+    let theater = sim.campaign.theater;
+    let mut cleanup_buffers = cleanup::CleanupBuffers::default();
+    let mut collision_buffers = collision::CollisionBuffers::default();
+    let mut detonation_buffers = detonation::DetonationBuffers::default();
+    let mut pass = |sim: &mut Simulation| {
+        collision::run(&mut sim.world, sim.tick, &mut collision_buffers);
+        detonation::run(&mut sim.world, sim.tick, &mut detonation_buffers);
+        cleanup::run(&mut sim.world, &theater, sim.tick, &mut cleanup_buffers);
+    };
+
+    pass(&mut sim);
+    let allocations = allocations_during(|| {
+        for _ in 0..10 {
+            pass(&mut sim);
+        }
+    });
+    assert_eq!(allocations, 0);
+}
@@ -0,0 +1,240 @@
+use deterrence_lib::ecs::components::*;
+use deterrence_lib::ecs::entity::EntityId;
+use deterrence_lib::engine::config;
+use deterrence_lib::engine::simulation::Simulation;
+use deterrence_lib::events::game_events::GameEvent;
+use deterrence_lib::systems::input_system::{self, PlayerCommand};
+
+/// Default campaign with its world set up.
+fn new_sim() -> Simulation {
+    let mut sim = Simulation::new();
+    sim.setup_world();
+    sim
+}
+
+fn spawn_missile(sim: &mut Simulation, x: f32, y: f32, vx: f32, vy: f32) -> EntityId {
+    let id = sim.world.spawn();
+    sim.world.insert(id, Transform { x, y, rotation: 0.0 });
+    sim.world.insert(id, Velocity { vx, vy });
+    sim.world.insert(id, Ballistic {
+        drag_coefficient: config::MISSILE_DRAG_COEFF,
+        mass: config::MISSILE_MASS,
+        cross_section: config::MISSILE_CROSS_SECTION,
+    });
+    sim.world.insert(id, Warhead {
+        yield_force: config::WARHEAD_YIELD,
+        blast_radius_base: config::WARHEAD_BLAST_RADIUS,
+        warhead_type: WarheadType::Standard,
+    });
+    sim.world.insert(id, EntityMarker {
+        kind: EntityKind::Missile,
+    });
+    id
+}
+
+fn launch(sim: &mut Simulation, battery_id: u32, target_x: f32, target_y: f32) {
+    sim.push_command(PlayerCommand::LaunchInterceptor {
+        battery_id,
+        target_x,
+        target_y,
+        interceptor_type: InterceptorType::Standard,
+    });
+}
+
+fn interceptor_count(sim: &Simulation) -> usize {
+    sim.world.interceptors.iter().count()
+}
+
+fn battery_state(sim: &Simulation, battery_id: usize) -> BatteryState {
+    *sim.world.battery_states.get(sim.battery_ids[battery_id]).unwrap()
+}
+
+fn salvo(sim: &mut Simulation, target_x: f32, target_y: f32, count: u32) {
+    sim.push_command(PlayerCommand::SalvoIntercept {
+        target_x,
+        target_y,
+        count,
+        interceptor_type: InterceptorType::Standard,
+    });
+}
+
+// --- Battery Constraint Tests ---
+
+#[test]
+fn battery_reload_blocks_back_to_back_launches() {
+    let mut sim = new_sim();
+    sim.start_wave();
+
+    launch(&mut sim, 0, 400.0, 500.0);
+    launch(&mut sim, 0, 420.0, 500.0);
+    sim.tick();
+
+    assert_eq!(interceptor_count(&sim), 1, "Second launch should wait for reload");
+    let bs = battery_state(&sim, 0);
+    assert_eq!(bs.ammo, config::BATTERY_MAX_AMMO - 1);
+    assert_eq!(bs.reload_remaining, config::BATTERY_RELOAD_TICKS);
+}
+
+#[test]
+fn battery_can_fire_again_after_reload() {
+    let mut sim = new_sim();
+    sim.start_wave();
+
+    launch(&mut sim, 0, 400.0, 500.0);
+    sim.tick();
+    for _ in 0..config::BATTERY_RELOAD_TICKS - 1 {
+        sim.tick();
+    }
+
+    launch(&mut sim, 0, 420.0, 500.0);
+    sim.tick();
+    assert_eq!(battery_state(&sim, 0).ammo, config::BATTERY_MAX_AMMO - 2);
+}
+
+#[test]
+fn launch_outside_firing_arc_is_rejected() {
+    let mut sim = new_sim();
+    sim.start_wave();
+
+    // Level with the battery: below the minimum elevation of the arc
+    let bat_pos = *sim.world.transforms.get(sim.battery_ids[0]).unwrap();
+    launch(&mut sim, 0, bat_pos.x + 500.0, bat_pos.y);
+    sim.tick();
+
+    assert_eq!(interceptor_count(&sim), 0);
+    assert_eq!(battery_state(&sim, 0).ammo, config::BATTERY_MAX_AMMO);
+}
+
+#[test]
+fn battery_tracks_interceptors_in_flight() {
+    let mut sim = new_sim();
+    sim.start_wave();
+
+    launch(&mut sim, 0, 400.0, 500.0);
+    sim.tick();
+    sim.tick();
+
+    assert_eq!(battery_state(&sim, 0).in_flight, 1);
+    assert_eq!(battery_state(&sim, 1).in_flight, 0);
+}
+
+#[test]
+fn in_flight_limit_blocks_launch() {
+    let bs = BatteryState {
+        ammo: 5,
+        max_ammo: 10,
+        reload_remaining: 0,
+        arc_min: config::BATTERY_ARC_MIN,
+        arc_max: config::BATTERY_ARC_MAX,
+        in_flight: config::BATTERY_MAX_IN_FLIGHT,
+        radar_multiplier: 1.0,
+    };
+    let straight_up = std::f32::consts::FRAC_PI_2;
+    assert!(!input_system::can_launch(&bs, straight_up));
+    assert!(input_system::can_launch(
+        &BatteryState { in_flight: 0, ..bs },
+        straight_up
+    ));
+}
+
+// --- Salvo Tests ---
+
+#[test]
+fn salvo_fires_nearest_battery_first_then_staggers() {
+    let mut sim = new_sim();
+    sim.start_wave();
+
+    let far_right = sim
+        .battery_ids
+        .iter()
+        .map(|&id| sim.world.transforms.get(id).unwrap().x)
+        .fold(f32::MIN, f32::max);
+    salvo(&mut sim, far_right, 600.0, 2);
+
+    sim.tick();
+    assert_eq!(interceptor_count(&sim), 1, "Salvo shots are staggered");
+    let first = sim
+        .world
+        .interceptors
+        .iter()
+        .next()
+        .unwrap()
+        .1
+        .battery_id as usize;
+    let first_x = sim.world.transforms.get(sim.battery_ids[first]).unwrap().x;
+    assert_eq!(first_x, far_right, "Best-placed battery should fire first");
+
+    for _ in 0..config::SALVO_STAGGER_TICKS {
+        sim.tick();
+    }
+    assert_eq!(interceptor_count(&sim), 2);
+    let fired: Vec<u32> = sim.world.interceptors.iter().map(|(_, i)| i.battery_id).collect();
+    assert_ne!(fired[0], fired[1], "Each salvo shot should come from a different battery");
+}
+
+fn salvo_shortfalls(events: &[GameEvent]) -> Vec<(u32, u32)> {
+    events
+        .iter()
+        .filter_map(|e| match e {
+            GameEvent::SalvoShortfall(s) => Some((s.requested, s.assigned)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn salvo_reports_shortfall_when_too_few_batteries() {
+    let mut sim = new_sim();
+    sim.start_wave();
+
+    // Homeland has two batteries, so a three-shot salvo gets two
+    salvo(&mut sim, 640.0, 600.0, 3);
+    let mut events = Vec::new();
+    for _ in 0..=config::SALVO_STAGGER_TICKS {
+        sim.tick();
+        events.extend(sim.drain_events());
+    }
+    assert_eq!(salvo_shortfalls(&events), vec![(3, 2)]);
+    assert_eq!(interceptor_count(&sim), 2);
+    assert!(sim.salvo_queue.is_empty());
+}
+
+#[test]
+fn salvo_waits_for_reload_instead_of_dropping() {
+    let mut sim = new_sim();
+    sim.start_wave();
+
+    launch(&mut sim, 0, 400.0, 500.0);
+    launch(&mut sim, 1, 900.0, 500.0);
+    sim.tick();
+    assert_eq!(interceptor_count(&sim), 2);
+
+    // Both batteries are reloading when the salvo is ordered
+    salvo(&mut sim, 640.0, 600.0, 2);
+    sim.tick();
+    assert_eq!(interceptor_count(&sim), 2);
+    assert_eq!(sim.salvo_queue.len(), 2, "Shots should wait for their batteries");
+
+    let mut events = Vec::new();
+    for _ in 0..config::BATTERY_RELOAD_TICKS + config::SALVO_STAGGER_TICKS {
+        sim.tick();
+        events.extend(sim.drain_events());
+    }
+    assert_eq!(interceptor_count(&sim), 4);
+    assert!(sim.salvo_queue.is_empty());
+    assert!(salvo_shortfalls(&events).is_empty());
+}
+
+#[test]
+fn salvo_leads_a_moving_missile() {
+    let mut sim = new_sim();
+    sim.start_wave();
+
+    spawn_missile(&mut sim, 640.0, 700.0, 60.0, -20.0);
+    salvo(&mut sim, 650.0, 690.0, 1);
+    sim.tick();
+
+    let (_, icpt) = sim.world.interceptors.iter().next().expect("Salvo shot fired");
+    assert!(icpt.target_x > 660.0, "Should aim ahead of the missile, got {}", icpt.target_x);
+    assert!(icpt.target_y < 700.0);
+}
@@ -13,38 +13,36 @@ fn setup_scenario(sim: &mut Simulation) {
 
     for (x, y, vx, vy, cd) in missiles {
         let id = sim.world.spawn();
-        let idx = id.index as usize;
-        sim.world.transforms[idx] = Some(Transform {
+        sim.world.insert(id, Transform {
             x,
             y,
             rotation: 0.0,
         });
-        sim.world.velocities[idx] = Some(Velocity { vx, vy });
-        sim.world.ballistics[idx] = Some(Ballistic {
+        sim.world.insert(id, Velocity { vx, vy });
+        sim.world.insert(id, Ballistic {
             drag_coefficient: cd,
             mass: 100.0,
             cross_section: 0.3,
         });
-        sim.world.markers[idx] = Some(EntityMarker {
+        sim.world.insert(id, EntityMarker {
             kind: EntityKind::Missile,
         });
     }
 
     // Spawn an interceptor
     let id = sim.world.spawn();
-    let idx = id.index as usize;
-    sim.world.transforms[idx] = Some(Transform {
+    sim.world.insert(id, Transform {
         x: 500.0,
         y: config::GROUND_Y,
         rotation: 0.0,
     });
-    sim.world.velocities[idx] = Some(Velocity { vx: 0.0, vy: 0.0 });
-    sim.world.ballistics[idx] = Some(Ballistic {
+    sim.world.insert(id, Velocity { vx: 0.0, vy: 0.0 });
+    sim.world.insert(id, Ballistic {
         drag_coefficient: 0.05,
         mass: 50.0,
         cross_section: 0.05,
     });
-    sim.world.interceptors[idx] = Some(Interceptor {
+    sim.world.insert(id, Interceptor {
         interceptor_type: InterceptorType::Standard,
         thrust: config::INTERCEPTOR_THRUST,
         burn_time: config::INTERCEPTOR_BURN_TIME,
@@ -53,9 +51,11 @@ fn setup_scenario(sim: &mut Simulation) {
         battery_id: 0,
         target_x: 300.0,
         target_y: 500.0,
+        aim_x: 300.0,
+        aim_y: 500.0,
         proximity_fuse_radius: 0.0,
     });
-    sim.world.markers[idx] = Some(EntityMarker {
+    sim.world.insert(id, EntityMarker {
         kind: EntityKind::Interceptor,
     });
 }
@@ -0,0 +1,131 @@
+use deterrence_lib::ecs::components::*;
+use deterrence_lib::ecs::entity::EntityId;
+use deterrence_lib::engine::config;
+use deterrence_lib::engine::simulation::Simulation;
+use deterrence_lib::state::game_state::GamePhase;
+use deterrence_lib::state::snapshot::EntityType;
+
+/// Default campaign with its world set up.
+fn new_sim() -> Simulation {
+    let mut sim = Simulation::new();
+    sim.setup_world();
+    sim
+}
+
+fn spawn_missile(sim: &mut Simulation, x: f32, y: f32, vx: f32, vy: f32) -> EntityId {
+    let id = sim.world.spawn();
+    sim.world.insert(id, Transform { x, y, rotation: 0.0 });
+    sim.world.insert(id, Velocity { vx, vy });
+    sim.world.insert(id, Ballistic {
+        drag_coefficient: config::MISSILE_DRAG_COEFF,
+        mass: config::MISSILE_MASS,
+        cross_section: config::MISSILE_CROSS_SECTION,
+    });
+    sim.world.insert(id, Warhead {
+        yield_force: config::WARHEAD_YIELD,
+        blast_radius_base: config::WARHEAD_BLAST_RADIUS,
+        warhead_type: WarheadType::Standard,
+    });
+    sim.world.insert(id, EntityMarker {
+        kind: EntityKind::Missile,
+    });
+    id
+}
+
+// --- Watchdog Tests ---
+
+#[test]
+fn degraded_mode_batches_detection() {
+    let mut sim = new_sim();
+    sim.degraded = true;
+    // Advance past a detection tick so the next pass is a full interval away
+    sim.tick();
+    let idx = spawn_missile(&mut sim, 300.0, 450.0, 0.0, -20.0);
+
+    for _ in 1..config::DEGRADED_DETECTION_INTERVAL {
+        let snapshot = sim.tick();
+        assert!(snapshot.degraded);
+        assert!(sim.world.detected.get(idx).is_none(), "Detection should wait for the batch tick");
+    }
+    sim.tick();
+    assert!(sim.world.detected.contains(idx), "Batched detection should still find the missile");
+}
+
+#[test]
+fn normal_mode_detects_every_tick() {
+    let mut sim = new_sim();
+    sim.tick();
+    let idx = spawn_missile(&mut sim, 300.0, 450.0, 0.0, -20.0);
+
+    let snapshot = sim.tick();
+    assert!(!snapshot.degraded);
+    assert!(sim.world.detected.contains(idx));
+}
+
+// --- Pause & Restart Tests ---
+
+#[test]
+fn restart_wave_restores_pre_wave_campaign() {
+    let mut sim = new_sim();
+    let before = sim.campaign.clone();
+    sim.start_wave();
+    let wave_number = sim.wave_number;
+
+    // Let the wave do some damage, then take a result
+    for _ in 0..3000 {
+        if sim.phase != GamePhase::WaveActive {
+            break;
+        }
+        sim.tick();
+    }
+    sim.campaign.resources = 0;
+
+    sim.restart_wave().unwrap();
+    assert_eq!(sim.phase, GamePhase::WaveActive);
+    assert_eq!(sim.wave_number, wave_number);
+    assert_eq!(sim.campaign.resources, before.resources);
+    assert_eq!(sim.campaign.wave_scores.len(), before.wave_scores.len());
+    assert_eq!(sim.campaign.city_healths, before.city_healths);
+    assert_eq!(sim.wave.as_ref().unwrap().missiles_spawned, 0);
+}
+
+#[test]
+fn restarted_wave_uses_fresh_seed() {
+    let spawn_positions = |restart: bool| {
+        let mut sim = Simulation::new();
+        sim.setup_world();
+        sim.start_wave();
+        if restart {
+            sim.restart_wave().unwrap();
+        }
+        let mut snapshot = sim.tick();
+        for _ in 0..200 {
+            snapshot = sim.tick();
+        }
+        snapshot
+            .entities
+            .iter()
+            .filter(|e| e.entity_type == EntityType::Missile)
+            .map(|e| (e.x, e.y))
+            .collect::<Vec<_>>()
+    };
+    assert_ne!(spawn_positions(false), spawn_positions(true));
+}
+
+#[test]
+fn restart_requires_a_wave() {
+    let mut sim = new_sim();
+    assert!(sim.restart_wave().is_err());
+}
+
+#[test]
+fn pause_only_applies_to_active_wave() {
+    let mut sim = new_sim();
+    assert!(!sim.pause());
+
+    sim.start_wave();
+    assert!(sim.pause());
+    assert!(sim.build_snapshot().paused);
+    sim.resume();
+    assert!(!sim.build_snapshot().paused);
+}
@@ -0,0 +1,199 @@
+use deterrence_lib::campaign::grading::WaveGrade;
+use deterrence_lib::ecs::components::*;
+use deterrence_lib::ecs::entity::EntityId;
+use deterrence_lib::engine::config;
+use deterrence_lib::engine::simulation::Simulation;
+use deterrence_lib::events::game_events::GameEvent;
+use deterrence_lib::state::campaign_state::CampaignState;
+use deterrence_lib::state::game_state::GamePhase;
+use deterrence_lib::systems::input_system::PlayerCommand;
+
+/// Default campaign with its world set up.
+fn new_sim() -> Simulation {
+    let mut sim = Simulation::new();
+    sim.setup_world();
+    sim
+}
+
+fn spawn_missile(sim: &mut Simulation, x: f32, y: f32, vx: f32, vy: f32) -> EntityId {
+    let id = sim.world.spawn();
+    sim.world.insert(id, Transform { x, y, rotation: 0.0 });
+    sim.world.insert(id, Velocity { vx, vy });
+    sim.world.insert(id, Ballistic {
+        drag_coefficient: config::MISSILE_DRAG_COEFF,
+        mass: config::MISSILE_MASS,
+        cross_section: config::MISSILE_CROSS_SECTION,
+    });
+    sim.world.insert(id, Warhead {
+        yield_force: config::WARHEAD_YIELD,
+        blast_radius_base: config::WARHEAD_BLAST_RADIUS,
+        warhead_type: WarheadType::Standard,
+    });
+    sim.world.insert(id, EntityMarker {
+        kind: EntityKind::Missile,
+    });
+    id
+}
+
+fn launch(sim: &mut Simulation, battery_id: u32, target_x: f32, target_y: f32) {
+    sim.push_command(PlayerCommand::LaunchInterceptor {
+        battery_id,
+        target_x,
+        target_y,
+        interceptor_type: InterceptorType::Standard,
+    });
+}
+
+fn run_wave_to_result(sim: &mut Simulation) {
+    for _ in 0..3000 {
+        if sim.phase != GamePhase::WaveActive {
+            break;
+        }
+        sim.tick();
+    }
+    assert_eq!(sim.phase, GamePhase::WaveResult);
+}
+
+// --- Wave Grading Tests ---
+
+#[test]
+fn completed_wave_is_graded_and_recorded() {
+    let mut sim = new_sim();
+    sim.start_wave();
+    run_wave_to_result(&mut sim);
+
+    let events = sim.drain_events();
+    let complete = events
+        .iter()
+        .find_map(|e| match e {
+            GameEvent::WaveComplete(c) => Some(c.clone()),
+            _ => None,
+        })
+        .expect("Wave should complete");
+
+    assert_eq!(sim.campaign.wave_scores.len(), 1);
+    let recorded = &sim.campaign.wave_scores[0];
+    assert_eq!(recorded.wave_number, 1);
+    assert_eq!(complete.grade, recorded.grade.as_str());
+    assert_eq!(complete.score, recorded.score);
+}
+
+#[test]
+fn undefended_wave_records_city_damage() {
+    let mut sim = new_sim();
+    sim.start_wave();
+    run_wave_to_result(&mut sim);
+
+    // No interceptors launched: every missile lands near a city
+    let recorded = &sim.campaign.wave_scores[0];
+    assert!(recorded.city_damage > 0.0);
+    assert!(recorded.grade > WaveGrade::A);
+}
+
+// --- Lifetime Stats Tests ---
+
+#[test]
+fn kills_are_credited_to_interceptor_type_through_chain_reactions() {
+    let mut sim = new_sim();
+    spawn_missile(&mut sim, 640.0, 500.0, 0.0, 0.0);
+    // Close enough to be caught by the first missile's chain reaction
+    spawn_missile(&mut sim, 640.0 + config::WARHEAD_BLAST_RADIUS * 0.3, 500.0, 0.0, 0.0);
+
+    let sw = sim.world.spawn();
+    sim.world.insert(sw, Transform { x: 640.0, y: 500.0, rotation: 0.0 });
+    sim.world.insert(sw, Shockwave {
+        radius: 20.0,
+        max_radius: 60.0,
+        force: 100.0,
+        expansion_rate: config::SHOCKWAVE_EXPANSION_RATE,
+        damage_applied: false,
+        source: Some(InterceptorType::Sprint),
+    });
+    sim.world.insert(sw, EntityMarker {
+        kind: EntityKind::Shockwave,
+    });
+
+    for _ in 0..30 {
+        sim.tick();
+    }
+    assert_eq!(sim.campaign.stats.kills_by_type.get(&InterceptorType::Sprint), Some(&2));
+}
+
+#[test]
+fn launches_income_and_spending_are_recorded() {
+    let mut sim = new_sim();
+    sim.start_wave();
+    launch(&mut sim, 0, 400.0, 500.0);
+    sim.tick();
+
+    sim.phase = GamePhase::Strategic;
+    sim.wave = None;
+    let income = sim.apply_wave_income();
+    sim.campaign.resources = 1000;
+    sim.restock_all_batteries().unwrap();
+
+    let stats = sim.campaign_stats();
+    assert_eq!(stats.interceptors_launched, 1);
+    assert_eq!(stats.by_type[0].interceptor_type, "Standard");
+    assert_eq!(stats.total_earned, income);
+    assert_eq!(stats.spent[0].category, "Restock");
+    assert_eq!(stats.total_spent, 1000 - sim.campaign.resources);
+}
+
+#[test]
+fn completed_wave_records_city_uptime() {
+    let mut sim = new_sim();
+    sim.start_wave();
+    run_wave_to_result(&mut sim);
+
+    let stats = &sim.campaign.stats;
+    assert_eq!(stats.waves_recorded, 1);
+    assert_eq!(stats.city_waves, sim.city_ids.len() as u32);
+    assert!(stats.city_waves_intact <= stats.city_waves);
+}
+
+#[test]
+fn saves_without_stats_are_backfilled_from_wave_history() {
+    let mut sim = new_sim();
+    sim.start_wave();
+    run_wave_to_result(&mut sim);
+    let destroyed = sim.campaign.wave_scores[0].missiles_destroyed;
+
+    let mut json = serde_json::to_value(sim.to_save_data("old")).unwrap();
+    json["campaign"].as_object_mut().unwrap().remove("stats");
+    let restored = Simulation::from_save_data(serde_json::from_value(json).unwrap());
+    assert_eq!(restored.campaign.stats.waves_recorded, 1);
+    assert_eq!(restored.campaign.stats.missiles_intercepted, destroyed);
+}
+
+#[test]
+fn saves_from_before_wave_history_load_as_partial_stats() {
+    // Only the fields the first save format had: no wave_scores, stats or signature
+    const ORIGINAL_FIELDS: [&str; 8] = [
+        "resources",
+        "owned_regions",
+        "regions",
+        "cost_table",
+        "total_waves_survived",
+        "city_healths",
+        "battery_ammo",
+        "tech_tree",
+    ];
+    let mut campaign = serde_json::to_value(CampaignState::default()).unwrap();
+    campaign.as_object_mut().unwrap().retain(|k, _| ORIGINAL_FIELDS.contains(&k.as_str()));
+    campaign["total_waves_survived"] = 6.into();
+    let json = serde_json::json!({
+        "campaign": campaign,
+        "wave_number": 6,
+        "seed": 42,
+        "timestamp": 0,
+        "slot_name": "old",
+    });
+
+    let sim = Simulation::from_save_data(serde_json::from_value(json).unwrap());
+    assert!(sim.campaign.wave_scores.is_empty());
+    let stats = sim.campaign_stats();
+    assert_eq!(stats.waves_recorded, 6);
+    assert!(stats.partial);
+    assert_eq!(stats.interception_rate, None);
+}
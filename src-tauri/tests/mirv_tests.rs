@@ -0,0 +1,131 @@
+use deterrence_lib::ecs::components::*;
+use deterrence_lib::ecs::entity::EntityId;
+use deterrence_lib::engine::config;
+use deterrence_lib::engine::simulation::Simulation;
+use deterrence_lib::events::game_events::GameEvent;
+use deterrence_lib::state::snapshot::EntityExtra;
+use deterrence_lib::systems::{collision, mirv_split};
+
+/// Default campaign with its world set up.
+fn new_sim() -> Simulation {
+    let mut sim = Simulation::new();
+    sim.setup_world();
+    sim
+}
+
+fn spawn_missile(sim: &mut Simulation, x: f32, y: f32, vx: f32, vy: f32) -> EntityId {
+    let id = sim.world.spawn();
+    sim.world.insert(id, Transform { x, y, rotation: 0.0 });
+    sim.world.insert(id, Velocity { vx, vy });
+    sim.world.insert(id, Ballistic {
+        drag_coefficient: config::MISSILE_DRAG_COEFF,
+        mass: config::MISSILE_MASS,
+        cross_section: config::MISSILE_CROSS_SECTION,
+    });
+    sim.world.insert(id, Warhead {
+        yield_force: config::WARHEAD_YIELD,
+        blast_radius_base: config::WARHEAD_BLAST_RADIUS,
+        warhead_type: WarheadType::Standard,
+    });
+    sim.world.insert(id, EntityMarker {
+        kind: EntityKind::Missile,
+    });
+    id
+}
+
+fn spawn_bus(sim: &mut Simulation, x: f32, y: f32, vy: f32, split_altitude: f32) -> EntityId {
+    let idx = spawn_missile(sim, x, y, 0.0, vy);
+    sim.world.insert(idx, MirvCarrier {
+        child_count: 3,
+        split_altitude,
+        spread_angle: config::MIRV_SPREAD_ANGLE,
+        estimated_split_altitude: None,
+    });
+    idx
+}
+
+// --- MIRV Bus Tests ---
+
+#[test]
+fn split_estimate_errs_high_and_converges() {
+    let far = mirv_split::estimate_split_altitude(700.0, 350.0);
+    let near = mirv_split::estimate_split_altitude(400.0, 350.0);
+    assert!(far > near && near > 350.0);
+    assert_eq!(mirv_split::estimate_split_altitude(350.0, 350.0), 350.0);
+}
+
+#[test]
+fn time_to_split_matches_free_fall() {
+    // Dropping from rest: t = sqrt(2h/g)
+    let t = mirv_split::time_to_split(450.0, 0.0, 350.0);
+    let expected = (2.0 * 100.0 / config::GRAVITY).sqrt();
+    assert!((t - expected).abs() < 1e-3);
+    assert_eq!(mirv_split::time_to_split(300.0, -10.0, 350.0), 0.0);
+}
+
+#[test]
+fn detected_bus_gets_split_estimate_and_countdown() {
+    let mut sim = new_sim();
+    // Within radar range of the homeland battery at x=160
+    let idx = spawn_bus(&mut sim, 300.0, 450.0, -20.0, 350.0);
+
+    let snapshot = sim.tick().clone();
+    let estimate = sim.world.mirv_carriers.get(idx).copied()
+        .unwrap()
+        .estimated_split_altitude
+        .expect("Detected bus should have an estimate");
+    assert!(estimate >= 350.0);
+
+    let entity = snapshot.entities.iter().find(|e| e.id == idx.index && e.generation == idx.generation).unwrap();
+    match &entity.extra {
+        Some(EntityExtra::Missile {
+            is_mirv,
+            split_countdown,
+            ..
+        }) => {
+            assert!(is_mirv);
+            assert!(split_countdown.unwrap() > 0.0);
+        }
+        other => panic!("Expected missile extra, got {other:?}"),
+    }
+}
+
+#[test]
+fn undetected_bus_has_no_estimate() {
+    let mut sim = new_sim();
+    // Far from every battery and above glow altitude
+    let idx = spawn_bus(&mut sim, 640.0, 700.0, 0.0, 350.0);
+    sim.tick();
+    assert!(sim.world.mirv_carriers.get(idx).unwrap().estimated_split_altitude.is_none());
+}
+
+#[test]
+fn killing_bus_before_split_emits_event_and_prevents_children() {
+    let mut sim = new_sim();
+    let idx = spawn_bus(&mut sim, 640.0, 500.0, 0.0, 350.0);
+
+    // Detonate an interceptor-strength shockwave right on the bus
+    let sw = sim.world.spawn();
+    sim.world.insert(sw, Transform { x: 640.0, y: 500.0, rotation: 0.0 });
+    sim.world.insert(sw, Shockwave {
+        radius: 20.0,
+        max_radius: 60.0,
+        force: 100.0,
+        expansion_rate: config::SHOCKWAVE_EXPANSION_RATE,
+        damage_applied: false,
+        source: None,
+    });
+    sim.world.insert(sw, EntityMarker {
+        kind: EntityKind::Shockwave,
+    });
+
+    let result = collision::run(&mut sim.world, 7, &mut Default::default());
+    let killed = result.events.iter().find_map(|e| match e {
+        GameEvent::MirvBusKilled(k) => Some(k.clone()),
+        _ => None,
+    });
+    let killed = killed.expect("Bus kill should emit MirvBusKilled");
+    assert_eq!((killed.carrier_id, killed.carrier_generation), (idx.index, idx.generation));
+    assert_eq!(killed.children_prevented, 3);
+    assert!(sim.world.mirv_carriers.get(idx).is_none());
+}
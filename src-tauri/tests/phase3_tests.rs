@@ -19,20 +19,18 @@ fn setup_world_creates_cities_and_batteries() {
 
     // Check cities have health
     for &id in &sim.city_ids {
-        let idx = id.index as usize;
-        let health = sim.world.healths[idx].unwrap();
+        let health = sim.world.healths.get(id).copied().unwrap();
         assert_eq!(health.current, config::CITY_MAX_HEALTH);
         assert_eq!(health.max, config::CITY_MAX_HEALTH);
-        let marker = sim.world.markers[idx].unwrap();
+        let marker = sim.world.markers.get(id).copied().unwrap();
         assert_eq!(marker.kind, EntityKind::City);
     }
 
     // Check batteries have ammo
     for &id in &sim.battery_ids {
-        let idx = id.index as usize;
-        let bs = sim.world.battery_states[idx].unwrap();
+        let bs = sim.world.battery_states.get(id).copied().unwrap();
         assert_eq!(bs.ammo, config::BATTERY_MAX_AMMO);
-        let marker = sim.world.markers[idx].unwrap();
+        let marker = sim.world.markers.get(id).copied().unwrap();
         assert_eq!(marker.kind, EntityKind::Battery);
     }
 }
@@ -75,8 +73,7 @@ fn wave_spawner_missiles_have_correct_components() {
 
     // Find the missile entity (not city, not battery)
     let missile_idx = sim.world.alive_entities().into_iter().find(|&idx| {
-        sim.world.markers[idx]
-            .as_ref()
+        sim.world.markers.get(idx)
             .is_some_and(|m| m.kind == EntityKind::Missile)
     });
 
@@ -84,11 +81,11 @@ fn wave_spawner_missiles_have_correct_components() {
     let idx = missile_idx.unwrap();
 
     // Missile should have all required components
-    assert!(sim.world.transforms[idx].is_some(), "Missile needs Transform");
-    assert!(sim.world.velocities[idx].is_some(), "Missile needs Velocity");
-    assert!(sim.world.ballistics[idx].is_some(), "Missile needs Ballistic");
-    assert!(sim.world.warheads[idx].is_some(), "Missile needs Warhead");
-    assert!(sim.world.markers[idx].is_some(), "Missile needs EntityMarker");
+    assert!(sim.world.transforms.contains(idx), "Missile needs Transform");
+    assert!(sim.world.velocities.contains(idx), "Missile needs Velocity");
+    assert!(sim.world.ballistics.contains(idx), "Missile needs Ballistic");
+    assert!(sim.world.warheads.contains(idx), "Missile needs Warhead");
+    assert!(sim.world.markers.contains(idx), "Missile needs EntityMarker");
 }
 
 // --- Input System Tests ---
@@ -99,7 +96,7 @@ fn launch_interceptor_spawns_entity_and_decrements_ammo() {
     sim.setup_world();
     sim.start_wave();
 
-    let initial_ammo = sim.world.battery_states[sim.battery_ids[0].index as usize]
+    let initial_ammo = sim.world.battery_states.get(sim.battery_ids[0])
         .unwrap()
         .ammo;
 
@@ -113,7 +110,7 @@ fn launch_interceptor_spawns_entity_and_decrements_ammo() {
     sim.tick();
 
     // Check ammo decremented
-    let ammo_after = sim.world.battery_states[sim.battery_ids[0].index as usize]
+    let ammo_after = sim.world.battery_states.get(sim.battery_ids[0])
         .unwrap()
         .ammo;
     assert_eq!(ammo_after, initial_ammo - 1);
@@ -124,8 +121,7 @@ fn launch_interceptor_spawns_entity_and_decrements_ammo() {
         .alive_entities()
         .iter()
         .filter(|&&idx| {
-            sim.world.markers[idx]
-                .as_ref()
+            sim.world.markers.get(idx)
                 .is_some_and(|m| m.kind == EntityKind::Interceptor)
         })
         .count();
@@ -139,11 +135,8 @@ fn launch_from_empty_battery_is_ignored() {
     sim.start_wave();
 
     // Exhaust all ammo
-    let bat_idx = sim.battery_ids[0].index as usize;
-    sim.world.battery_states[bat_idx] = Some(BatteryState {
-        ammo: 0,
-        max_ammo: config::BATTERY_MAX_AMMO,
-    });
+    let bat_idx = sim.battery_ids[0];
+    sim.world.battery_states.get_mut(bat_idx).unwrap().ammo = 0;
 
     sim.push_command(PlayerCommand::LaunchInterceptor {
         battery_id: 0,
@@ -160,8 +153,7 @@ fn launch_from_empty_battery_is_ignored() {
         .alive_entities()
         .iter()
         .filter(|&&idx| {
-            sim.world.markers[idx]
-                .as_ref()
+            sim.world.markers.get(idx)
                 .is_some_and(|m| m.kind == EntityKind::Interceptor)
         })
         .count();
@@ -177,24 +169,23 @@ fn missile_detonates_at_ground_level() {
 
     // Manually spawn a missile heading straight down near ground
     let id = sim.world.spawn();
-    let idx = id.index as usize;
-    sim.world.transforms[idx] = Some(Transform {
+    sim.world.insert(id, Transform {
         x: 400.0,
         y: config::GROUND_Y + 5.0,
         rotation: 0.0,
     });
-    sim.world.velocities[idx] = Some(Velocity { vx: 0.0, vy: -50.0 });
-    sim.world.ballistics[idx] = Some(Ballistic {
+    sim.world.insert(id, Velocity { vx: 0.0, vy: -50.0 });
+    sim.world.insert(id, Ballistic {
         drag_coefficient: 0.0,
         mass: 50.0,
         cross_section: 0.5,
     });
-    sim.world.warheads[idx] = Some(Warhead {
+    sim.world.insert(id, Warhead {
         yield_force: config::WARHEAD_YIELD,
         blast_radius_base: config::WARHEAD_BLAST_RADIUS,
         warhead_type: WarheadType::Standard,
     });
-    sim.world.markers[idx] = Some(EntityMarker {
+    sim.world.insert(id, EntityMarker {
         kind: EntityKind::Missile,
     });
 
@@ -205,9 +196,8 @@ fn missile_detonates_at_ground_level() {
 
     // The missile should be gone (detonated)
     assert!(
-        !sim.world.alive_entities().contains(&idx)
-            || sim.world.markers[idx]
-                .as_ref()
+        !sim.world.alive_entities().contains(&id)
+            || sim.world.markers.get(id)
                 .is_none_or(|m| m.kind != EntityKind::Missile),
         "Missile should be despawned after ground impact"
     );
@@ -218,8 +208,7 @@ fn missile_detonates_at_ground_level() {
         .alive_entities()
         .iter()
         .filter(|&&i| {
-            sim.world.markers[i]
-                .as_ref()
+            sim.world.markers.get(i)
                 .is_some_and(|m| m.kind == EntityKind::Shockwave)
         })
         .count();
@@ -241,46 +230,46 @@ fn shockwave_destroys_nearby_missile() {
 
     // Spawn a shockwave
     let sw_id = sim.world.spawn();
-    let sw_idx = sw_id.index as usize;
-    sim.world.transforms[sw_idx] = Some(Transform {
+    sim.world.insert(sw_id, Transform {
         x: 400.0,
         y: 400.0,
         rotation: 0.0,
     });
-    sim.world.shockwaves[sw_idx] = Some(Shockwave {
+    sim.world.insert(sw_id, Shockwave {
         radius: 50.0,
         max_radius: 60.0,
         force: 100.0,
         expansion_rate: config::SHOCKWAVE_EXPANSION_RATE,
         damage_applied: false,
+        source: None,
     });
-    sim.world.markers[sw_idx] = Some(EntityMarker {
+    sim.world.insert(sw_id, EntityMarker {
         kind: EntityKind::Shockwave,
     });
-    sim.world.lifetimes[sw_idx] = Some(Lifetime {
+    sim.world.insert(sw_id, Lifetime {
         remaining_ticks: 30,
     });
 
     // Spawn a missile within shockwave radius
     let ms_id = sim.world.spawn();
-    let ms_idx = ms_id.index as usize;
-    sim.world.transforms[ms_idx] = Some(Transform {
+    let ms_idx = ms_id;
+    sim.world.insert(ms_idx, Transform {
         x: 420.0,
         y: 400.0,
         rotation: 0.0,
     });
-    sim.world.velocities[ms_idx] = Some(Velocity { vx: 0.0, vy: -10.0 });
-    sim.world.ballistics[ms_idx] = Some(Ballistic {
+    sim.world.insert(ms_idx, Velocity { vx: 0.0, vy: -10.0 });
+    sim.world.insert(ms_idx, Ballistic {
         drag_coefficient: 0.0,
         mass: 50.0,
         cross_section: 0.5,
     });
-    sim.world.warheads[ms_idx] = Some(Warhead {
+    sim.world.insert(ms_idx, Warhead {
         yield_force: 80.0,
         blast_radius_base: 30.0,
         warhead_type: WarheadType::Standard,
     });
-    sim.world.markers[ms_idx] = Some(EntityMarker {
+    sim.world.insert(ms_idx, EntityMarker {
         kind: EntityKind::Missile,
     });
 
@@ -288,8 +277,7 @@ fn shockwave_destroys_nearby_missile() {
 
     // Missile should be destroyed
     let missile_alive = sim.world.alive_entities().iter().any(|&i| {
-        sim.world.markers[i]
-            .as_ref()
+        sim.world.markers.get(i)
             .is_some_and(|m| m.kind == EntityKind::Missile)
     });
     assert!(!missile_alive, "Missile should be destroyed by shockwave");
@@ -301,8 +289,7 @@ fn shockwave_destroys_nearby_missile() {
         .alive_entities()
         .iter()
         .filter(|&&i| {
-            sim.world.markers[i]
-                .as_ref()
+            sim.world.markers.get(i)
                 .is_some_and(|m| m.kind == EntityKind::Shockwave)
         })
         .count();
@@ -317,29 +304,28 @@ fn ground_impact_damages_nearby_city() {
     sim.setup_world();
 
     let city_id = sim.city_ids[0];
-    let city_idx = city_id.index as usize;
-    let city_x = sim.world.transforms[city_idx].unwrap().x;
+    let city_x = sim.world.transforms.get(city_id).unwrap().x;
 
     // Spawn a missile directly above the first city, close to ground
     let id = sim.world.spawn();
-    let idx = id.index as usize;
-    sim.world.transforms[idx] = Some(Transform {
+    let idx = id;
+    sim.world.insert(idx, Transform {
         x: city_x,
         y: config::GROUND_Y + 2.0,
         rotation: 0.0,
     });
-    sim.world.velocities[idx] = Some(Velocity { vx: 0.0, vy: -100.0 });
-    sim.world.ballistics[idx] = Some(Ballistic {
+    sim.world.insert(idx, Velocity { vx: 0.0, vy: -100.0 });
+    sim.world.insert(idx, Ballistic {
         drag_coefficient: 0.0,
         mass: 50.0,
         cross_section: 0.5,
     });
-    sim.world.warheads[idx] = Some(Warhead {
+    sim.world.insert(idx, Warhead {
         yield_force: config::WARHEAD_YIELD,
         blast_radius_base: config::WARHEAD_BLAST_RADIUS,
         warhead_type: WarheadType::Standard,
     });
-    sim.world.markers[idx] = Some(EntityMarker {
+    sim.world.insert(idx, EntityMarker {
         kind: EntityKind::Missile,
     });
 
@@ -348,7 +334,7 @@ fn ground_impact_damages_nearby_city() {
         sim.tick();
     }
 
-    let health = sim.world.healths[city_idx].unwrap();
+    let health = sim.world.healths.get(city_id).copied().unwrap();
     assert!(
         health.current < config::CITY_MAX_HEALTH,
         "City should have taken damage: health = {} (max = {})",
@@ -426,13 +412,12 @@ fn scripted_intercepts_produce_expected_kills() {
 
     // Find the missile
     let missile_idx = sim.world.alive_entities().into_iter().find(|&idx| {
-        sim.world.markers[idx]
-            .as_ref()
+        sim.world.markers.get(idx)
             .is_some_and(|m| m.kind == EntityKind::Missile)
     });
 
     if let Some(ms_idx) = missile_idx {
-        let ms_pos = sim.world.transforms[ms_idx].unwrap();
+        let ms_pos = sim.world.transforms.get(ms_idx).copied().unwrap();
 
         // Launch an interceptor at the missile's position
         sim.push_command(PlayerCommand::LaunchInterceptor {
@@ -462,23 +447,23 @@ fn shockwave_expands_to_max_radius() {
     let mut sim = Simulation::new();
 
     let sw_id = sim.world.spawn();
-    let sw_idx = sw_id.index as usize;
-    sim.world.transforms[sw_idx] = Some(Transform {
+    sim.world.insert(sw_id, Transform {
         x: 400.0,
         y: 400.0,
         rotation: 0.0,
     });
-    sim.world.shockwaves[sw_idx] = Some(Shockwave {
+    sim.world.insert(sw_id, Shockwave {
         radius: 0.0,
         max_radius: 60.0,
         force: 100.0,
         expansion_rate: config::SHOCKWAVE_EXPANSION_RATE,
         damage_applied: false,
+        source: None,
     });
-    sim.world.markers[sw_idx] = Some(EntityMarker {
+    sim.world.insert(sw_id, EntityMarker {
         kind: EntityKind::Shockwave,
     });
-    sim.world.lifetimes[sw_idx] = Some(Lifetime {
+    sim.world.insert(sw_id, Lifetime {
         remaining_ticks: config::SHOCKWAVE_LIFETIME_TICKS,
     });
 
@@ -487,7 +472,7 @@ fn shockwave_expands_to_max_radius() {
         sim.tick();
     }
 
-    let sw = sim.world.shockwaves[sw_idx].unwrap();
+    let sw = sim.world.shockwaves.get(sw_id).copied().unwrap();
     assert!(
         (sw.radius - 60.0).abs() < 1.0,
         "Shockwave should reach max radius: got {}",
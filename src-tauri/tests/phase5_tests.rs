@@ -26,11 +26,11 @@ fn wave_income_from_full_health_homeland() {
     let mut sim = Simulation::new();
     sim.setup_world();
 
-    // All 3 cities at full health, population 500 each, multiplier 1.0
-    // Income = (500 + 500 + 500) / 10 = 150
+    // All 3 cities at full health, population 500 each, multiplier 1.0, plains bonus 1.2
+    // Income = (600 + 600 + 600) / 10 = 180
     let income = sim.apply_wave_income();
-    assert_eq!(income, 150);
-    assert_eq!(sim.campaign.resources, 100 + 150); // started with 100
+    assert_eq!(income, 180);
+    assert_eq!(sim.campaign.resources, 100 + 180); // started with 100
     assert_eq!(sim.campaign.total_waves_survived, 1);
 }
 
@@ -41,13 +41,14 @@ fn wave_income_scales_with_city_damage() {
 
     // Damage first city to half health
     let city0 = sim.city_ids[0];
-    sim.world.healths[city0.index as usize].as_mut().unwrap().current = 50.0;
+    sim.world.healths.get_mut(city0).unwrap().current = 50.0;
     sim.sync_to_campaign();
 
     let income = sim.apply_wave_income();
-    // City 0: 500 * 0.5 = 250, City 1: 500 * 1.0 = 500, City 2: 500 * 1.0 = 500
-    // Total = 1250 / 10 = 125
-    assert_eq!(income, 125);
+    // With the 1.2 plains bonus:
+    // City 0: 500 * 0.5 * 1.2 = 300, City 1: 500 * 1.2 = 600, City 2: 500 * 1.2 = 600
+    // Total = 1500 / 10 = 150
+    assert_eq!(income, 150);
 }
 
 // --- Strategic Actions: Expand Region ---
@@ -154,16 +155,16 @@ fn restock_all_batteries_succeeds() {
     // Deplete both batteries
     let bat0 = sim.battery_ids[0];
     let bat1 = sim.battery_ids[1];
-    sim.world.battery_states[bat0.index as usize].as_mut().unwrap().ammo = 0;
-    sim.world.battery_states[bat1.index as usize].as_mut().unwrap().ammo = 0;
+    sim.world.battery_states.get_mut(bat0).unwrap().ammo = 0;
+    sim.world.battery_states.get_mut(bat1).unwrap().ammo = 0;
 
     let resources_before = sim.campaign.resources;
     let result = sim.restock_all_batteries();
     assert!(result.is_ok());
     assert_eq!(sim.campaign.resources, resources_before - 30); // 15 per battery * 2
 
-    let ammo0 = sim.world.battery_states[bat0.index as usize].unwrap().ammo;
-    let ammo1 = sim.world.battery_states[bat1.index as usize].unwrap().ammo;
+    let ammo0 = sim.world.battery_states.get(bat0).unwrap().ammo;
+    let ammo1 = sim.world.battery_states.get(bat1).unwrap().ammo;
     assert_eq!(ammo0, config::BATTERY_MAX_AMMO);
     assert_eq!(ammo1, config::BATTERY_MAX_AMMO);
 }
@@ -183,7 +184,7 @@ fn restock_all_batteries_fails_insufficient_resources() {
     sim.setup_world();
 
     let bat0 = sim.battery_ids[0];
-    sim.world.battery_states[bat0.index as usize].as_mut().unwrap().ammo = 0;
+    sim.world.battery_states.get_mut(bat0).unwrap().ammo = 0;
     sim.campaign.resources = 0;
 
     let result = sim.restock_all_batteries();
@@ -199,7 +200,7 @@ fn repair_city_succeeds() {
 
     // Damage city to 50 health
     let city0 = sim.city_ids[0];
-    sim.world.healths[city0.index as usize].as_mut().unwrap().current = 50.0;
+    sim.world.healths.get_mut(city0).unwrap().current = 50.0;
 
     let resources_before = sim.campaign.resources;
     let result = sim.repair_city(0);
@@ -208,7 +209,7 @@ fn repair_city_succeeds() {
     // Cost: 50 damage * 2 per hp = 100
     assert_eq!(sim.campaign.resources, resources_before - 100);
 
-    let health = sim.world.healths[city0.index as usize].unwrap().current;
+    let health = sim.world.healths.get(city0).unwrap().current;
     assert_eq!(health, config::CITY_MAX_HEALTH);
 }
 
@@ -260,11 +261,11 @@ fn rebuild_world_preserves_campaign_state() {
 
     // Damage a city
     let city0 = sim.city_ids[0];
-    sim.world.healths[city0.index as usize].as_mut().unwrap().current = 60.0;
+    sim.world.healths.get_mut(city0).unwrap().current = 60.0;
 
     // Deplete battery ammo
     let bat0 = sim.battery_ids[0];
-    sim.world.battery_states[bat0.index as usize].as_mut().unwrap().ammo = 3;
+    sim.world.battery_states.get_mut(bat0).unwrap().ammo = 3;
 
     // Sync to campaign, then rebuild
     sim.sync_to_campaign();
@@ -272,12 +273,12 @@ fn rebuild_world_preserves_campaign_state() {
 
     // City health should be preserved
     let city0 = sim.city_ids[0];
-    let health = sim.world.healths[city0.index as usize].unwrap().current;
+    let health = sim.world.healths.get(city0).unwrap().current;
     assert!((health - 60.0).abs() < 0.01, "City health should be preserved: got {health}");
 
     // Battery ammo should be preserved
     let bat0 = sim.battery_ids[0];
-    let ammo = sim.world.battery_states[bat0.index as usize].unwrap().ammo;
+    let ammo = sim.world.battery_states.get(bat0).unwrap().ammo;
     assert_eq!(ammo, 3, "Battery ammo should be preserved");
 }
 
@@ -356,7 +357,7 @@ fn default_campaign_matches_original_layout() {
     // Verify city positions match config::CITY_POSITIONS
     for (i, &(expected_x, expected_y)) in config::CITY_POSITIONS.iter().enumerate() {
         let city = sim.city_ids[i];
-        let t = sim.world.transforms[city.index as usize].unwrap();
+        let t = sim.world.transforms.get(city).copied().unwrap();
         assert!((t.x - expected_x).abs() < 0.01, "City {i} x mismatch");
         assert!((t.y - expected_y).abs() < 0.01, "City {i} y mismatch");
     }
@@ -364,7 +365,7 @@ fn default_campaign_matches_original_layout() {
     // Verify battery positions match config::BATTERY_POSITIONS
     for (i, &(expected_x, expected_y)) in config::BATTERY_POSITIONS.iter().enumerate() {
         let bat = sim.battery_ids[i];
-        let t = sim.world.transforms[bat.index as usize].unwrap();
+        let t = sim.world.transforms.get(bat).copied().unwrap();
         assert!((t.x - expected_x).abs() < 0.01, "Battery {i} x mismatch");
         assert!((t.y - expected_y).abs() < 0.01, "Battery {i} y mismatch");
     }
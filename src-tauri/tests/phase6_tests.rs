@@ -1,5 +1,6 @@
 use deterrence_lib::campaign::upgrades::UpgradeAxis;
 use deterrence_lib::ecs::components::*;
+use deterrence_lib::ecs::entity::EntityId;
 use deterrence_lib::ecs::world::World;
 use deterrence_lib::engine::config;
 use deterrence_lib::engine::simulation::Simulation;
@@ -56,14 +57,13 @@ fn launch_with_interceptor_type_uses_correct_profile() {
 
     // Find the spawned interceptor
     let interceptor_idx = sim.world.alive_entities().iter().find(|&&idx| {
-        sim.world.markers[idx]
-            .as_ref()
+        sim.world.markers.get(idx)
             .is_some_and(|m| m.kind == EntityKind::Interceptor)
     }).copied();
 
     assert!(interceptor_idx.is_some(), "Should have spawned interceptor");
     let idx = interceptor_idx.unwrap();
-    let interceptor = sim.world.interceptors[idx].as_ref().unwrap();
+    let interceptor = sim.world.interceptors.get(idx).unwrap();
     assert_eq!(interceptor.interceptor_type, InterceptorType::Sprint);
     assert_eq!(interceptor.thrust, config::SPRINT_THRUST);
     assert_eq!(interceptor.burn_time, config::SPRINT_BURN_TIME);
@@ -71,28 +71,28 @@ fn launch_with_interceptor_type_uses_correct_profile() {
 
 // --- MIRV Split Tests ---
 
-fn spawn_mirv_carrier(world: &mut World, x: f32, y: f32, vy: f32, split_altitude: f32, child_count: u32) -> usize {
+fn spawn_mirv_carrier(world: &mut World, x: f32, y: f32, vy: f32, split_altitude: f32, child_count: u32) -> EntityId {
     let id = world.spawn();
-    let idx = id.index as usize;
-    world.transforms[idx] = Some(Transform { x, y, rotation: 0.0 });
-    world.velocities[idx] = Some(Velocity { vx: 0.0, vy });
-    world.ballistics[idx] = Some(Ballistic {
+    world.insert(id, Transform { x, y, rotation: 0.0 });
+    world.insert(id, Velocity { vx: 0.0, vy });
+    world.insert(id, Ballistic {
         drag_coefficient: config::MISSILE_DRAG_COEFF,
         mass: config::MISSILE_MASS,
         cross_section: config::MISSILE_CROSS_SECTION,
     });
-    world.warheads[idx] = Some(Warhead {
+    world.insert(id, Warhead {
         yield_force: 0.0,
         blast_radius_base: 0.0,
         warhead_type: WarheadType::Mirv,
     });
-    world.markers[idx] = Some(EntityMarker { kind: EntityKind::Missile });
-    world.mirv_carriers[idx] = Some(MirvCarrier {
+    world.insert(id, EntityMarker { kind: EntityKind::Missile });
+    world.insert(id, MirvCarrier {
         child_count,
         split_altitude,
         spread_angle: config::MIRV_SPREAD_ANGLE,
+        estimated_split_altitude: None,
     });
-    idx
+    id
 }
 
 #[test]
@@ -106,7 +106,7 @@ fn mirv_splits_at_altitude() {
     assert_eq!(result.splits, 1, "Should have split");
     // No MIRV carriers should remain (carrier despawned, children are standard)
     let mirv_count = world.alive_entities().iter().filter(|&&idx| {
-        world.mirv_carriers[idx].is_some()
+        world.mirv_carriers.contains(idx)
     }).count();
     assert_eq!(mirv_count, 0, "Carrier should be despawned (no MIRV carriers remain)");
 }
@@ -124,7 +124,7 @@ fn mirv_produces_correct_child_count() {
 
     // All children should be missiles
     let missile_count = world.alive_entities().iter().filter(|&&idx| {
-        world.markers[idx].as_ref().is_some_and(|m| m.kind == EntityKind::Missile)
+        world.markers.get(idx).is_some_and(|m| m.kind == EntityKind::Missile)
     }).count();
     assert_eq!(missile_count, 4);
 }
@@ -136,12 +136,12 @@ fn mirv_children_have_warheads() {
     deterrence_lib::systems::mirv_split::run(&mut world, 0);
 
     for &idx in &world.alive_entities() {
-        if world.markers[idx].as_ref().is_some_and(|m| m.kind == EntityKind::Missile) {
-            assert!(world.warheads[idx].is_some(), "Child should have warhead");
-            let wh = world.warheads[idx].unwrap();
+        if world.markers.get(idx).is_some_and(|m| m.kind == EntityKind::Missile) {
+            assert!(world.warheads.contains(idx), "Child should have warhead");
+            let wh = world.warheads.get(idx).copied().unwrap();
             assert_eq!(wh.warhead_type, WarheadType::Standard, "Children should be standard");
             assert!(wh.yield_force > 0.0, "Children should have yield");
-            assert!(world.mirv_carriers[idx].is_none(), "Children should NOT be MIRV carriers");
+            assert!(world.mirv_carriers.get(idx).is_none(), "Children should NOT be MIRV carriers");
         }
     }
 }
@@ -153,8 +153,8 @@ fn mirv_children_have_spread_velocities() {
     deterrence_lib::systems::mirv_split::run(&mut world, 0);
 
     let velocities: Vec<(f32, f32)> = world.alive_entities().iter().filter_map(|&idx| {
-        if world.markers[idx].as_ref().is_some_and(|m| m.kind == EntityKind::Missile) {
-            world.velocities[idx].as_ref().map(|v| (v.vx, v.vy))
+        if world.markers.get(idx).is_some_and(|m| m.kind == EntityKind::Missile) {
+            world.velocities.get(idx).map(|v| (v.vx, v.vy))
         } else {
             None
         }
@@ -210,43 +210,41 @@ fn mirv_split_emits_event() {
 
 // --- Chain Reaction / Dual-Zone Collision Tests ---
 
-fn spawn_shockwave(world: &mut World, x: f32, y: f32, radius: f32, max_radius: f32, force: f32) -> usize {
+fn spawn_shockwave(world: &mut World, x: f32, y: f32, radius: f32, max_radius: f32, force: f32) -> EntityId {
     let id = world.spawn();
-    let idx = id.index as usize;
-    world.transforms[idx] = Some(Transform { x, y, rotation: 0.0 });
-    world.shockwaves[idx] = Some(Shockwave {
+    world.insert(id, Transform { x, y, rotation: 0.0 });
+    world.insert(id, Shockwave {
         radius,
         max_radius,
         force,
         expansion_rate: config::SHOCKWAVE_EXPANSION_RATE,
         damage_applied: false,
+        source: None,
     });
-    world.markers[idx] = Some(EntityMarker { kind: EntityKind::Shockwave });
-    world.lifetimes[idx] = Some(Lifetime { remaining_ticks: config::SHOCKWAVE_LIFETIME_TICKS });
-    idx
+    world.insert(id, EntityMarker { kind: EntityKind::Shockwave });
+    world.insert(id, Lifetime { remaining_ticks: config::SHOCKWAVE_LIFETIME_TICKS });
+    id
 }
 
-fn spawn_missile(world: &mut World, x: f32, y: f32, vx: f32, vy: f32) -> usize {
+fn spawn_missile(world: &mut World, x: f32, y: f32, vx: f32, vy: f32) -> EntityId {
     let id = world.spawn();
-    let idx = id.index as usize;
-    world.transforms[idx] = Some(Transform { x, y, rotation: 0.0 });
-    world.velocities[idx] = Some(Velocity { vx, vy });
-    world.warheads[idx] = Some(Warhead {
+    world.insert(id, Transform { x, y, rotation: 0.0 });
+    world.insert(id, Velocity { vx, vy });
+    world.insert(id, Warhead {
         yield_force: config::WARHEAD_YIELD,
         blast_radius_base: config::WARHEAD_BLAST_RADIUS,
         warhead_type: WarheadType::Standard,
     });
-    world.markers[idx] = Some(EntityMarker { kind: EntityKind::Missile });
-    idx
+    world.insert(id, EntityMarker { kind: EntityKind::Missile });
+    id
 }
 
-fn spawn_interceptor_entity(world: &mut World, x: f32, y: f32, vx: f32, vy: f32) -> usize {
+fn spawn_interceptor_entity(world: &mut World, x: f32, y: f32, vx: f32, vy: f32) -> EntityId {
     let id = world.spawn();
-    let idx = id.index as usize;
-    world.transforms[idx] = Some(Transform { x, y, rotation: 0.0 });
-    world.velocities[idx] = Some(Velocity { vx, vy });
-    world.markers[idx] = Some(EntityMarker { kind: EntityKind::Interceptor });
-    world.interceptors[idx] = Some(Interceptor {
+    world.insert(id, Transform { x, y, rotation: 0.0 });
+    world.insert(id, Velocity { vx, vy });
+    world.insert(id, EntityMarker { kind: EntityKind::Interceptor });
+    world.insert(id, Interceptor {
         interceptor_type: InterceptorType::Standard,
         thrust: config::INTERCEPTOR_THRUST,
         burn_time: config::INTERCEPTOR_BURN_TIME,
@@ -255,14 +253,16 @@ fn spawn_interceptor_entity(world: &mut World, x: f32, y: f32, vx: f32, vy: f32)
         battery_id: 0,
         target_x: x,
         target_y: y,
+        aim_x: x,
+        aim_y: y,
         proximity_fuse_radius: 0.0,
     });
-    world.warheads[idx] = Some(Warhead {
+    world.insert(id, Warhead {
         yield_force: config::WARHEAD_YIELD,
         blast_radius_base: config::WARHEAD_BLAST_RADIUS,
         warhead_type: WarheadType::Standard,
     });
-    idx
+    id
 }
 
 #[test]
@@ -273,7 +273,7 @@ fn shockwave_destroys_interceptor_in_inner_zone() {
     // Interceptor at (410, 400) — distance=10, well inside destroy radius (50*0.7=35)
     let intc_idx = spawn_interceptor_entity(&mut world, 410.0, 400.0, 0.0, 0.0);
 
-    let result = deterrence_lib::systems::collision::run(&mut world, 0);
+    let result = deterrence_lib::systems::collision::run(&mut world, 0, &mut Default::default());
 
     assert_eq!(result.interceptors_destroyed, 1, "Interceptor in inner zone should be destroyed");
     assert!(!world.alive_entities().contains(&intc_idx), "Interceptor should be despawned");
@@ -285,7 +285,7 @@ fn destroyed_interceptor_does_not_chain_react() {
     spawn_shockwave(&mut world, 400.0, 400.0, 50.0, 60.0, 100.0);
     spawn_interceptor_entity(&mut world, 410.0, 400.0, 0.0, 0.0);
 
-    let result = deterrence_lib::systems::collision::run(&mut world, 0);
+    let result = deterrence_lib::systems::collision::run(&mut world, 0, &mut Default::default());
 
     // No detonation event should be emitted for the interceptor
     assert!(result.events.is_empty(), "Destroyed interceptor should NOT emit chain events");
@@ -294,7 +294,7 @@ fn destroyed_interceptor_does_not_chain_react() {
 
     // Only the original shockwave should remain (no chain shockwave spawned)
     let sw_count = world.alive_entities().iter().filter(|&&idx| {
-        world.markers[idx].as_ref().is_some_and(|m| m.kind == EntityKind::Shockwave)
+        world.markers.get(idx).is_some_and(|m| m.kind == EntityKind::Shockwave)
     }).count();
     assert_eq!(sw_count, 1, "Only original shockwave should remain, no chain reaction from interceptor");
 }
@@ -308,15 +308,15 @@ fn missile_in_edge_zone_gets_deflected() {
     // Missile at (440, 400) — distance=40, in deflect zone (35 < 40 < 50)
     let ms_idx = spawn_missile(&mut world, 440.0, 400.0, 0.0, -50.0);
 
-    let vx_before = world.velocities[ms_idx].as_ref().unwrap().vx;
+    let vx_before = world.velocities.get(ms_idx).unwrap().vx;
 
-    let result = deterrence_lib::systems::collision::run(&mut world, 0);
+    let result = deterrence_lib::systems::collision::run(&mut world, 0, &mut Default::default());
 
     assert_eq!(result.missiles_destroyed, 0, "Missile in deflect zone should NOT be destroyed");
     assert!(world.alive_entities().contains(&ms_idx), "Missile should still be alive");
 
     // Velocity should have been nudged in the +x direction (away from shockwave center)
-    let vx_after = world.velocities[ms_idx].as_ref().unwrap().vx;
+    let vx_after = world.velocities.get(ms_idx).unwrap().vx;
     assert!(vx_after > vx_before, "Missile should be pushed away (vx increased): before={}, after={}", vx_before, vx_after);
 }
 
@@ -328,10 +328,10 @@ fn deflection_pushes_entity_away_from_center() {
     // Missile above the shockwave center, in deflect zone
     let ms_idx = spawn_missile(&mut world, 400.0, 440.0, 0.0, -50.0);
 
-    deterrence_lib::systems::collision::run(&mut world, 0);
+    deterrence_lib::systems::collision::run(&mut world, 0, &mut Default::default());
 
     // Should be pushed in +y direction (away from shockwave center below it)
-    let vy_after = world.velocities[ms_idx].as_ref().unwrap().vy;
+    let vy_after = world.velocities.get(ms_idx).unwrap().vy;
     assert!(vy_after > -50.0, "Missile should have vy pushed upward (away from center): {}", vy_after);
 }
 
@@ -342,8 +342,8 @@ fn deflection_is_deterministic() {
         let mut world = World::new();
         spawn_shockwave(&mut world, 400.0, 400.0, 50.0, 60.0, 100.0);
         let ms_idx = spawn_missile(&mut world, 440.0, 400.0, 10.0, -30.0);
-        deterrence_lib::systems::collision::run(&mut world, 0);
-        let vel = world.velocities[ms_idx].as_ref().unwrap();
+        deterrence_lib::systems::collision::run(&mut world, 0, &mut Default::default());
+        let vel = world.velocities.get(ms_idx).unwrap();
         (vel.vx, vel.vy)
     };
     let (vx1, vy1) = run();
@@ -368,14 +368,14 @@ fn multi_step_cascade_over_ticks() {
     spawn_missile(&mut world, 420.0, 400.0, 0.0, -50.0);
 
     // First pass: original shockwave destroys missile A, spawning chain shockwave at (402, 400)
-    let result1 = deterrence_lib::systems::collision::run(&mut world, 0);
+    let result1 = deterrence_lib::systems::collision::run(&mut world, 0, &mut Default::default());
     assert_eq!(result1.missiles_destroyed, 1, "First pass should destroy only missile A");
 
     // Chain shockwave starts at radius 0 — expand it over multiple ticks
     let mut total_destroyed = result1.missiles_destroyed;
     for tick in 1..30 {
         deterrence_lib::systems::shockwave_system::run(&mut world);
-        let r = deterrence_lib::systems::collision::run(&mut world, tick);
+        let r = deterrence_lib::systems::collision::run(&mut world, tick, &mut Default::default());
         total_destroyed += r.missiles_destroyed;
         if total_destroyed >= 2 { break; }
     }
@@ -405,8 +405,8 @@ fn area_denial_shockwave_lingers() {
         sim.tick();
         // Look for a shockwave
         for &idx in &sim.world.alive_entities() {
-            if sim.world.markers[idx].as_ref().is_some_and(|m| m.kind == EntityKind::Shockwave)
-                && let Some(lt) = &sim.world.lifetimes[idx]
+            if sim.world.markers.get(idx).is_some_and(|m| m.kind == EntityKind::Shockwave)
+                && let Some(lt) = sim.world.lifetimes.get(idx)
                 && lt.remaining_ticks > config::SHOCKWAVE_LIFETIME_TICKS
             {
                 shockwave_found = true;
@@ -444,12 +444,12 @@ fn launch_uses_upgraded_stats() {
     sim.tick();
 
     let interceptor_idx = sim.world.alive_entities().iter().find(|&&idx| {
-        sim.world.markers[idx].as_ref().is_some_and(|m| m.kind == EntityKind::Interceptor)
+        sim.world.markers.get(idx).is_some_and(|m| m.kind == EntityKind::Interceptor)
     }).copied();
 
     assert!(interceptor_idx.is_some(), "Should have spawned interceptor");
     let idx = interceptor_idx.unwrap();
-    let interceptor = sim.world.interceptors[idx].as_ref().unwrap();
+    let interceptor = sim.world.interceptors.get(idx).unwrap();
 
     let base_thrust = config::INTERCEPTOR_THRUST;
     let expected_thrust = base_thrust * 1.15;
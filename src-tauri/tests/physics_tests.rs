@@ -1,27 +1,27 @@
 use deterrence_lib::ecs::components::*;
+use deterrence_lib::ecs::entity::EntityId;
 use deterrence_lib::ecs::world::World;
 use deterrence_lib::engine::config;
 use deterrence_lib::engine::simulation::Simulation;
 
 /// Helper: spawn a ballistic missile at (x, y) with velocity (vx, vy)
-fn spawn_missile(world: &mut World, x: f32, y: f32, vx: f32, vy: f32) -> usize {
+fn spawn_missile(world: &mut World, x: f32, y: f32, vx: f32, vy: f32) -> EntityId {
     let id = world.spawn();
-    let idx = id.index as usize;
-    world.transforms[idx] = Some(Transform {
+    world.insert(id, Transform {
         x,
         y,
         rotation: 0.0,
     });
-    world.velocities[idx] = Some(Velocity { vx, vy });
-    world.ballistics[idx] = Some(Ballistic {
+    world.insert(id, Velocity { vx, vy });
+    world.insert(id, Ballistic {
         drag_coefficient: 0.0, // no drag for pure ballistic tests
         mass: 100.0,
         cross_section: 0.1,
     });
-    world.markers[idx] = Some(EntityMarker {
+    world.insert(id, EntityMarker {
         kind: EntityKind::Missile,
     });
-    idx
+    id
 }
 
 /// Helper: spawn a missile with drag
@@ -32,24 +32,23 @@ fn spawn_missile_with_drag(
     vx: f32,
     vy: f32,
     cd: f32,
-) -> usize {
+) -> EntityId {
     let id = world.spawn();
-    let idx = id.index as usize;
-    world.transforms[idx] = Some(Transform {
+    world.insert(id, Transform {
         x,
         y,
         rotation: 0.0,
     });
-    world.velocities[idx] = Some(Velocity { vx, vy });
-    world.ballistics[idx] = Some(Ballistic {
+    world.insert(id, Velocity { vx, vy });
+    world.insert(id, Ballistic {
         drag_coefficient: cd,
         mass: 100.0,
         cross_section: 0.5,
     });
-    world.markers[idx] = Some(EntityMarker {
+    world.insert(id, EntityMarker {
         kind: EntityKind::Missile,
     });
-    idx
+    id
 }
 
 /// Helper: spawn an interceptor
@@ -59,21 +58,20 @@ fn spawn_interceptor(
     y: f32,
     target_x: f32,
     target_y: f32,
-) -> usize {
+) -> EntityId {
     let id = world.spawn();
-    let idx = id.index as usize;
-    world.transforms[idx] = Some(Transform {
+    world.insert(id, Transform {
         x,
         y,
         rotation: 0.0,
     });
-    world.velocities[idx] = Some(Velocity { vx: 0.0, vy: 0.0 });
-    world.ballistics[idx] = Some(Ballistic {
+    world.insert(id, Velocity { vx: 0.0, vy: 0.0 });
+    world.insert(id, Ballistic {
         drag_coefficient: 0.0,
         mass: 50.0,
         cross_section: 0.05,
     });
-    world.interceptors[idx] = Some(Interceptor {
+    world.insert(id, Interceptor {
         interceptor_type: InterceptorType::Standard,
         thrust: config::INTERCEPTOR_THRUST,
         burn_time: config::INTERCEPTOR_BURN_TIME,
@@ -82,12 +80,14 @@ fn spawn_interceptor(
         battery_id: 0,
         target_x,
         target_y,
+        aim_x: target_x,
+        aim_y: target_y,
         proximity_fuse_radius: 0.0,
     });
-    world.markers[idx] = Some(EntityMarker {
+    world.insert(id, EntityMarker {
         kind: EntityKind::Interceptor,
     });
-    idx
+    id
 }
 
 #[test]
@@ -103,8 +103,8 @@ fn freefall_matches_kinematics() {
         sim.tick();
     }
 
-    let transform = sim.world.transforms[idx].unwrap();
-    let vel = sim.world.velocities[idx].unwrap();
+    let transform = sim.world.transforms.get(idx).copied().unwrap();
+    let vel = sim.world.velocities.get(idx).copied().unwrap();
 
     // After 1 second of freefall: vy ≈ -9.81 m/s
     let expected_vy = -config::GRAVITY * (ticks as f32) * config::DT;
@@ -144,7 +144,7 @@ fn horizontal_projectile_arcs() {
     // time to peak ≈ vy/g = 35.4/9.81 ≈ 3.6s = 216 ticks, full flight ≈ 432 ticks
     for tick in 0..500 {
         sim.tick();
-        if let Some(t) = sim.world.transforms[idx] {
+        if let Some(t) = sim.world.transforms.get(idx).copied() {
             if t.y > max_height {
                 max_height = t.y;
                 peak_tick = tick;
@@ -180,14 +180,14 @@ fn drag_reduces_speed() {
         0.5, // significant drag
     );
 
-    let initial_vx = sim.world.velocities[idx].unwrap().vx;
+    let initial_vx = sim.world.velocities.get(idx).unwrap().vx;
 
     // Run 30 ticks (0.5 seconds)
     for _ in 0..30 {
         sim.tick();
     }
 
-    if let Some(vel) = sim.world.velocities[idx] {
+    if let Some(vel) = sim.world.velocities.get(idx).copied() {
         assert!(
             vel.vx.abs() < initial_vx.abs(),
             "Drag should reduce horizontal speed: initial={initial_vx}, current={}",
@@ -225,8 +225,8 @@ fn drag_stronger_at_low_altitude() {
         sim_high.tick();
     }
 
-    let vx_low = sim_low.world.velocities[idx_low].unwrap().vx;
-    let vx_high = sim_high.world.velocities[idx_high].unwrap().vx;
+    let vx_low = sim_low.world.velocities.get(idx_low).unwrap().vx;
+    let vx_high = sim_high.world.velocities.get(idx_high).unwrap().vx;
 
     assert!(
         vx_low < vx_high,
@@ -244,8 +244,8 @@ fn thrust_accelerates_interceptor() {
         sim.tick();
     }
 
-    let vel = sim.world.velocities[idx].unwrap();
-    let transform = sim.world.transforms[idx].unwrap();
+    let vel = sim.world.velocities.get(idx).copied().unwrap();
+    let transform = sim.world.transforms.get(idx).copied().unwrap();
 
     // Interceptor should have gained velocity toward target
     let speed = (vel.vx * vel.vx + vel.vy * vel.vy).sqrt();
@@ -270,7 +270,7 @@ fn interceptor_goes_ballistic_after_burn() {
         sim.tick();
     }
 
-    let interceptor = sim.world.interceptors[idx].unwrap();
+    let interceptor = sim.world.interceptors.get(idx).copied().unwrap();
     assert!(
         interceptor.burn_remaining <= 0.0,
         "Burn should be exhausted: remaining = {}",
@@ -278,9 +278,9 @@ fn interceptor_goes_ballistic_after_burn() {
     );
 
     // Record velocity, tick once more, check gravity is pulling it down
-    let vel_before = sim.world.velocities[idx].unwrap();
+    let vel_before = sim.world.velocities.get(idx).copied().unwrap();
     sim.tick();
-    let vel_after = sim.world.velocities[idx].unwrap();
+    let vel_after = sim.world.velocities.get(idx).copied().unwrap();
 
     // vy should decrease (gravity pulling down) since no more thrust
     assert!(
@@ -294,13 +294,12 @@ fn cleanup_removes_oob_entities() {
     let mut sim = Simulation::new();
     // Spawn entity way out of bounds
     let id = sim.world.spawn();
-    let idx = id.index as usize;
-    sim.world.transforms[idx] = Some(Transform {
+    sim.world.insert(id, Transform {
         x: -500.0,
         y: -500.0,
         rotation: 0.0,
     });
-    sim.world.markers[idx] = Some(EntityMarker {
+    sim.world.insert(id, EntityMarker {
         kind: EntityKind::Missile,
     });
 
@@ -317,16 +316,15 @@ fn cleanup_removes_oob_entities() {
 fn cleanup_removes_expired_entities() {
     let mut sim = Simulation::new();
     let id = sim.world.spawn();
-    let idx = id.index as usize;
-    sim.world.transforms[idx] = Some(Transform {
+    sim.world.insert(id, Transform {
         x: 400.0,
         y: 400.0,
         rotation: 0.0,
     });
-    sim.world.lifetimes[idx] = Some(Lifetime {
+    sim.world.insert(id, Lifetime {
         remaining_ticks: 3,
     });
-    sim.world.markers[idx] = Some(EntityMarker {
+    sim.world.insert(id, EntityMarker {
         kind: EntityKind::Shockwave,
     });
 
@@ -378,16 +376,17 @@ fn projectile_45_degree_range() {
     let x0 = 100.0;
     let idx = spawn_missile(&mut sim.world, x0, config::GROUND_Y, vx, vy);
 
-    // Run until it falls back to ground
+    // Run until it falls back to ground; the impact despawns it, so keep its last position
+    let mut final_x = x0;
     for tick in 0..2000 {
         sim.tick();
-        let y = sim.world.transforms[idx].unwrap().y;
-        if y <= config::GROUND_Y && tick > 10 {
+        let Some(t) = sim.world.transforms.get(idx) else { break };
+        final_x = t.x;
+        if t.y <= config::GROUND_Y && tick > 10 {
             break;
         }
     }
 
-    let final_x = sim.world.transforms[idx].unwrap().x;
     let range = final_x - x0;
     let expected_range = speed * speed / config::GRAVITY;
 
@@ -0,0 +1,94 @@
+use deterrence_lib::ecs::components::*;
+use deterrence_lib::ecs::entity::EntityId;
+use deterrence_lib::engine::config;
+use deterrence_lib::engine::simulation::Simulation;
+
+/// Default campaign with its world set up.
+fn new_sim() -> Simulation {
+    let mut sim = Simulation::new();
+    sim.setup_world();
+    sim
+}
+
+fn spawn_missile(sim: &mut Simulation, x: f32, y: f32, vx: f32, vy: f32) -> EntityId {
+    let id = sim.world.spawn();
+    sim.world.insert(id, Transform { x, y, rotation: 0.0 });
+    sim.world.insert(id, Velocity { vx, vy });
+    sim.world.insert(id, Ballistic {
+        drag_coefficient: config::MISSILE_DRAG_COEFF,
+        mass: config::MISSILE_MASS,
+        cross_section: config::MISSILE_CROSS_SECTION,
+    });
+    sim.world.insert(id, Warhead {
+        yield_force: config::WARHEAD_YIELD,
+        blast_radius_base: config::WARHEAD_BLAST_RADIUS,
+        warhead_type: WarheadType::Standard,
+    });
+    sim.world.insert(id, EntityMarker {
+        kind: EntityKind::Missile,
+    });
+    id
+}
+
+// --- Impact Prediction Tests ---
+
+#[test]
+fn predict_impacts_covers_detected_missiles() {
+    let mut sim = new_sim();
+    let idx = spawn_missile(&mut sim, 300.0, 300.0, 10.0, -40.0);
+    sim.world.insert(idx, Detected {
+        by_radar: true,
+        by_glow: false,
+    });
+
+    let predictions = sim.predict_impacts();
+    assert_eq!(predictions.impacts.len(), 1);
+    let impact = &predictions.impacts[0];
+    assert_eq!((impact.entity_id, impact.generation), (idx.index, idx.generation));
+    assert_eq!(impact.y, config::GROUND_Y);
+    assert!(impact.x > 300.0, "Missile moving right should land right of its position");
+    assert!(impact.time_to_impact > 0.0);
+}
+
+#[test]
+fn predict_impacts_skips_undetected_missiles() {
+    let mut sim = new_sim();
+    spawn_missile(&mut sim, 300.0, 300.0, 10.0, -40.0);
+
+    assert!(sim.predict_impacts().impacts.is_empty());
+}
+
+#[test]
+fn predicted_impact_matches_simulated_impact() {
+    let mut sim = new_sim();
+    sim.start_wave();
+    // Hold off the spawner so only our missile is in flight
+    sim.wave.as_mut().unwrap().spawn_timer = 10_000;
+    let idx = spawn_missile(&mut sim, 500.0, 400.0, 20.0, -30.0);
+    sim.world.insert(idx, Detected {
+        by_radar: true,
+        by_glow: false,
+    });
+    let predicted = sim.predict_impacts().impacts[0].clone();
+
+    let mut impact_x = None;
+    for _ in 0..2000 {
+        sim.tick();
+        for event in sim.drain_events() {
+            if let deterrence_lib::events::game_events::GameEvent::Impact(e) = event {
+                impact_x = Some(e.x);
+            }
+        }
+        if impact_x.is_some() {
+            break;
+        }
+    }
+
+    let impact_x = impact_x.expect("Missile should hit the ground");
+    assert!(
+        (impact_x - predicted.x).abs() < 2.0,
+        "Predicted x {} should match simulated x {}",
+        predicted.x,
+        impact_x
+    );
+}
@@ -0,0 +1,183 @@
+use deterrence_lib::campaign::custom_map::CustomMap;
+use deterrence_lib::campaign::difficulty::Difficulty;
+use deterrence_lib::campaign::territory::{define_regions, TheaterBounds};
+use deterrence_lib::ecs::components::*;
+use deterrence_lib::engine::config;
+use deterrence_lib::engine::simulation::Simulation;
+use deterrence_lib::persistence::profile::PlayerProfile;
+use deterrence_lib::state::campaign_state::AvailableAction;
+use deterrence_lib::state::campaign_state::CampaignState;
+use deterrence_lib::state::game_state::GamePhase;
+
+/// `campaign` with its world set up, on a fixed seed.
+fn campaign_sim(campaign: CampaignState) -> Simulation {
+    let mut sim = Simulation::new_with_campaign(campaign, 42);
+    sim.setup_world();
+    sim
+}
+
+/// Endless campaign about to play `wave_number`.
+fn endless_sim(wave_number: u32) -> Simulation {
+    let mut sim = campaign_sim(CampaignState::with_profile(&PlayerProfile::default(), true));
+    sim.wave_number = wave_number;
+    sim
+}
+
+fn battery_state(sim: &Simulation, battery_id: usize) -> BatteryState {
+    *sim.world.battery_states.get(sim.battery_ids[battery_id]).unwrap()
+}
+
+// --- Endless Mode & Prestige Tests ---
+
+#[test]
+fn endless_mode_outscales_campaign_late() {
+    let endless = endless_sim(60);
+    let mut normal = Simulation::new();
+    normal.setup_world();
+    normal.wave_number = 60;
+
+    let e = endless.preview_next_wave();
+    let n = normal.preview_next_wave();
+    assert!(
+        e.missile_count > n.missile_count,
+        "Endless wave 61 ({}) should outnumber campaign wave 61 ({})",
+        e.missile_count,
+        n.missile_count
+    );
+}
+
+#[test]
+fn prestige_buffs_apply_to_spawned_batteries() {
+    let profile = PlayerProfile {
+        prestige_level: 2,
+        best_waves_survived: 20,
+    };
+    let mut sim = Simulation::new_with_campaign(CampaignState::with_profile(&profile, false), 42);
+    sim.setup_world();
+    let bs = battery_state(&sim, 0);
+    assert_eq!(bs.max_ammo, config::BATTERY_MAX_AMMO + 2 * config::PRESTIGE_AMMO_PER_LEVEL);
+    assert_eq!(bs.ammo, bs.max_ammo);
+}
+
+#[test]
+fn prestige_reset_requires_enough_waves() {
+    let mut sim = endless_sim(3);
+    sim.campaign.total_waves_survived = config::PRESTIGE_MIN_WAVES - 1;
+    let mut profile = PlayerProfile::default();
+    assert!(sim.prestige_reset(&mut profile).is_err());
+    assert_eq!(profile.prestige_level, 0);
+    assert!(!sim
+        .build_campaign_snapshot()
+        .available_actions
+        .iter()
+        .any(|a| matches!(a, AvailableAction::Prestige { .. })));
+}
+
+#[test]
+fn prestige_reset_restarts_campaign_with_buffs() {
+    let mut sim = endless_sim(config::PRESTIGE_MIN_WAVES);
+    sim.campaign.total_waves_survived = config::PRESTIGE_MIN_WAVES;
+    sim.campaign.resources = 5;
+    assert!(sim
+        .build_campaign_snapshot()
+        .available_actions
+        .iter()
+        .any(|a| matches!(a, AvailableAction::Prestige { next_level: 1 })));
+
+    let mut profile = PlayerProfile::default();
+    sim.prestige_reset(&mut profile).unwrap();
+
+    assert_eq!(profile.prestige_level, 1);
+    assert_eq!(profile.best_waves_survived, config::PRESTIGE_MIN_WAVES);
+    assert_eq!(sim.wave_number, 0);
+    assert_eq!(sim.phase, GamePhase::Strategic);
+    assert!(sim.campaign.endless, "Prestige keeps the endless setting");
+    assert_eq!(sim.campaign.total_waves_survived, 0);
+    assert_eq!(sim.campaign.resources, 100 + config::PRESTIGE_RESOURCES_PER_LEVEL);
+    assert_eq!(battery_state(&sim, 0).max_ammo, config::BATTERY_MAX_AMMO + config::PRESTIGE_AMMO_PER_LEVEL);
+}
+
+#[test]
+fn prestige_reset_keeps_the_custom_map() {
+    let mut regions = define_regions();
+    regions.truncate(2);
+    regions[0].name = "Atoll".into();
+    let (home, neighbour) = (regions[0].id, regions[1].id);
+    regions[0].adjacent.retain(|id| *id == neighbour);
+    regions[1].adjacent.retain(|id| *id == home);
+    let theater = TheaterBounds {
+        max_x: config::WORLD_WIDTH + 400.0,
+        ..TheaterBounds::default()
+    };
+    let map = CustomMap {
+        name: "Atolls".into(),
+        description: String::new(),
+        regions,
+        theater: Some(theater),
+    };
+    let mut sim = campaign_sim(CampaignState::from_map(map).apply_profile(&PlayerProfile::default(), true));
+    sim.campaign.total_waves_survived = config::PRESTIGE_MIN_WAVES;
+
+    let mut profile = PlayerProfile::default();
+    sim.prestige_reset(&mut profile).unwrap();
+
+    assert_eq!(sim.campaign.regions.len(), 2);
+    assert_eq!(sim.campaign.regions[0].name, "Atoll");
+    assert_eq!(sim.campaign.theater, theater);
+    assert!(sim.campaign.map.is_some(), "A second reset should still use the map");
+}
+
+#[test]
+fn reloaded_save_cannot_be_reset_twice() {
+    let mut sim = endless_sim(config::PRESTIGE_MIN_WAVES);
+    sim.campaign.total_waves_survived = config::PRESTIGE_MIN_WAVES;
+    let save = serde_json::to_string(&sim.to_save_data("farm")).unwrap();
+
+    let mut profile = PlayerProfile::default();
+    sim.prestige_reset(&mut profile).unwrap();
+    assert_eq!(profile.prestige_level, 1);
+
+    // Reload the pre-reset save, as the game loop would
+    let mut reloaded = Simulation::from_save_data(serde_json::from_str(&save).unwrap());
+    reloaded.profile_prestige_level = profile.prestige_level;
+    assert!(!reloaded
+        .build_campaign_snapshot()
+        .available_actions
+        .iter()
+        .any(|a| matches!(a, AvailableAction::Prestige { .. })));
+    assert!(reloaded.prestige_reset(&mut profile).is_err());
+    assert_eq!(profile.prestige_level, 1);
+}
+
+// --- Difficulty Tests ---
+
+#[test]
+fn harder_campaigns_face_bigger_waves() {
+    let preview = |d| {
+        let mut sim = campaign_sim(CampaignState::default().with_difficulty(d));
+        sim.wave_number = 10;
+        sim.preview_next_wave()
+    };
+    let (easy, normal, hard) = (preview(Difficulty::Easy), preview(Difficulty::Normal), preview(Difficulty::Hard));
+    assert!(easy.missile_count < normal.missile_count);
+    assert!(hard.missile_count > normal.missile_count);
+}
+
+#[test]
+fn harder_campaigns_earn_less() {
+    let income = |d| campaign_sim(CampaignState::default().with_difficulty(d)).apply_wave_income();
+    assert!(income(Difficulty::Easy) > income(Difficulty::Normal));
+    assert!(income(Difficulty::Hard) < income(Difficulty::Normal));
+}
+
+#[test]
+fn difficulty_survives_prestige_and_saves() {
+    let mut sim = campaign_sim(CampaignState::default().with_difficulty(Difficulty::Hard));
+    sim.campaign.total_waves_survived = config::PRESTIGE_MIN_WAVES;
+    sim.prestige_reset(&mut PlayerProfile::default()).unwrap();
+    assert_eq!(sim.campaign.difficulty, Difficulty::Hard);
+
+    let restored = Simulation::from_save_data(sim.to_save_data("hard"));
+    assert_eq!(restored.campaign.difficulty, Difficulty::Hard);
+    assert_eq!(restored.build_campaign_snapshot().difficulty, "Hard");
+}
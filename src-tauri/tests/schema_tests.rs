@@ -0,0 +1,23 @@
+use deterrence_lib::state::schema;
+use std::fs;
+use std::path::Path;
+
+#[test]
+fn generated_types_match_the_rust_types() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(schema::GENERATED_DIR);
+    for (name, contents) in schema::generated_files() {
+        let committed = fs::read_to_string(dir.join(&name)).unwrap_or_default();
+        assert!(
+            committed == contents,
+            "src/types/generated/{name} is out of date; run `cargo run --bin export-types`"
+        );
+    }
+}
+
+#[test]
+fn typescript_covers_the_ipc_payloads() {
+    let ts = schema::typescript();
+    for name in ["PlayerCommand", "StateSnapshot", "EntitySnapshot", "EntityExtra", "EntityType", "InterceptorType"] {
+        assert!(ts.contains(&format!("export type {name} = ")), "{name} missing");
+    }
+}
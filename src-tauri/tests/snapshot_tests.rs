@@ -0,0 +1,205 @@
+use deterrence_lib::ecs::components::*;
+use deterrence_lib::ecs::entity::EntityId;
+use deterrence_lib::engine::config;
+use deterrence_lib::engine::simulation::Simulation;
+use deterrence_lib::state::game_state::GamePhase;
+use deterrence_lib::state::snapshot::{EntityExtra, EntityType};
+
+/// Default campaign with its world set up.
+fn new_sim() -> Simulation {
+    let mut sim = Simulation::new();
+    sim.setup_world();
+    sim
+}
+
+fn spawn_missile(sim: &mut Simulation, x: f32, y: f32, vx: f32, vy: f32) -> EntityId {
+    let id = sim.world.spawn();
+    sim.world.insert(id, Transform { x, y, rotation: 0.0 });
+    sim.world.insert(id, Velocity { vx, vy });
+    sim.world.insert(id, Ballistic {
+        drag_coefficient: config::MISSILE_DRAG_COEFF,
+        mass: config::MISSILE_MASS,
+        cross_section: config::MISSILE_CROSS_SECTION,
+    });
+    sim.world.insert(id, Warhead {
+        yield_force: config::WARHEAD_YIELD,
+        blast_radius_base: config::WARHEAD_BLAST_RADIUS,
+        warhead_type: WarheadType::Standard,
+    });
+    sim.world.insert(id, EntityMarker {
+        kind: EntityKind::Missile,
+    });
+    id
+}
+
+/// Fill the sky with `count` missiles carrying full trails, half of them low enough to be high-threat.
+fn crowded_sim(count: usize) -> Simulation {
+    let mut sim = new_sim();
+    for i in 0..count {
+        let x = 20.0 + (i as f32 * 12.3) % (config::WORLD_WIDTH - 40.0);
+        let y = if i % 2 == 0 { 650.0 } else { config::GROUND_Y + 100.0 };
+        let idx = spawn_missile(&mut sim, x, y, 1.5, -40.0);
+        sim.world.insert(idx, Trail {
+            points: (0..config::TRAIL_MAX_POINTS)
+                .map(|p| (x - p as f32 * 1.37, y + p as f32 * 2.11))
+                .collect(),
+        });
+    }
+    sim
+}
+
+// --- Entity Recycling Tests ---
+
+#[test]
+fn snapshot_ids_stay_unique_across_waves() {
+    let mut sim = new_sim();
+    let mut seen = std::collections::HashMap::new();
+
+    for _ in 0..3 {
+        if sim.campaign.pending_event.is_some() {
+            sim.resolve_strategic_event(false).unwrap();
+        }
+        sim.start_wave();
+        for _ in 0..3000 {
+            if sim.phase != GamePhase::WaveActive {
+                break;
+            }
+            let snapshot = sim.tick();
+            for e in &snapshot.entities {
+                let kind = format!("{:?}", e.entity_type);
+                let prev = seen.entry((e.id, e.generation)).or_insert_with(|| kind.clone());
+                assert_eq!(*prev, kind, "Entity ({}, {}) changed type: slot aliased", e.id, e.generation);
+            }
+        }
+        assert_eq!(sim.phase, GamePhase::WaveResult);
+        assert!(sim.continue_to_strategic().is_some());
+        assert_eq!(sim.phase, GamePhase::Strategic);
+    }
+    assert!(seen.keys().any(|&(_, generation)| generation > 0), "Slots should have been recycled");
+}
+
+// --- Snapshot Metadata Tests ---
+
+#[test]
+fn snapshot_carries_trails_and_warhead_type() {
+    let mut sim = new_sim();
+    let idx = spawn_missile(&mut sim, 640.0, 700.0, 0.0, -20.0);
+
+    let mut snapshot = sim.tick();
+    for _ in 0..20 {
+        snapshot = sim.tick();
+    }
+    let missile = snapshot.entities.iter().find(|e| e.id == idx.index && e.generation == idx.generation).unwrap();
+    assert_eq!(missile.trail.len(), 11);
+    assert!(missile.trail.windows(2).all(|w| w[1].1 < w[0].1), "Trail should follow the descent");
+    match &missile.extra {
+        Some(EntityExtra::Missile { warhead_type, .. }) => assert_eq!(warhead_type, "Standard"),
+        other => panic!("Expected missile extra, got {other:?}"),
+    }
+
+    let city = snapshot.entities.iter().find(|e| e.entity_type == EntityType::City).unwrap();
+    assert!(city.trail.is_empty());
+}
+
+#[test]
+fn degraded_mode_shortens_trails() {
+    let mut sim = new_sim();
+    sim.degraded = true;
+    let idx = spawn_missile(&mut sim, 640.0, 700.0, 0.0, -5.0);
+
+    for _ in 0..200 {
+        sim.tick();
+    }
+    let trail = &sim.world.trails.get(idx).unwrap().points;
+    assert_eq!(trail.len(), config::DEGRADED_TRAIL_POINTS);
+}
+
+// --- Snapshot LOD Tests ---
+
+fn missile_trail_lengths(sim: &Simulation) -> Vec<(f32, usize)> {
+    sim.build_snapshot()
+        .entities
+        .iter()
+        .filter(|e| e.entity_type == EntityType::Missile)
+        .map(|e| (e.y, e.trail.len()))
+        .collect()
+}
+
+/// An interceptor in flight towards (target_x, target_y)
+fn engage(sim: &mut Simulation, target_x: f32, target_y: f32) {
+    let id = sim.world.spawn();
+    sim.world.insert(id, Transform { x: target_x, y: config::GROUND_Y, rotation: 0.0 });
+    sim.world.insert(id, Interceptor {
+        interceptor_type: InterceptorType::Standard,
+        thrust: config::INTERCEPTOR_THRUST,
+        burn_time: config::INTERCEPTOR_BURN_TIME,
+        burn_remaining: config::INTERCEPTOR_BURN_TIME,
+        ceiling: config::INTERCEPTOR_CEILING,
+        battery_id: 0,
+        target_x,
+        target_y,
+        aim_x: target_x,
+        aim_y: target_y,
+        proximity_fuse_radius: 0.0,
+    });
+    sim.world.insert(id, EntityMarker { kind: EntityKind::Interceptor });
+}
+
+#[test]
+fn lod_trims_distant_missiles_only() {
+    let sim = crowded_sim(config::SNAPSHOT_LOD_MISSILE_THRESHOLD + 10);
+    let snapshot = sim.build_snapshot();
+    for e in snapshot.entities.iter().filter(|e| e.entity_type == EntityType::Missile) {
+        if e.y - config::GROUND_Y > config::SNAPSHOT_LOD_THREAT_ALTITUDE {
+            assert_eq!(e.trail.len(), config::SNAPSHOT_LOD_TRAIL_POINTS);
+            assert!(e.extra.is_none(), "Low-threat tracks carry no extra data");
+        } else {
+            assert_eq!(e.trail.len(), config::TRAIL_MAX_POINTS);
+            assert!(matches!(e.extra, Some(EntityExtra::Missile { .. })));
+        }
+    }
+}
+
+#[test]
+fn lod_keeps_engaged_missiles_in_full() {
+    let mut sim = crowded_sim(config::SNAPSHOT_LOD_MISSILE_THRESHOLD + 10);
+    // The first missile is high up at x = 20 heading down at 40 px/s; aim a little ahead of it
+    engage(&mut sim, 20.0 + 1.5, 650.0 - 40.0);
+    let snapshot = sim.build_snapshot();
+    let engaged: Vec<_> = snapshot
+        .entities
+        .iter()
+        .filter(|e| e.entity_type == EntityType::Missile && e.x == 20.0 && e.y == 650.0)
+        .collect();
+    assert_eq!(engaged.len(), 1);
+    assert_eq!(engaged[0].trail.len(), config::TRAIL_MAX_POINTS);
+    assert!(matches!(engaged[0].extra, Some(EntityExtra::Missile { .. })));
+
+    // Its high-altitude neighbours stay trimmed
+    let trimmed = snapshot
+        .entities
+        .iter()
+        .filter(|e| e.entity_type == EntityType::Missile && e.y == 650.0 && e.extra.is_none())
+        .count();
+    assert!(trimmed > 0);
+}
+
+#[test]
+fn lod_is_inactive_below_threshold() {
+    let sim = crowded_sim(config::SNAPSHOT_LOD_MISSILE_THRESHOLD);
+    assert!(missile_trail_lengths(&sim)
+        .iter()
+        .all(|&(_, len)| len == config::TRAIL_MAX_POINTS));
+}
+
+#[test]
+fn crowded_snapshot_fits_size_budget() {
+    let mut sim = crowded_sim(150);
+    let size = serde_json::to_string(&sim.build_snapshot()).unwrap().len();
+    assert!(size < config::SNAPSHOT_SIZE_BUDGET, "Snapshot is {size} bytes");
+
+    // The same sky without LOD blows the budget
+    sim.snapshot_lod.missile_threshold = usize::MAX;
+    let full = serde_json::to_string(&sim.build_snapshot()).unwrap().len();
+    assert!(full > config::SNAPSHOT_SIZE_BUDGET, "Unthinned snapshot is only {full} bytes");
+}
@@ -0,0 +1,148 @@
+use deterrence_lib::campaign::strategic_events::StrategicEvent;
+use deterrence_lib::campaign::territory::RegionId;
+use deterrence_lib::ecs::components::*;
+use deterrence_lib::engine::config;
+use deterrence_lib::engine::simulation::Simulation;
+use deterrence_lib::state::campaign_state::AvailableAction;
+use deterrence_lib::state::weather::{self, WeatherCondition};
+
+/// Default campaign with its world set up.
+fn new_sim() -> Simulation {
+    let mut sim = Simulation::new();
+    sim.setup_world();
+    sim
+}
+
+fn battery_state(sim: &Simulation, battery_id: usize) -> BatteryState {
+    *sim.world.battery_states.get(sim.battery_ids[battery_id]).unwrap()
+}
+
+// --- Strategic Event Tests ---
+
+#[test]
+fn resolve_without_pending_event_fails() {
+    let mut sim = new_sim();
+    assert!(sim.resolve_strategic_event(true).is_err());
+}
+
+#[test]
+fn declined_sabotage_drains_battery_ammo() {
+    let mut sim = new_sim();
+    sim.campaign.pending_event = Some(StrategicEvent::Sabotage {
+        region_id: RegionId(0),
+        slot_index: 0,
+        ammo_lost: config::SABOTAGE_AMMO_LOST,
+        cost: config::SABOTAGE_SWEEP_COST,
+    });
+
+    sim.resolve_strategic_event(false).unwrap();
+
+    assert!(sim.campaign.pending_event.is_none());
+    assert_eq!(
+        battery_state(&sim, 0).ammo,
+        config::BATTERY_MAX_AMMO - config::SABOTAGE_AMMO_LOST
+    );
+}
+
+#[test]
+fn accepted_sabotage_sweep_costs_resources_and_keeps_ammo() {
+    let mut sim = new_sim();
+    let resources = sim.campaign.resources;
+    sim.campaign.pending_event = Some(StrategicEvent::Sabotage {
+        region_id: RegionId(0),
+        slot_index: 0,
+        ammo_lost: config::SABOTAGE_AMMO_LOST,
+        cost: config::SABOTAGE_SWEEP_COST,
+    });
+
+    sim.resolve_strategic_event(true).unwrap();
+
+    assert_eq!(sim.campaign.resources, resources - config::SABOTAGE_SWEEP_COST);
+    assert_eq!(battery_state(&sim, 0).ammo, config::BATTERY_MAX_AMMO);
+}
+
+#[test]
+fn accepted_intel_reveals_next_wave_until_it_starts() {
+    // Pin the next wave's weather so the preview has to account for the storm
+    let seed = (0..)
+        .find(|&s| weather::weather_for_wave(s, 21).condition == WeatherCondition::Storm)
+        .unwrap();
+    let mut sim = Simulation::new_with_seed(seed);
+    sim.setup_world();
+    sim.wave_number = 20;
+    sim.roll_weather_forecast();
+    sim.campaign.pending_event = Some(StrategicEvent::IntelWindfall {
+        cost: config::INTEL_COST,
+    });
+
+    sim.resolve_strategic_event(true).unwrap();
+    let preview = sim.campaign.intel_preview.clone().unwrap();
+    assert_eq!(preview.wave_number, sim.wave_number + 1);
+
+    sim.start_wave();
+    assert_eq!(sim.weather.condition, WeatherCondition::Storm);
+    let wave = &sim.wave.as_ref().unwrap().definition;
+    assert_eq!(preview.missile_count, wave.missile_count);
+    assert_eq!(preview.mirv_count, wave.mirv_count);
+    assert_eq!(preview.sea_skimmer_count, wave.sea_skimmer_count);
+    assert!(sim.campaign.intel_preview.is_none());
+}
+
+#[test]
+fn accepted_refugees_grow_city_population() {
+    let mut sim = new_sim();
+    sim.campaign.pending_event = Some(StrategicEvent::RefugeeInflux {
+        region_id: RegionId(0),
+        city_index: 1,
+        population: config::REFUGEE_POPULATION,
+        cost: config::REFUGEE_RESETTLE_COST,
+    });
+
+    sim.resolve_strategic_event(true).unwrap();
+
+    let city = &sim.campaign.get_region(RegionId(0)).unwrap().cities[1];
+    assert_eq!(city.population, 500 + config::REFUGEE_POPULATION);
+}
+
+#[test]
+fn pending_event_blocks_start_wave_action() {
+    let mut sim = new_sim();
+    sim.campaign.pending_event = Some(StrategicEvent::IntelWindfall {
+        cost: config::INTEL_COST,
+    });
+
+    let snap = sim.build_campaign_snapshot();
+    assert!(snap.pending_event.is_some());
+    assert!(!snap
+        .available_actions
+        .iter()
+        .any(|a| matches!(a, AvailableAction::StartWave)));
+}
+
+#[test]
+fn pending_event_survives_save_round_trip() {
+    let mut sim = new_sim();
+    sim.campaign.pending_event = Some(StrategicEvent::IntelWindfall {
+        cost: config::INTEL_COST,
+    });
+
+    let json = serde_json::to_string(&sim.to_save_data("test")).unwrap();
+    let restored = Simulation::from_save_data(serde_json::from_str(&json).unwrap());
+    assert_eq!(restored.campaign.pending_event, sim.campaign.pending_event);
+}
+
+#[test]
+fn strategic_event_rolls_are_deterministic() {
+    let roll = |seed| {
+        let mut sim = Simulation::new_with_seed(seed);
+        sim.setup_world();
+        (0..20)
+            .map(|_| {
+                sim.campaign.pending_event = None;
+                sim.roll_strategic_event();
+                sim.campaign.pending_event.clone()
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(roll(11), roll(11));
+}
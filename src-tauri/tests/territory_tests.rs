@@ -0,0 +1,270 @@
+use deterrence_lib::campaign::custom_map::{self, CustomMap};
+use deterrence_lib::campaign::diplomacy;
+use deterrence_lib::campaign::territory::{self, RegionId, TheaterBounds};
+use deterrence_lib::ecs::components::*;
+use deterrence_lib::ecs::entity::EntityId;
+use deterrence_lib::engine::config;
+use deterrence_lib::engine::simulation::Simulation;
+use deterrence_lib::events::game_events::GameEvent;
+use deterrence_lib::state::campaign_state::AvailableAction;
+use deterrence_lib::state::campaign_state::CampaignState;
+use deterrence_lib::systems::input_system::PlayerCommand;
+
+/// Default campaign with its world set up.
+fn new_sim() -> Simulation {
+    let mut sim = Simulation::new();
+    sim.setup_world();
+    sim
+}
+
+fn spawn_missile(sim: &mut Simulation, x: f32, y: f32, vx: f32, vy: f32) -> EntityId {
+    let id = sim.world.spawn();
+    sim.world.insert(id, Transform { x, y, rotation: 0.0 });
+    sim.world.insert(id, Velocity { vx, vy });
+    sim.world.insert(id, Ballistic {
+        drag_coefficient: config::MISSILE_DRAG_COEFF,
+        mass: config::MISSILE_MASS,
+        cross_section: config::MISSILE_CROSS_SECTION,
+    });
+    sim.world.insert(id, Warhead {
+        yield_force: config::WARHEAD_YIELD,
+        blast_radius_base: config::WARHEAD_BLAST_RADIUS,
+        warhead_type: WarheadType::Standard,
+    });
+    sim.world.insert(id, EntityMarker {
+        kind: EntityKind::Missile,
+    });
+    id
+}
+
+fn launch(sim: &mut Simulation, battery_id: u32, target_x: f32, target_y: f32) {
+    sim.push_command(PlayerCommand::LaunchInterceptor {
+        battery_id,
+        target_x,
+        target_y,
+        interceptor_type: InterceptorType::Standard,
+    });
+}
+
+fn interceptor_count(sim: &Simulation) -> usize {
+    sim.world.interceptors.iter().count()
+}
+
+fn battery_state(sim: &Simulation, battery_id: usize) -> BatteryState {
+    *sim.world.battery_states.get(sim.battery_ids[battery_id]).unwrap()
+}
+
+// --- Terrain Modifier Tests ---
+
+#[test]
+fn mountain_batteries_have_reduced_radar() {
+    let mut sim = new_sim();
+    sim.campaign.resources = 1000;
+    sim.expand_region(1).unwrap(); // Western Highlands (Mountains)
+    sim.place_battery(1, 0).unwrap();
+
+    let homeland = battery_state(&sim, 0);
+    let highland = battery_state(&sim, 2);
+    assert_eq!(homeland.radar_multiplier, 1.0);
+    assert_eq!(highland.radar_multiplier, config::MOUNTAIN_RADAR_MULT);
+}
+
+#[test]
+fn coastal_region_spawns_glowless_sea_skimmer() {
+    let mut sim = Simulation::new_with_seed(7);
+    sim.setup_world();
+    sim.campaign.resources = 1000;
+    sim.expand_region(2).unwrap(); // Eastern Seaboard (Coastal)
+    sim.start_wave();
+    assert_eq!(sim.wave.as_ref().unwrap().definition.sea_skimmer_count, 1);
+
+    sim.tick();
+    let missile_idx = sim
+        .world
+        .alive_entities()
+        .into_iter()
+        .find(|&idx| {
+            sim.world.markers.get(idx)
+                .is_some_and(|m| m.kind == EntityKind::Missile)
+        })
+        .expect("First missile should spawn on the first tick");
+
+    let pos = sim.world.transforms.get(missile_idx).copied().unwrap();
+    assert!(pos.y < config::SEA_SKIMMER_ALTITUDE + 10.0, "Skimmer should enter low, y = {}", pos.y);
+    assert!(sim.world.reentry_glows.get(missile_idx).is_none());
+}
+
+// --- Diplomacy Tests ---
+
+#[test]
+fn form_alliance_defends_allied_cities() {
+    let mut sim = new_sim();
+    sim.campaign.resources = 1000;
+    let cities_before = sim.city_ids.len();
+
+    sim.form_alliance(2).unwrap();
+
+    assert!(sim.campaign.allied_regions.contains(&RegionId(2)));
+    assert!(!sim.campaign.owned_regions.contains(&RegionId(2)));
+    assert_eq!(sim.city_ids.len(), cities_before + 1);
+    assert_eq!(sim.form_alliance(2), Err("Region already allied".into()));
+}
+
+#[test]
+fn alliance_requires_adjacency_and_resources() {
+    let mut sim = new_sim();
+    assert!(sim.form_alliance(4).is_err(), "Region 4 is not adjacent to the homeland");
+
+    sim.campaign.resources = 0;
+    assert!(sim.form_alliance(2).is_err());
+}
+
+#[test]
+fn lease_battery_requires_alliance() {
+    let mut sim = new_sim();
+    sim.campaign.resources = 1000;
+    assert!(sim.lease_battery(2, 0).is_err());
+
+    sim.form_alliance(2).unwrap();
+    let batteries_before = sim.battery_ids.len();
+    sim.lease_battery(2, 0).unwrap();
+    assert_eq!(sim.battery_ids.len(), batteries_before + 1);
+    assert!(sim.lease_battery(2, 0).is_err(), "Slot already leased");
+}
+
+#[test]
+fn damage_to_allied_city_worsens_relations_and_costs() {
+    let mut sim = new_sim();
+    sim.campaign.resources = 1000;
+    sim.form_alliance(2).unwrap();
+    let cost_before = diplomacy::lease_cost(sim.campaign.cost_table.place_battery, sim.campaign.relations);
+
+    // Allied city is spawned after the three homeland cities
+    let allied_city = sim.city_ids[3];
+    sim.world.healths.get_mut(allied_city).unwrap().current = 50.0;
+    sim.sync_to_campaign();
+
+    assert!(sim.campaign.relations < config::RELATIONS_START);
+    let cost_after = diplomacy::lease_cost(sim.campaign.cost_table.place_battery, sim.campaign.relations);
+    assert!(cost_after > cost_before);
+}
+
+#[test]
+fn allied_cities_contribute_partial_income() {
+    let mut sim = new_sim();
+    let homeland_income = sim.apply_wave_income();
+
+    let mut sim = new_sim();
+    sim.campaign.resources = 1000;
+    sim.form_alliance(2).unwrap();
+    let allied_income = sim.apply_wave_income();
+
+    // Eastern Seaboard: 400 pop * 1.2 multiplier * 0.5 share / 10 = 24
+    assert_eq!(allied_income, homeland_income + 24);
+}
+
+#[test]
+fn annexing_ally_keeps_city_tracking() {
+    let mut sim = new_sim();
+    sim.campaign.resources = 1000;
+    sim.form_alliance(2).unwrap();
+    sim.expand_region(2).unwrap();
+
+    assert!(sim.campaign.allied_regions.is_empty());
+    let tracked = sim
+        .campaign
+        .city_healths
+        .iter()
+        .filter(|(r, _, _)| *r == RegionId(2))
+        .count();
+    assert_eq!(tracked, 1);
+}
+
+#[test]
+fn campaign_snapshot_offers_diplomacy_actions() {
+    let mut sim = new_sim();
+    sim.campaign.resources = 1000;
+
+    let snap = sim.build_campaign_snapshot();
+    assert!(snap
+        .available_actions
+        .iter()
+        .any(|a| matches!(a, AvailableAction::FormAlliance { region_id: 2, .. })));
+
+    sim.form_alliance(2).unwrap();
+    let snap = sim.build_campaign_snapshot();
+    assert_eq!(snap.allied_region_ids, vec![2]);
+    assert!(snap.regions.iter().any(|r| r.id == 2 && r.allied && !r.owned));
+    assert!(snap
+        .available_actions
+        .iter()
+        .any(|a| matches!(a, AvailableAction::LeaseBattery { region_id: 2, slot_index: 0, .. })));
+}
+
+// --- Custom Map Tests ---
+
+#[test]
+fn custom_map_campaign_spawns_its_homeland() {
+    let mut regions = territory::define_regions();
+    regions.truncate(2);
+    regions[0].adjacent.retain(|id| *id == RegionId(1));
+    regions[1].adjacent.retain(|id| *id == RegionId(0));
+    regions[0].cities.truncate(2);
+    let map = CustomMap {
+        name: "Duel".into(),
+        description: String::new(),
+        regions,
+        theater: None,
+    };
+    let map = custom_map::parse_map(&serde_json::to_string(&map).unwrap()).unwrap();
+
+    let mut sim = Simulation::new_with_campaign(CampaignState::from_regions(map.regions), 42);
+    sim.setup_world();
+    assert_eq!(sim.city_ids.len(), 2);
+    assert_eq!(sim.battery_ids.len(), 2);
+    assert_eq!(sim.campaign.expandable_regions().len(), 1);
+}
+
+// --- Theater Bounds Tests ---
+
+#[test]
+fn missile_leaving_theater_is_reported_as_exited() {
+    let mut sim = new_sim();
+    sim.start_wave();
+
+    let margin = config::OOB_MARGIN;
+    spawn_missile(&mut sim, config::WORLD_WIDTH + margin - 1.0, 600.0, 300.0, 0.0);
+    sim.tick();
+
+    let exited = sim
+        .drain_events()
+        .into_iter()
+        .filter(|e| matches!(e, GameEvent::ThreatExited(_)))
+        .count();
+    assert_eq!(exited, 1);
+    assert_eq!(sim.wave.as_ref().unwrap().missiles_exited, 1);
+    assert_eq!(sim.wave.as_ref().unwrap().missiles_impacted, 0);
+}
+
+#[test]
+fn interceptor_leaving_theater_self_destructs() {
+    let mut sim = new_sim();
+    sim.campaign.theater = TheaterBounds {
+        max_y: 200.0,
+        ..TheaterBounds::default()
+    };
+    sim.start_wave();
+
+    launch(&mut sim, 0, 400.0, 600.0);
+    let mut self_destructs = 0;
+    for _ in 0..300 {
+        sim.tick();
+        self_destructs += sim
+            .drain_events()
+            .iter()
+            .filter(|e| matches!(e, GameEvent::InterceptorSelfDestruct(_)))
+            .count();
+    }
+    assert_eq!(self_destructs, 1);
+    assert_eq!(interceptor_count(&sim), 0);
+}
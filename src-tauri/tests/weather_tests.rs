@@ -0,0 +1,64 @@
+use deterrence_lib::engine::simulation::Simulation;
+use deterrence_lib::state::weather::{self, WeatherCondition};
+
+// --- Weather Forecast Tests ---
+
+#[test]
+fn forecast_describes_the_next_waves_weather() {
+    let mut sim = Simulation::new_with_seed(5);
+    sim.setup_world();
+    sim.wave_number = 20;
+    sim.roll_weather_forecast();
+
+    let forecast = sim.build_campaign_snapshot().weather_forecast.unwrap();
+    let upcoming = weather::weather_for_wave(sim.seed, 21);
+    assert_eq!(forecast.wave_number, 21);
+
+    sim.start_wave();
+    assert_eq!(sim.wave_number, 21);
+    assert_eq!(sim.weather.condition, upcoming.condition);
+    assert!(forecast.wind_x_min <= sim.weather.wind_x && sim.weather.wind_x <= forecast.wind_x_max);
+    assert!(sim.campaign.weather_forecast.is_none());
+}
+
+#[test]
+fn forecast_survives_save_roundtrip() {
+    let mut sim = Simulation::new_with_seed(5);
+    sim.setup_world();
+    sim.wave_number = 20;
+    sim.roll_weather_forecast();
+
+    let save = serde_json::to_value(sim.to_save_data("test")).unwrap();
+    let campaign = save["campaign"].as_object().unwrap();
+    assert!(campaign.keys().all(|k| k != "upcoming_weather"), "The actual weather must not be saved");
+
+    let mut restored = Simulation::from_save_data(serde_json::from_value(save).unwrap());
+    assert_eq!(restored.campaign.weather_forecast, sim.campaign.weather_forecast);
+
+    restored.start_wave();
+    sim.start_wave();
+    assert_eq!(restored.weather.condition, sim.weather.condition);
+    assert_eq!(restored.weather.wind_x, sim.weather.wind_x);
+}
+
+#[test]
+fn preview_uses_the_forecast_weather() {
+    let seed = (0..)
+        .find(|&s| weather::weather_for_wave(s, 21).condition == WeatherCondition::Storm)
+        .unwrap();
+    let mut sim = Simulation::new_with_seed(seed);
+    sim.setup_world();
+    sim.wave_number = 20;
+    let clear = sim.preview_next_wave();
+
+    sim.roll_weather_forecast();
+    let preview = sim.preview_next_wave();
+    assert!(preview.missile_count > clear.missile_count);
+
+    sim.start_wave();
+    assert_eq!(sim.weather.condition, WeatherCondition::Storm);
+    let wave = sim.wave.as_ref().unwrap();
+    assert_eq!(preview.missile_count, wave.definition.missile_count);
+    assert_eq!(preview.mirv_count, wave.definition.mirv_count);
+    assert_eq!(preview.sea_skimmer_count, wave.definition.sea_skimmer_count);
+}
@@ -1,7 +1,14 @@
 import { invoke } from "@tauri-apps/api/core";
 import { getCurrentWindow } from "@tauri-apps/api/window";
 import { LogicalSize } from "@tauri-apps/api/dpi";
-import type { PingResponse, ArcPrediction, SaveMetadata } from "../types/commands";
+import type {
+  PingResponse,
+  ArcPrediction,
+  SaveMetadata,
+  MapMetadata,
+  IntegrityStatus,
+} from "../types/commands";
+import type { Difficulty, TheaterBounds } from "../types/campaign";
 
 export async function ping(): Promise<PingResponse> {
   return await invoke<PingResponse>("ping");
@@ -21,13 +28,28 @@ export async function launchInterceptor(
   });
 }
 
+export async function salvoIntercept(
+  targetX: number,
+  targetY: number,
+  count: number,
+  interceptorType?: string
+): Promise<void> {
+  await invoke("salvo_intercept", {
+    targetX,
+    targetY,
+    count,
+    interceptorType,
+  });
+}
+
 export async function predictArc(
   batteryX: number,
   batteryY: number,
   targetX: number,
   targetY: number,
   interceptorType?: string,
-  windX?: number
+  windX?: number,
+  theater?: TheaterBounds
 ): Promise<ArcPrediction> {
   return await invoke<ArcPrediction>("predict_arc", {
     batteryX,
@@ -36,9 +58,14 @@ export async function predictArc(
     targetY,
     interceptorType,
     windX,
+    theater,
   });
 }
 
+export async function predictImpacts(): Promise<void> {
+  await invoke("predict_impacts");
+}
+
 export async function startWave(): Promise<void> {
   await invoke("start_wave");
 }
@@ -58,6 +85,17 @@ export async function placeBattery(
   await invoke("place_battery", { regionId, slotIndex });
 }
 
+export async function formAlliance(regionId: number): Promise<void> {
+  await invoke("form_alliance", { regionId });
+}
+
+export async function leaseBattery(
+  regionId: number,
+  slotIndex: number
+): Promise<void> {
+  await invoke("lease_battery", { regionId, slotIndex });
+}
+
 export async function restockAllBatteries(): Promise<void> {
   await invoke("restock_all_batteries");
 }
@@ -74,12 +112,41 @@ export async function upgradeInterceptor(interceptorType: string, axis: string):
   await invoke("upgrade_interceptor", { interceptorType, axis });
 }
 
+export async function resolveStrategicEvent(accept: boolean): Promise<void> {
+  await invoke("resolve_strategic_event", { accept });
+}
+
 export async function getCampaignState(): Promise<void> {
   await invoke("get_campaign_state");
 }
 
-export async function newGame(): Promise<void> {
-  await invoke("new_game");
+export async function getCampaignStats(): Promise<void> {
+  await invoke("get_campaign_stats");
+}
+
+export async function newGame(
+  endless = false,
+  mapName?: string,
+  difficulty: Difficulty = "Normal",
+): Promise<void> {
+  await invoke("new_game", { endless, difficulty, mapName });
+}
+
+export async function prestigeReset(): Promise<void> {
+  await invoke("prestige_reset");
+}
+
+export async function pauseWave(): Promise<void> {
+  await invoke("pause_wave");
+}
+
+export async function resumeWave(): Promise<void> {
+  await invoke("resume_wave");
+}
+
+/** Replay the current wave from its starting state with a fresh seed. */
+export async function restartWave(): Promise<void> {
+  await invoke("restart_wave");
 }
 
 export async function returnToMainMenu(): Promise<void> {
@@ -90,14 +157,19 @@ export async function saveGame(slotName: string): Promise<void> {
   await invoke("save_game", { slotName });
 }
 
-export async function loadGame(slotName: string): Promise<void> {
-  await invoke("load_game", { slotName });
+/** Resolves to the save's integrity status; "Tampered" saves are not loaded. */
+export async function loadGame(slotName: string): Promise<IntegrityStatus> {
+  return await invoke<IntegrityStatus>("load_game", { slotName });
 }
 
 export async function listSaves(): Promise<SaveMetadata[]> {
   return await invoke<SaveMetadata[]>("list_saves");
 }
 
+export async function listMaps(): Promise<MapMetadata[]> {
+  return await invoke<MapMetadata[]>("list_maps");
+}
+
 export async function deleteSave(slotName: string): Promise<void> {
   await invoke("delete_save", { slotName });
 }
@@ -1,7 +1,8 @@
 import { listen } from "@tauri-apps/api/event";
 import type { StateSnapshot } from "../types/snapshot";
-import type { DetonationEvent, ImpactEvent, CityDamagedEvent, WaveCompleteEvent, MirvSplitEvent } from "../types/events";
-import type { CampaignSnapshot } from "../types/campaign";
+import type { DetonationEvent, ImpactEvent, CityDamagedEvent, WaveCompleteEvent, MirvSplitEvent, MirvBusKilledEvent, InterceptorSelfDestructEvent, ThreatExitedEvent, SalvoShortfallEvent } from "../types/events";
+import type { CampaignSnapshot, CampaignStats } from "../types/campaign";
+import type { ImpactPredictions } from "../types/commands";
 
 export function onStateSnapshot(callback: (snapshot: StateSnapshot) => void) {
   return listen<StateSnapshot>("game:state_snapshot", (event) => {
@@ -39,8 +40,44 @@ export function onMirvSplit(callback: (event: MirvSplitEvent) => void) {
   });
 }
 
+export function onMirvBusKilled(callback: (event: MirvBusKilledEvent) => void) {
+  return listen<MirvBusKilledEvent>("game:mirv_bus_killed", (e) => {
+    callback(e.payload);
+  });
+}
+
+export function onInterceptorSelfDestruct(callback: (event: InterceptorSelfDestructEvent) => void) {
+  return listen<InterceptorSelfDestructEvent>("game:interceptor_self_destruct", (e) => {
+    callback(e.payload);
+  });
+}
+
+export function onThreatExited(callback: (event: ThreatExitedEvent) => void) {
+  return listen<ThreatExitedEvent>("game:threat_exited", (e) => {
+    callback(e.payload);
+  });
+}
+
+export function onSalvoShortfall(callback: (event: SalvoShortfallEvent) => void) {
+  return listen<SalvoShortfallEvent>("game:salvo_shortfall", (e) => {
+    callback(e.payload);
+  });
+}
+
+export function onImpactPredictions(callback: (predictions: ImpactPredictions) => void) {
+  return listen<ImpactPredictions>("game:impact_predictions", (e) => {
+    callback(e.payload);
+  });
+}
+
 export function onCampaignUpdate(callback: (snapshot: CampaignSnapshot) => void) {
   return listen<CampaignSnapshot>("campaign:state_update", (e) => {
     callback(e.payload);
   });
 }
+
+export function onCampaignStats(callback: (stats: CampaignStats) => void) {
+  return listen<CampaignStats>("campaign:stats", (e) => {
+    callback(e.payload);
+  });
+}
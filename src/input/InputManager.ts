@@ -1,25 +1,33 @@
 import { Application } from "pixi.js";
 import {
   launchInterceptor,
+  salvoIntercept,
   predictArc,
   startWave,
   continueToStrategic,
   expandRegion,
   placeBattery,
+  formAlliance,
+  leaseBattery,
   restockAllBatteries,
   repairCity,
   unlockInterceptor,
   upgradeInterceptor,
+  prestigeReset,
   saveGame,
   loadGame,
   returnToMainMenu,
+  pauseWave,
+  resumeWave,
+  restartWave,
 } from "../bridge/commands";
 import { getCurrentWindow } from "@tauri-apps/api/window";
 import type { ArcPrediction } from "../types/commands";
-import type { CampaignSnapshot, AvailableAction } from "../types/campaign";
+import type { CampaignSnapshot, AvailableAction, TheaterBounds } from "../types/campaign";
 
 const GROUND_Y = 50;
 const ARC_THROTTLE_MS = 67; // ~15Hz
+const SALVO_COUNT = 3;
 
 const TYPE_KEYS: Record<string, string> = {
   q: "Standard",
@@ -43,10 +51,12 @@ export class InputManager {
   private worldWidth: number;
   private worldHeight: number;
   private currentPhase: string = "Strategic";
+  private paused = false;
   private _selectedBattery: number = 0;
   private _selectedType: string = "Standard";
   private unlockedTypes: string[] = ["Standard"];
   private currentWindX: number = 0;
+  private theater: TheaterBounds | undefined;
   private lastArcRequest: number = 0;
   /** Dynamic battery positions from campaign state */
   private batteryPositions: BatteryPosition[] = [
@@ -78,6 +88,9 @@ export class InputManager {
   /** Callback invoked when fullscreen state changes. */
   onFullscreenChange: ((fullscreen: boolean) => void) | null = null;
 
+  /** Callback invoked with the outcome of a quicksave or quickload (null on success). */
+  onSaveResult: ((error: string | null) => void) | null = null;
+
   constructor(app: Application, worldWidth: number, worldHeight: number) {
     this.app = app;
     this.worldWidth = worldWidth;
@@ -113,6 +126,10 @@ export class InputManager {
     this.currentWindX = windX;
   }
 
+  setPaused(paused: boolean) {
+    this.paused = paused;
+  }
+
   setPhase(phase: string) {
     const prev = this.currentPhase;
     this.currentPhase = phase;
@@ -124,6 +141,7 @@ export class InputManager {
 
   /** Update battery positions and tech tree from campaign state */
   updateBatteryPositions(campaign: CampaignSnapshot) {
+    this.theater = campaign.theater;
     const positions: BatteryPosition[] = [];
     const cityMap: CityMapEntry[] = [];
     const regionById = new Map(campaign.regions.map((region) => [region.id, region]));
@@ -131,10 +149,12 @@ export class InputManager {
       campaign.owned_region_ids.length > 0
         ? campaign.owned_region_ids
         : campaign.regions.filter((region) => region.owned).map((region) => region.id);
+    // Allied regions are defended after owned ones, matching backend entity order
+    const defendedIds = [...ownedIds, ...(campaign.allied_region_ids ?? [])];
 
-    for (const rid of ownedIds) {
+    for (const rid of defendedIds) {
       const region = regionById.get(rid);
-      if (!region || !region.owned) continue;
+      if (!region || !(region.owned || region.allied)) continue;
       for (let i = 0; i < region.cities.length; i++) {
         cityMap.push({ regionId: region.id, cityIndex: i });
       }
@@ -180,6 +200,13 @@ export class InputManager {
         action.PlaceBattery.region_id,
         action.PlaceBattery.slot_index
       );
+    } else if ("FormAlliance" in action) {
+      formAlliance(action.FormAlliance.region_id);
+    } else if ("LeaseBattery" in action) {
+      leaseBattery(
+        action.LeaseBattery.region_id,
+        action.LeaseBattery.slot_index
+      );
     } else if ("RestockAllBatteries" in action) {
       restockAllBatteries();
     } else if ("RepairCity" in action) {
@@ -194,6 +221,8 @@ export class InputManager {
       unlockInterceptor(action.UnlockInterceptor.interceptor_type);
     } else if ("UpgradeInterceptor" in action) {
       upgradeInterceptor(action.UpgradeInterceptor.interceptor_type, action.UpgradeInterceptor.axis);
+    } else if ("Prestige" in action) {
+      prestigeReset();
     }
   }
 
@@ -247,7 +276,12 @@ export class InputManager {
     const { worldX, worldY } = this.screenToWorld(e);
 
     if (this.currentPhase === "WaveActive") {
-      if (worldY > GROUND_Y + 20) {
+      if (this.paused) return;
+      if (worldY > GROUND_Y + 20 && e.shiftKey) {
+        // Shift+click: coordinated salvo from the best-placed batteries
+        salvoIntercept(worldX, worldY, SALVO_COUNT, this._selectedType);
+        this.onLaunchSound?.(worldX);
+      } else if (worldY > GROUND_Y + 20) {
         // Auto-select nearest battery on click
         this.selectBattery(this.nearestBattery(worldX));
         launchInterceptor(this._selectedBattery, worldX, worldY, this._selectedType);
@@ -285,7 +319,7 @@ export class InputManager {
     const bat = this.batteryPositions[this._selectedBattery];
     if (!bat) return;
 
-    predictArc(bat.x, bat.y, worldX, worldY, this._selectedType, this.currentWindX)
+    predictArc(bat.x, bat.y, worldX, worldY, this._selectedType, this.currentWindX, this.theater)
       .then((prediction) => {
         this.onArcUpdate?.(prediction);
       })
@@ -329,13 +363,32 @@ export class InputManager {
       case "F5":
         e.preventDefault();
         if (this.currentPhase === "Strategic") {
-          saveGame("quicksave");
+          saveGame("quicksave")
+            .then(() => this.onSaveResult?.(null))
+            .catch((err) => this.onSaveResult?.(`SAVE FAILED: ${err}`));
         }
         break;
       case "F9":
         e.preventDefault();
         if (this.currentPhase === "Strategic") {
-          loadGame("quicksave");
+          loadGame("quicksave")
+            .then(() => this.onSaveResult?.(null))
+            .catch((err) => this.onSaveResult?.(`LOAD FAILED: ${err}`));
+        }
+        break;
+      case "p":
+        if (this.currentPhase === "WaveActive") {
+          if (this.paused) {
+            resumeWave();
+          } else {
+            pauseWave();
+          }
+        }
+        break;
+      case "F8":
+        e.preventDefault();
+        if (this.currentPhase === "WaveActive" || this.currentPhase === "WaveResult") {
+          restartWave();
         }
         break;
       case "m":
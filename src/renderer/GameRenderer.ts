@@ -15,14 +15,24 @@ import {
   onWaveComplete,
   onCampaignUpdate,
   onMirvSplit,
+  onMirvBusKilled,
+  onInterceptorSelfDestruct,
+  onSalvoShortfall,
   onImpact,
 } from "../bridge/events";
 import type { StateSnapshot } from "../types/snapshot";
-import type { WaveCompleteEvent, MirvSplitEvent } from "../types/events";
+import type {
+  WaveCompleteEvent,
+  MirvSplitEvent,
+  MirvBusKilledEvent,
+  InterceptorSelfDestructEvent,
+  SalvoShortfallEvent,
+} from "../types/events";
 import type { CampaignSnapshot } from "../types/campaign";
 
 const WORLD_WIDTH = 1280;
 const WORLD_HEIGHT = 720;
+const SALVO_NOTICE_MS = 2500;
 
 export class GameRenderer {
   app: Application;
@@ -41,6 +51,7 @@ export class GameRenderer {
   // Screen shake state
   private shakeDecay: number = 0;
   private shakeIntensity: number = 0;
+  private salvoNoticeTimer: number | undefined;
 
   constructor() {
     this.app = new Application();
@@ -107,6 +118,10 @@ export class GameRenderer {
       this.store.getState().updateSettings({ fullscreen });
     };
 
+    this.inputManager.onSaveResult = (error) => {
+      this.store.getState().setSaveError(error);
+    };
+
     // Show main menu by default (initial snapshot may arrive before listener is ready)
     this.setViewForPhase("MainMenu");
 
@@ -128,6 +143,7 @@ export class GameRenderer {
       // Track phase and wind for input manager
       this.inputManager.setPhase(snapshot.phase);
       this.inputManager.setWindX(snapshot.wind_x ?? 0);
+      this.inputManager.setPaused(snapshot.paused ?? false);
 
       // Feed live battery ammo to input manager for auto-switch
       this.updateInputBatteryAmmo(snapshot);
@@ -219,6 +235,27 @@ export class GameRenderer {
       this.audio.playMirvSplit(event.x);
     });
 
+    // A bus killed before splitting takes all its warheads with it
+    onMirvBusKilled((event: MirvBusKilledEvent) => {
+      this.particleManager.spawnExplosion(event.x, event.y, 1 + event.children_prevented * 0.25);
+    });
+
+    onInterceptorSelfDestruct((event: InterceptorSelfDestructEvent) => {
+      this.particleManager.spawnExplosion(event.x, event.y, 0.4);
+    });
+
+    onSalvoShortfall((event: SalvoShortfallEvent) => {
+      const notice =
+        event.assigned === 0 && event.requested === 1
+          ? "SALVO: SHOT LOST - BATTERY UNAVAILABLE"
+          : `SALVO: ${event.assigned}/${event.requested} BATTERIES ASSIGNED`;
+      this.store.getState().setHud({ salvoNotice: notice });
+      window.clearTimeout(this.salvoNoticeTimer);
+      this.salvoNoticeTimer = window.setTimeout(() => {
+        this.store.getState().setHud({ salvoNotice: null });
+      }, SALVO_NOTICE_MS);
+    });
+
     // Listen for wave completion
     onWaveComplete((event: WaveCompleteEvent) => {
       this.store.getState().setWaveComplete(event);
@@ -355,7 +392,10 @@ export class GameRenderer {
     let radarCount = 0;
     let glowCount = 0;
     for (const m of missiles) {
-      if (m.extra && "Missile" in m.extra) {
+      if (!m.extra) {
+        // Low-detail tracks drop their extra data; every missile is a radar track
+        radarCount++;
+      } else if ("Missile" in m.extra) {
         const data = (
           m.extra as { Missile: { detected_by_radar: boolean; detected_by_glow: boolean } }
         ).Missile;
@@ -373,6 +413,7 @@ export class GameRenderer {
       contactsGlow: glowCount,
       weather: snapshot.weather ?? null,
       windX: snapshot.wind_x ?? 0,
+      paused: snapshot.paused ?? false,
     });
   }
 
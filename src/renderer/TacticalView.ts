@@ -36,7 +36,8 @@ export class TacticalView {
   private worldWidth: number;
   private stage: Container;
   private groundLine: Graphics;
-  private entityVisuals: Map<number, EntityVisual> = new Map();
+  /** Keyed by `${id}:${generation}` so a recycled entity slot never reuses a stale visual */
+  private entityVisuals: Map<string, EntityVisual> = new Map();
 
   // Arc overlay layer
   private arcOverlay: Graphics;
@@ -108,15 +109,16 @@ export class TacticalView {
   }
 
   update(snapshot: StateSnapshot) {
-    const activeIds = new Set<number>();
+    const activeIds = new Set<string>();
 
     for (const entity of snapshot.entities) {
-      activeIds.add(entity.id);
+      const key = `${entity.id}:${entity.generation}`;
+      activeIds.add(key);
 
-      let visual = this.entityVisuals.get(entity.id);
+      let visual = this.entityVisuals.get(key);
       if (!visual) {
         visual = this.createVisual(entity);
-        this.entityVisuals.set(entity.id, visual);
+        this.entityVisuals.set(key, visual);
         this.stage.addChild(visual.container);
       }
 
@@ -264,12 +266,13 @@ export class TacticalView {
     visual.container.x = 0;
     visual.container.y = 0;
 
-    // Update trail
+    // Update trail from the sim's sampled positions, ending at the current position
     if (visual.trail && (entity.entity_type === "Missile" || entity.entity_type === "Interceptor")) {
+      visual.trailPoints = (entity.trail ?? []).map(([x, y]) => ({
+        x,
+        y: worldToScreen(y, this.worldHeight),
+      }));
       visual.trailPoints.push({ x: screenX, y: screenY });
-      if (visual.trailPoints.length > 60) {
-        visual.trailPoints.shift();
-      }
 
       let trailColor = MISSILE_BODY;
       let trailDimColor = MISSILE_TRAIL_DIM;
@@ -359,7 +362,7 @@ export class TacticalView {
     }
   }
 
-  private drawMissile(g: Graphics, x: number, y: number, extra: EntityExtra | null) {
+  private drawMissile(g: Graphics, x: number, y: number, extra?: EntityExtra | null) {
     let isMirv = false;
     if (extra && "Missile" in extra) {
       const data = (extra as { Missile: { is_mirv: boolean; detected_by_radar: boolean; detected_by_glow: boolean } }).Missile;
@@ -385,7 +388,7 @@ export class TacticalView {
     }
   }
 
-  private drawInterceptor(g: Graphics, x: number, y: number, extra: EntityExtra | null) {
+  private drawInterceptor(g: Graphics, x: number, y: number, extra?: EntityExtra | null) {
     let burning = false;
     let burnRatio = 0;
     let typeName = "Standard";
@@ -427,7 +430,7 @@ export class TacticalView {
     }
   }
 
-  private drawShockwave(g: Graphics, x: number, y: number, extra: EntityExtra | null) {
+  private drawShockwave(g: Graphics, x: number, y: number, extra?: EntityExtra | null) {
     if (!extra || !("Shockwave" in extra)) return;
     const sw = (extra as { Shockwave: { radius: number; max_radius: number } }).Shockwave;
     const progress = sw.radius / sw.max_radius;
@@ -457,7 +460,7 @@ export class TacticalView {
     }
   }
 
-  private drawCity(g: Graphics, x: number, y: number, extra: EntityExtra | null) {
+  private drawCity(g: Graphics, x: number, y: number, extra?: EntityExtra | null) {
     let healthRatio = 1;
     if (extra && "City" in extra) {
       const city = (extra as { City: { health: number; max_health: number } }).City;
@@ -517,7 +520,7 @@ export class TacticalView {
     }
   }
 
-  private drawBattery(g: Graphics, x: number, y: number, extra: EntityExtra | null) {
+  private drawBattery(g: Graphics, x: number, y: number, extra?: EntityExtra | null) {
     // Glow behind triangle
     g.circle(x, y - 6, 10);
     g.fill({ color: NEON_CYAN, alpha: 0.08 });
@@ -1,3 +1,5 @@
+export type Difficulty = "Easy" | "Normal" | "Hard";
+
 export interface CampaignSnapshot {
   resources: number;
   wave_number: number;
@@ -5,9 +7,88 @@ export interface CampaignSnapshot {
   regions: RegionSnapshot[];
   available_actions: AvailableAction[];
   tech_tree: TechTreeSnapshot;
+  allied_region_ids: number[];
+  relations: number;
+  pending_event?: StrategicEvent;
+  intel_preview?: WavePreview;
+  weather_forecast?: WeatherForecast;
+  wave_scores: WaveScore[];
+  medals: string[];
+  endless: boolean;
+  prestige_level: number;
+  difficulty: Difficulty;
+  theater: TheaterBounds;
   wave_income?: number;
 }
 
+/** Playable bounds; entities leaving them are removed */
+export interface TheaterBounds {
+  min_x: number;
+  max_x: number;
+  min_y: number;
+  max_y: number;
+}
+
+export interface WaveScore {
+  wave_number: number;
+  grade: string;
+  score: number;
+  missiles_destroyed: number;
+  interceptors_launched: number;
+  city_damage: number;
+  overkill: number;
+}
+
+export type StrategicEvent =
+  | { Sabotage: { region_id: number; slot_index: number; ammo_lost: number; cost: number } }
+  | { IntelWindfall: { cost: number } }
+  | { RefugeeInflux: { region_id: number; city_index: number; population: number; cost: number } };
+
+export interface WavePreview {
+  wave_number: number;
+  missile_count: number;
+  mirv_count: number;
+  sea_skimmer_count: number;
+}
+
+export interface WeatherForecast {
+  wave_number: number;
+  condition: string;
+  wind_x_min: number;
+  wind_x_max: number;
+  confidence: number;
+}
+
+export interface CampaignStats {
+  waves_recorded: number;
+  missiles_intercepted: number;
+  missiles_impacted: number;
+  interceptors_launched: number;
+  /** Unknown (null) when the stats are partial */
+  interception_rate: number | null;
+  by_type: InterceptorTypeStats[];
+  earned: ResourceLine[];
+  spent: ResourceLine[];
+  total_earned: number;
+  total_spent: number;
+  city_uptime: number;
+  /** Some totals were rebuilt from a save made before stats were tracked */
+  partial: boolean;
+}
+
+export interface InterceptorTypeStats {
+  interceptor_type: string;
+  launched: number;
+  kills: number;
+  /** Kills per launch; chain reactions can push this above 1 */
+  kills_per_interceptor: number;
+}
+
+export interface ResourceLine {
+  category: string;
+  amount: number;
+}
+
 export interface TechTreeSnapshot {
   unlocked_types: string[];
   upgrades: TypeUpgradeSnapshot[];
@@ -25,6 +106,7 @@ export interface RegionSnapshot {
   name: string;
   terrain: string;
   owned: boolean;
+  allied: boolean;
   expandable: boolean;
   cities: CitySnapshotCampaign[];
   battery_slots: BatterySlotSnapshot[];
@@ -52,8 +134,11 @@ export interface BatterySlotSnapshot {
 export type AvailableAction =
   | { ExpandRegion: { region_id: number; cost: number } }
   | { PlaceBattery: { region_id: number; slot_index: number; cost: number } }
+  | { FormAlliance: { region_id: number; cost: number } }
+  | { LeaseBattery: { region_id: number; slot_index: number; cost: number } }
   | { RestockAllBatteries: { count: number; cost: number } }
   | { RepairCity: { region_id: number; city_index: number; cost: number; health_to_restore: number } }
   | { UnlockInterceptor: { interceptor_type: string; cost: number; min_wave: number } }
   | { UpgradeInterceptor: { interceptor_type: string; axis: string; cost: number; current_level: number } }
+  | { Prestige: { next_level: number } }
   | "StartWave";
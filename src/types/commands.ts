@@ -13,6 +13,22 @@ export interface ArcPrediction {
   points: [number, number][];
   time_to_target: number;
   reaches_target: boolean;
+  aim_x: number;
+  aim_y: number;
+}
+
+export interface ImpactPrediction {
+  entity_id: number;
+  generation: number;
+  x: number;
+  y: number;
+  time_to_impact: number;
+  is_mirv: boolean;
+}
+
+export interface ImpactPredictions {
+  tick: number;
+  impacts: ImpactPrediction[];
 }
 
 export interface SaveMetadata {
@@ -21,3 +37,15 @@ export interface SaveMetadata {
   timestamp: number;
   resources: number;
 }
+
+export type IntegrityStatus = "Verified" | "Unsigned" | "Tampered";
+
+export interface MapMetadata {
+  file_name: string;
+  name: string;
+  description: string;
+  region_count: number;
+  error?: string;
+}
+
+export type { PlayerCommand, InterceptorType } from "./generated/ipc";
@@ -1,5 +1,6 @@
 export interface DetonationEvent {
   entity_id: number;
+  generation: number;
   x: number;
   y: number;
   yield_force: number;
@@ -8,6 +9,7 @@ export interface DetonationEvent {
 
 export interface ImpactEvent {
   entity_id: number;
+  generation: number;
   x: number;
   y: number;
   tick: number;
@@ -24,15 +26,51 @@ export interface WaveCompleteEvent {
   wave_number: number;
   missiles_destroyed: number;
   missiles_impacted: number;
+  missiles_exited: number;
   interceptors_launched: number;
   cities_remaining: number;
+  grade: string;
+  score: number;
+  new_medals: string[];
   tick: number;
 }
 
 export interface MirvSplitEvent {
   carrier_id: number;
+  carrier_generation: number;
   x: number;
   y: number;
   child_count: number;
   tick: number;
 }
+
+export interface MirvBusKilledEvent {
+  carrier_id: number;
+  carrier_generation: number;
+  x: number;
+  y: number;
+  children_prevented: number;
+  tick: number;
+}
+
+export interface InterceptorSelfDestructEvent {
+  entity_id: number;
+  generation: number;
+  x: number;
+  y: number;
+  tick: number;
+}
+
+export interface ThreatExitedEvent {
+  entity_id: number;
+  generation: number;
+  x: number;
+  y: number;
+  tick: number;
+}
+
+export interface SalvoShortfallEvent {
+  requested: number;
+  assigned: number;
+  tick: number;
+}
@@ -0,0 +1,87 @@
+// Generated from the Rust IPC types by `cargo run --bin export-types`. Do not edit.
+
+export type PlayerCommand = "Resume" | {
+  LaunchInterceptor: {
+    battery_id: number;
+    target_x: number;
+    target_y: number;
+    interceptor_type: InterceptorType;
+  };
+} | {
+  SalvoIntercept: {
+    target_x: number;
+    target_y: number;
+    count: number;
+    interceptor_type: InterceptorType;
+  };
+} | "Pause";
+
+export type InterceptorType = "Standard" | "Sprint" | "Exoatmospheric" | "AreaDenial";
+
+export type StateSnapshot = {
+  tick: number;
+  wave_number: number;
+  phase: string;
+  entities: EntitySnapshot[];
+  weather?: string | null;
+  wind_x?: number | null;
+  /** The game loop is over its tick budget and shedding load */
+  degraded?: boolean;
+  paused?: boolean;
+};
+
+export type EntitySnapshot = {
+  id: number;
+  /** Generation of the entity slot; (id, generation) is unique over a session */
+  generation: number;
+  entity_type: EntityType;
+  x: number;
+  y: number;
+  rotation: number;
+  vx: number;
+  vy: number;
+  extra?: EntityExtra | null;
+  /** Recent positions for missiles and interceptors, oldest first */
+  trail?: [number, number][];
+};
+
+export type EntityType = "Missile" | "Interceptor" | "Shockwave" | "City" | "Battery";
+
+export type EntityExtra = {
+  Shockwave: {
+    radius: number;
+    max_radius: number;
+  };
+} | {
+  City: {
+    health: number;
+    max_health: number;
+  };
+} | {
+  Battery: {
+    ammo: number;
+    max_ammo: number;
+    reload_remaining: number;
+    reload_time: number;
+    in_flight: number;
+    max_in_flight: number;
+    arc_min: number;
+    arc_max: number;
+  };
+} | {
+  Interceptor: {
+    burn_remaining: number;
+    burn_time: number;
+    interceptor_type: string;
+  };
+} | {
+  Missile: {
+    is_mirv: boolean;
+    warhead_type: string;
+    detected_by_radar: boolean;
+    detected_by_glow: boolean;
+    /** MIRV bus only: estimated split altitude and seconds until split */
+    estimated_split_altitude?: number | null;
+    split_countdown?: number | null;
+  };
+};
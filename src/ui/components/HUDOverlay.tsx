@@ -69,6 +69,16 @@ export function HUDOverlay() {
             {weatherLine}
           </div>
         )}
+        {hud.salvoNotice && (
+          <div className={styles.line} data-tone="warn">
+            {hud.salvoNotice}
+          </div>
+        )}
+        {hud.paused && (
+          <div className={styles.line} data-tone="warn">
+            PAUSED - P=Resume | F8=Restart Wave
+          </div>
+        )}
         {hud.muted && <div className={styles.lineMuted}>[MUTED]</div>}
       </div>
 
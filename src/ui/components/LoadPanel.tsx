@@ -12,6 +12,7 @@ interface LoadPanelProps {
 export function LoadPanel({ onBack }: LoadPanelProps) {
   const [loading, setLoading] = useState(true);
   const [saves, setSaves] = useState<SaveMetadata[]>([]);
+  const [error, setError] = useState<string | null>(null);
 
   useEffect(() => {
     let mounted = true;
@@ -38,6 +39,7 @@ export function LoadPanel({ onBack }: LoadPanelProps) {
       <div className={styles.panelHeader}>LOAD GAME</div>
       <div className={styles.panelBody}>
         {loading && <div className={styles.panelMuted}>Loading saves...</div>}
+        {error && <div className={styles.panelWarning}>{error}</div>}
         {!loading && saves.length === 0 && (
           <div className={styles.panelWarning}>No saved games found.</div>
         )}
@@ -55,7 +57,16 @@ export function LoadPanel({ onBack }: LoadPanelProps) {
                 fullWidth
                 onClick={() => {
                   playUiClick();
-                  loadGame(save.slot_name);
+                  setError(null);
+                  loadGame(save.slot_name)
+                    .then((status) => {
+                      if (status === "Tampered") {
+                        setError(
+                          `${save.slot_name.toUpperCase()} failed its integrity check and was not loaded.`,
+                        );
+                      }
+                    })
+                    .catch((e) => setError(String(e)));
                 }}
               />
             );
@@ -1,13 +1,17 @@
 import { useCallback, useEffect, useMemo, useRef, useState } from "react";
 import { getCurrentWindow } from "@tauri-apps/api/window";
 import { newGame } from "../../bridge/commands";
+import type { Difficulty } from "../../types/campaign";
 import { playUiClick } from "../gameActions";
 import { NeonButton } from "./controls/NeonButton";
 import { LoadPanel } from "./LoadPanel";
+import { MapsPanel } from "./MapsPanel";
 import { SettingsPanel } from "./SettingsPanel";
 import styles from "../styles/MainMenu.module.css";
 
-type Panel = "main" | "load" | "settings";
+type Panel = "main" | "load" | "maps" | "settings";
+
+const DIFFICULTIES: Difficulty[] = ["Easy", "Normal", "Hard"];
 
 // ─── Boot sequence (plays once on load) ─────────────────────────────────
 const BOOT_LINES = [
@@ -233,6 +237,7 @@ type Phase = "boot" | "transition" | "cycling";
 
 export function MainMenu() {
   const [panel, setPanel] = useState<Panel>("main");
+  const [difficulty, setDifficulty] = useState<Difficulty>("Normal");
   const [bootIndex, setBootIndex] = useState(0);
 
   // Terminal cycling state
@@ -400,7 +405,36 @@ export function MainMenu() {
               fullWidth
               onClick={() => {
                 playUiClick();
-                newGame();
+                newGame(false, undefined, difficulty);
+              }}
+            />
+            <NeonButton
+              label="ENDLESS MODE"
+              size="lg"
+              fullWidth
+              onClick={() => {
+                playUiClick();
+                newGame(true, undefined, difficulty);
+              }}
+            />
+            <NeonButton
+              label="CUSTOM MAP"
+              size="lg"
+              fullWidth
+              onClick={() => {
+                playUiClick();
+                setPanel("maps");
+              }}
+            />
+            <NeonButton
+              label={`DIFFICULTY: ${difficulty.toUpperCase()}`}
+              size="md"
+              fullWidth
+              variant="secondary"
+              onClick={() => {
+                playUiClick();
+                const next = (DIFFICULTIES.indexOf(difficulty) + 1) % DIFFICULTIES.length;
+                setDifficulty(DIFFICULTIES[next]);
               }}
             />
             <NeonButton
@@ -436,6 +470,7 @@ export function MainMenu() {
         )}
 
         {panel === "load" && <LoadPanel onBack={() => setPanel("main")} />}
+        {panel === "maps" && <MapsPanel difficulty={difficulty} onBack={() => setPanel("main")} />}
         {panel === "settings" && <SettingsPanel onBack={() => setPanel("main")} />}
       </div>
 
@@ -0,0 +1,81 @@
+import { useEffect, useState } from "react";
+import { listMaps, newGame } from "../../bridge/commands";
+import type { MapMetadata } from "../../types/commands";
+import type { Difficulty } from "../../types/campaign";
+import { playUiClick } from "../gameActions";
+import { NeonButton } from "./controls/NeonButton";
+import styles from "../styles/MainMenu.module.css";
+
+interface MapsPanelProps {
+  difficulty: Difficulty;
+  onBack: () => void;
+}
+
+export function MapsPanel({ difficulty, onBack }: MapsPanelProps) {
+  const [loading, setLoading] = useState(true);
+  const [maps, setMaps] = useState<MapMetadata[]>([]);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    let mounted = true;
+    setLoading(true);
+    listMaps()
+      .then((data) => {
+        if (!mounted) return;
+        setMaps(data);
+      })
+      .catch(() => {
+        if (!mounted) return;
+        setMaps([]);
+      })
+      .finally(() => {
+        if (mounted) setLoading(false);
+      });
+    return () => {
+      mounted = false;
+    };
+  }, []);
+
+  return (
+    <div className={styles.panel}>
+      <div className={styles.panelHeader}>CUSTOM MAPS</div>
+      <div className={styles.panelBody}>
+        {loading && <div className={styles.panelMuted}>Loading maps...</div>}
+        {!loading && maps.length === 0 && (
+          <div className={styles.panelWarning}>No custom maps found in the maps folder.</div>
+        )}
+        {error && <div className={styles.panelWarning}>{error}</div>}
+        {!loading &&
+          maps.map((map) => {
+            const label = map.error
+              ? `${map.file_name.toUpperCase()}  |  INVALID: ${map.error}`
+              : `${map.name.toUpperCase()}  |  ${map.region_count} REGIONS`;
+            return (
+              <NeonButton
+                key={map.file_name}
+                label={label}
+                size="sm"
+                fullWidth
+                disabled={!!map.error}
+                onClick={() => {
+                  playUiClick();
+                  newGame(false, map.file_name, difficulty).catch((e) => setError(String(e)));
+                }}
+              />
+            );
+          })}
+      </div>
+      <div className={styles.panelFooter}>
+        <NeonButton
+          label="BACK"
+          size="md"
+          variant="secondary"
+          onClick={() => {
+            playUiClick();
+            onBack();
+          }}
+        />
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,61 @@
+import { useEffect, useState } from "react";
+import { getCampaignStats } from "../../bridge/commands";
+import { onCampaignStats } from "../../bridge/events";
+import type { CampaignStats } from "../../types/campaign";
+import { useGameStore } from "../store";
+import styles from "../styles/StrategicOverlay.module.css";
+
+const percent = (ratio: number) => `${Math.round(ratio * 100)}%`;
+
+export function StatsPanel() {
+  const campaign = useGameStore((state) => state.campaign);
+  const [stats, setStats] = useState<CampaignStats | null>(null);
+
+  useEffect(() => {
+    const unlisten = onCampaignStats(setStats);
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  // Spending changes the totals, so refresh on every campaign update
+  useEffect(() => {
+    getCampaignStats();
+  }, [campaign]);
+
+  if (!stats) {
+    return <div className={styles.regionPanel}>Compiling statistics...</div>;
+  }
+
+  return (
+    <div className={styles.regionPanel}>
+      <div className={styles.regionTitle}>CAMPAIGN STATISTICS</div>
+      <div className={styles.regionMeta}>
+        {stats.waves_recorded} waves | {stats.missiles_intercepted} intercepted |{" "}
+        {stats.missiles_impacted} impacts |{" "}
+        {stats.interception_rate !== null && <>{percent(stats.interception_rate)} interception rate | </>}
+        {percent(stats.city_uptime)} city uptime
+      </div>
+      <div className={styles.regionDetails}>
+        {stats.partial && <div data-tone="warn">Partial: earlier waves predate stat tracking</div>}
+        {stats.by_type.map((t) => (
+          <div key={t.interceptor_type}>
+            {t.interceptor_type}: {t.kills} kills / {t.launched} launched ({t.kills_per_interceptor.toFixed(2)} kills per interceptor)
+          </div>
+        ))}
+        <div data-tone="good">Earned: ${stats.total_earned}</div>
+        {stats.earned.map((line) => (
+          <div key={`earned-${line.category}`}>
+            {line.category}: ${line.amount}
+          </div>
+        ))}
+        <div data-tone="warn">Spent: ${stats.total_spent}</div>
+        {stats.spent.map((line) => (
+          <div key={`spent-${line.category}`}>
+            {line.category}: ${line.amount}
+          </div>
+        ))}
+      </div>
+    </div>
+  );
+}
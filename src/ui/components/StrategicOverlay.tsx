@@ -1,9 +1,10 @@
-import { useMemo } from "react";
-import type { AvailableAction, RegionSnapshot } from "../../types/campaign";
+import { useMemo, useState } from "react";
+import type { AvailableAction, RegionSnapshot, StrategicEvent } from "../../types/campaign";
 import { useGameStore } from "../store";
 import { handleStrategicAction, playUiClick } from "../gameActions";
-import { returnToMainMenu } from "../../bridge/commands";
+import { resolveStrategicEvent, returnToMainMenu } from "../../bridge/commands";
 import { NeonButton } from "./controls/NeonButton";
+import { StatsPanel } from "./StatsPanel";
 import styles from "../styles/StrategicOverlay.module.css";
 
 function formatAction(action: AvailableAction, regions: RegionSnapshot[]) {
@@ -18,6 +19,16 @@ function formatAction(action: AvailableAction, regions: RegionSnapshot[]) {
     const name = regions.find((r) => r.id === region_id)?.name ?? `Region ${region_id}`;
     return { label: `PLACE BATTERY: ${name} ($${cost})`, cost, isStart: false };
   }
+  if ("FormAlliance" in action) {
+    const { region_id, cost } = action.FormAlliance;
+    const name = regions.find((r) => r.id === region_id)?.name ?? `Region ${region_id}`;
+    return { label: `FORM ALLIANCE: ${name} ($${cost})`, cost, isStart: false };
+  }
+  if ("LeaseBattery" in action) {
+    const { region_id, cost } = action.LeaseBattery;
+    const name = regions.find((r) => r.id === region_id)?.name ?? `Region ${region_id}`;
+    return { label: `LEASE BATTERY: ${name} ($${cost})`, cost, isStart: false };
+  }
   if ("RestockAllBatteries" in action) {
     const { count, cost } = action.RestockAllBatteries;
     const label = count === 1
@@ -42,12 +53,54 @@ function formatAction(action: AvailableAction, regions: RegionSnapshot[]) {
       isStart: false,
     };
   }
+  if ("Prestige" in action) {
+    return {
+      label: `PRESTIGE: RESET FOR LEVEL ${action.Prestige.next_level}`,
+      cost: 0,
+      isStart: false,
+    };
+  }
   return { label: "UNKNOWN ACTION", cost: 0, isStart: false };
 }
 
+function formatEvent(event: StrategicEvent, regions: RegionSnapshot[]) {
+  const regionName = (id: number) => regions.find((r) => r.id === id)?.name ?? `Region ${id}`;
+  if ("Sabotage" in event) {
+    const { region_id, ammo_lost, cost } = event.Sabotage;
+    return {
+      title: "SABOTAGE",
+      text: `Saboteurs target a battery in ${regionName(region_id)}. Ignore and it loses ${ammo_lost} ammo.`,
+      accept: `SECURITY SWEEP ($${cost})`,
+      decline: "IGNORE",
+      cost,
+    };
+  }
+  if ("IntelWindfall" in event) {
+    const { cost } = event.IntelWindfall;
+    return {
+      title: "INTEL WINDFALL",
+      text: "An informant offers the enemy's next strike plan.",
+      accept: `BUY INTEL ($${cost})`,
+      decline: "DECLINE",
+      cost,
+    };
+  }
+  const { region_id, population, cost } = event.RefugeeInflux;
+  return {
+    title: "REFUGEE INFLUX",
+    text: `${population} refugees seek shelter in ${regionName(region_id)}.`,
+    accept: `RESETTLE ($${cost})`,
+    decline: "TURN AWAY",
+    cost,
+  };
+}
+
 export function StrategicOverlay() {
   const campaign = useGameStore((state) => state.campaign);
   const hoveredRegionId = useGameStore((state) => state.hoveredRegionId);
+  const saveError = useGameStore((state) => state.saveError);
+  const [showStats, setShowStats] = useState(false);
+  const [confirmPrestige, setConfirmPrestige] = useState<AvailableAction | null>(null);
 
   const hoveredRegion = useMemo(() => {
     if (!campaign || hoveredRegionId == null) return null;
@@ -91,7 +144,91 @@ export function StrategicOverlay() {
         </div>
       )}
 
+      {saveError && <div className={styles.saveError}>{saveError}</div>}
+
+      {campaign.intel_preview && (
+        <div className={styles.income}>
+          INTEL: WAVE {campaign.intel_preview.wave_number} EXPECTS{" "}
+          {campaign.intel_preview.missile_count} MISSILES ({campaign.intel_preview.mirv_count} MIRV,{" "}
+          {campaign.intel_preview.sea_skimmer_count} SEA-SKIMMER)
+        </div>
+      )}
+
+      {campaign.weather_forecast && (
+        <div className={styles.income}>
+          FORECAST: WAVE {campaign.weather_forecast.wave_number}{" "}
+          {campaign.weather_forecast.condition.toUpperCase()}, WIND{" "}
+          {campaign.weather_forecast.wind_x_min.toFixed(0)} TO{" "}
+          {campaign.weather_forecast.wind_x_max.toFixed(0)} M/S (
+          {Math.round(campaign.weather_forecast.confidence * 100)}% CONFIDENCE)
+        </div>
+      )}
+
       <div className={styles.panel}>
+        {campaign.pending_event && (() => {
+          const event = formatEvent(campaign.pending_event, campaign.regions);
+          return (
+            <>
+              <div className={styles.panelHeader}>CRISIS: {event.title}</div>
+              <div className={styles.eventText}>{event.text}</div>
+              <div className={styles.actions}>
+                <NeonButton
+                  label={event.accept}
+                  size="sm"
+                  variant="secondary"
+                  disabled={campaign.resources < event.cost}
+                  fullWidth
+                  onClick={() => {
+                    playUiClick();
+                    resolveStrategicEvent(true);
+                  }}
+                />
+                <NeonButton
+                  label={event.decline}
+                  size="sm"
+                  variant="danger"
+                  fullWidth
+                  onClick={() => {
+                    playUiClick();
+                    resolveStrategicEvent(false);
+                  }}
+                />
+              </div>
+            </>
+          );
+        })()}
+        {confirmPrestige && (
+          <>
+            <div className={styles.panelHeader}>CONFIRM PRESTIGE</div>
+            <div className={styles.eventText}>
+              This ends the current campaign for good: every region, battery and upgrade is lost in
+              exchange for permanent prestige buffs.
+            </div>
+            <div className={styles.actions}>
+              <NeonButton
+                label="RESET CAMPAIGN"
+                size="sm"
+                variant="danger"
+                fullWidth
+                onClick={() => {
+                  playUiClick();
+                  handleStrategicAction(confirmPrestige);
+                  setConfirmPrestige(null);
+                }}
+              />
+              <NeonButton
+                label="CANCEL"
+                size="sm"
+                variant="secondary"
+                fullWidth
+                onClick={() => {
+                  playUiClick();
+                  setConfirmPrestige(null);
+                }}
+              />
+            </div>
+          </>
+        )}
         <div className={styles.panelHeader}>AVAILABLE ACTIONS</div>
         <div className={styles.actions}>
           {campaign.available_actions.map((action, index) => {
@@ -109,7 +246,11 @@ export function StrategicOverlay() {
                 fullWidth
                 onClick={() => {
                   playUiClick();
-                  handleStrategicAction(action);
+                  if (action !== "StartWave" && "Prestige" in action) {
+                    setConfirmPrestige(action);
+                  } else {
+                    handleStrategicAction(action);
+                  }
                 }}
               />
             );
@@ -119,7 +260,10 @@ export function StrategicOverlay() {
 
       <div className={styles.info}>
         <div className={styles.intel}>
-          INTEL: {ownedRegions.length} regions secured | {totalCities} cities |{" "}
+          INTEL: {ownedRegions.length} regions secured | {campaign.allied_region_ids.length} allied
+          (relations {Math.round(campaign.relations)}) | {campaign.medals.length} medals |{" "}
+          {campaign.difficulty.toUpperCase()} | {campaign.endless ? "ENDLESS | " : ""}prestige {campaign.prestige_level} |{" "}
+          {totalCities} cities |{" "}
           {totalBatteries} batteries deployed | {emptySlots} open slots | ENTER=Start Wave
           | F5=Quick Save | F9=Quick Load | ESC=Main Menu
         </div>
@@ -132,7 +276,17 @@ export function StrategicOverlay() {
             returnToMainMenu();
           }}
         />
+        <NeonButton
+          label={showStats ? "HIDE STATS" : "STATS"}
+          size="sm"
+          variant="secondary"
+          onClick={() => {
+            playUiClick();
+            setShowStats((s) => !s);
+          }}
+        />
 
+        {showStats && <StatsPanel />}
         {hoveredRegion && (
           <div className={styles.regionPanel}>
             <div className={styles.regionTitle}>{hoveredRegion.name.toUpperCase()}</div>
@@ -9,7 +9,7 @@ export function WaveCompleteOverlay() {
 
   const lines = useMemo(() => {
     if (!event) return [];
-    const totalMissiles = event.missiles_destroyed + event.missiles_impacted;
+    const totalMissiles = event.missiles_destroyed + event.missiles_impacted + event.missiles_exited;
     const efficiency =
       event.interceptors_launched > 0
         ? Math.round((event.missiles_destroyed / event.interceptors_launched) * 100)
@@ -19,11 +19,15 @@ export function WaveCompleteOverlay() {
       "",
       `Missiles Destroyed: ${event.missiles_destroyed}/${totalMissiles}`,
       `Missiles Impacted:  ${event.missiles_impacted}`,
+      ...(event.missiles_exited > 0 ? [`Missiles Turned Away: ${event.missiles_exited}`] : []),
       `Interceptors Used:  ${event.interceptors_launched}`,
       `Efficiency:         ${efficiency}%`,
       `Cities Remaining:   ${event.cities_remaining}`,
       "",
-      "Press ENTER or Click to Continue",
+      `GRADE: ${event.grade} (${event.score}/100)`,
+      ...event.new_medals.map((medal) => `MEDAL EARNED: ${medal.toUpperCase()}`),
+      "",
+      "Press ENTER or Click to Continue | F8 to Retry Wave",
     ];
   }, [event]);
 
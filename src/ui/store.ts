@@ -35,10 +35,13 @@ export interface HudState {
   contactsGlow: number;
   weather: string | null;
   windX: number;
+  paused: boolean;
   resources: number;
   waveIncome: number | null;
   battery: BatteryStatus | null;
   muted: boolean;
+  /** Shown briefly when a salvo gets fewer shots than requested */
+  salvoNotice: string | null;
 }
 
 const SETTINGS_KEY = "deterrence_settings";
@@ -61,10 +64,12 @@ const DEFAULT_HUD: HudState = {
   contactsGlow: 0,
   weather: null,
   windX: 0,
+  paused: false,
   resources: 0,
   waveIncome: null,
   battery: null,
   muted: false,
+  salvoNotice: null,
 };
 
 function loadSettings(): GameSettings {
@@ -96,6 +101,8 @@ interface GameStoreState {
   saves: SaveMetadata[];
   settings: GameSettings;
   screenShake: { x: number; y: number };
+  /** Last quicksave/quickload failure, shown on the strategic screen */
+  saveError: string | null;
 
   setPhase: (phase: GamePhase) => void;
   setHud: (partial: Partial<HudState>) => void;
@@ -106,6 +113,7 @@ interface GameStoreState {
   setSettings: (settings: GameSettings) => void;
   updateSettings: (partial: Partial<GameSettings>) => void;
   setScreenShake: (x: number, y: number) => void;
+  setSaveError: (error: string | null) => void;
 }
 
 export const useGameStore = create<GameStoreState>((set, get) => ({
@@ -117,6 +125,7 @@ export const useGameStore = create<GameStoreState>((set, get) => ({
   saves: [],
   settings: loadSettings(),
   screenShake: { x: 0, y: 0 },
+  saveError: null,
 
   setPhase: (phase) => set({ phase }),
   setHud: (partial) =>
@@ -137,4 +146,5 @@ export const useGameStore = create<GameStoreState>((set, get) => ({
     set({ settings: next });
   },
   setScreenShake: (x, y) => set({ screenShake: { x, y } }),
+  setSaveError: (error) => set({ saveError: error }),
 }));